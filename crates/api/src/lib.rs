@@ -1,10 +1,12 @@
 use chrono::{DateTime, Utc};
+use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition;
 use kube::CustomResource;
 use kube::CustomResourceExt;
-use schemars::JsonSchema;
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
-use std::collections::BTreeMap;
+use snafu::Snafu;
+use std::{borrow::Cow, collections::BTreeMap};
 
 pub const API_GROUP: &str = "frontend-forge.kubesphere.io";
 pub const API_VERSION: &str = "v1alpha1";
@@ -13,6 +15,7 @@ pub const JSBUNDLE_API_GROUP: &str = "extensions.kubesphere.io";
 pub const JSBUNDLE_API_VERSION: &str = "v1alpha1";
 pub const RESOURCE_SERVED_LABEL_KEY: &str = "kubesphere.io/resource-served";
 pub const RESOURCE_SERVED_LABEL_VALUE: &str = "true";
+pub const FRONTEND_FORGE_CONFIG_NAME: &str = "default";
 
 #[derive(CustomResource, Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
 #[kube(
@@ -21,7 +24,13 @@ pub const RESOURCE_SERVED_LABEL_VALUE: &str = "true";
     kind = "FrontendIntegration",
     plural = "frontendintegrations",
     status = "FrontendIntegrationStatus",
-    shortname = "fi"
+    shortname = "fi",
+    category = "frontend-forge",
+    printcolumn = r#"{"name":"Phase","type":"string","jsonPath":".status.phase"}"#,
+    printcolumn = r#"{"name":"Message","type":"string","jsonPath":".status.message","priority":1}"#,
+    printcolumn = r#"{"name":"Hash","type":"string","jsonPath":".status.observed_spec_hash","priority":1}"#,
+    printcolumn = r#"{"name":"Bundle","type":"string","jsonPath":".status.bundle_ref.name"}"#,
+    printcolumn = r#"{"name":"Age","type":"date","jsonPath":".metadata.creationTimestamp"}"#
 )]
 pub struct FrontendIntegrationSpec {
     #[serde(
@@ -30,10 +39,53 @@ pub struct FrontendIntegrationSpec {
         rename = "displayName"
     )]
     pub display_name: Option<String>,
+    /// Extension description. Takes precedence over the `kubesphere.io/description` annotation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Catalog metadata passed through to the manifest's `meta` object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<IntegrationMetadata>,
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub locales: BTreeMap<String, BTreeMap<String, String>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enabled: Option<bool>,
+    // Suspends the in-flight build Job without touching the published bundle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paused: Option<bool>,
+    // Any new value rebuilds the unchanged spec once; clearing it does nothing.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "forceRebuildToken"
+    )]
+    pub force_rebuild_token: Option<String>,
+    // Override `fi-<name>` and the controller-wide ConfigMap namespace for this FI's bundle.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "bundleName"
+    )]
+    pub bundle_name: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "bundleConfigMapNamespace"
+    )]
+    pub bundle_config_map_namespace: Option<String>,
+    // Copied onto the build Job, the JSBundle and its ConfigMap/Secret; `frontend-forge.io/`
+    // keys stay reserved for the controller.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "propagateLabels"
+    )]
+    pub propagate_labels: Option<BTreeMap<String, String>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "propagateAnnotations"
+    )]
+    pub propagate_annotations: Option<BTreeMap<String, String>>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub menus: Vec<PrimaryMenuSpec>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -42,7 +94,23 @@ pub struct FrontendIntegrationSpec {
     pub builder: Option<BuilderSpec>,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Default)]
+pub struct IntegrationMetadata {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "docsUrl")]
+    pub docs_url: Option<String>,
+}
+
+impl IntegrationMetadata {
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty() && self.owner.is_none() && self.docs_url.is_none()
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Default)]
 pub struct BuilderSpec {
     #[serde(
         default,
@@ -50,6 +118,61 @@ pub struct BuilderSpec {
         rename = "engineVersion"
     )]
     pub engine_version: Option<String>,
+    /// ServiceAccount for the runner pod; must be allowed by the controller's allowlist.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "serviceAccountName"
+    )]
+    pub service_account_name: Option<String>,
+    /// Runner image for this FI only, e.g. to canary a runner build; defaults to the controller's.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "runnerImage"
+    )]
+    pub runner_image: Option<String>,
+    /// Runner container requests/limits; each entry overrides the controller default of the same name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resources: Option<BuilderResourcesSpec>,
+    /// Bundle format and packaging; unset keeps the engine version's defaults.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<BuildOutputSpec>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Default)]
+pub struct BuildOutputSpec {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<BuildOutputFormat>,
+    /// Shared libraries provided by the console at runtime, e.g. `react`; never inlined.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub externals: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub minify: Option<bool>,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BuildOutputFormat {
+    Systemjs,
+    Esm,
+}
+
+impl BuildOutputFormat {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Systemjs => "systemjs",
+            Self::Esm => "esm",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Default)]
+pub struct BuilderResourcesSpec {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub requests: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub limits: BTreeMap<String, String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
@@ -59,11 +182,20 @@ pub struct PrimaryMenuSpec {
     pub key: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub icon: Option<String>,
+    // Lower values sort first in the console nav; unset keeps the historical 999.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<i32>,
+    // Existing console menu group such as `cluster.monitoring`; defaults to the placement root.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
     pub placement: MenuPlacement,
     #[serde(rename = "type")]
     pub type_: MenuNodeType,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub children: Vec<SecondaryMenuSpec>,
+    /// Extra route paths for the same page, e.g. a pre-rename path kept for bookmarks.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
@@ -73,6 +205,10 @@ pub struct SecondaryMenuSpec {
     pub key: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub icon: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<i32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
@@ -91,6 +227,20 @@ pub struct PageSpec {
     pub crd_table: Option<CrdTablePageSpec>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub iframe: Option<IframePageSpec>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom: Option<CustomPageSpec>,
+    /// RBAC rules the viewer must satisfy to see the page and its menu entry. Empty means no gating.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub permissions: Vec<PermissionSpec>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct PermissionSpec {
+    /// API group of the resource; omit for the core group.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    pub resource: String,
+    pub verbs: Vec<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
@@ -101,12 +251,44 @@ pub enum PageType {
     #[serde(rename = "iframe")]
     #[schemars(rename = "iframe")]
     Iframe,
+    #[serde(rename = "custom")]
+    #[schemars(rename = "custom")]
+    Custom,
+}
+
+// Inserted as the page root verbatim, for components shipped in the integration's own bundle.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct CustomPageSpec {
+    #[serde(rename = "type")]
+    pub type_: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(extend("x-kubernetes-preserve-unknown-fields" = true))]
+    pub props: Option<Map<String, Value>>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
 pub struct IframePageSpec {
+    /// May use `:cluster`, `:workspace` or `:namespace` where the page's placement provides them.
     #[serde(alias = "url")]
     pub src: String,
+    /// iframe `sandbox` tokens such as `allow-scripts`; an empty list applies every restriction.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sandbox: Option<Vec<String>>,
+    /// Permissions-policy directives for the iframe `allow` attribute, e.g. `fullscreen`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow: Option<Vec<String>>,
+    /// CSS height of the frame, e.g. `1200px` or `80vh`; the console default applies when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub loading: Option<IframeLoading>,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum IframeLoading {
+    Eager,
+    Lazy,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
@@ -119,6 +301,113 @@ pub struct CrdTablePageSpec {
     pub scope: CrdScope,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub columns: Vec<ColumnSpec>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "refreshIntervalSeconds"
+    )]
+    pub refresh_interval_seconds: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub table: Option<TableSpec>,
+    // Appended to list requests so several FIs can show slices of one CRD.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "labelSelector"
+    )]
+    pub label_selector: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "fieldSelector"
+    )]
+    pub field_selector: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actions: Option<CrdActionsSpec>,
+    // Empty keeps the console's name search.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub filters: Vec<FilterSpec>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct FilterSpec {
+    // Field path on the listed object, e.g. `metadata.name` or `status.phase`.
+    pub key: String,
+    pub label: String,
+    #[serde(rename = "type")]
+    pub type_: FilterType,
+    // Only read for `select` filters, which must declare at least one.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum FilterType {
+    Text,
+    Select,
+    LabelSelector,
+}
+
+// Every action defaults to enabled; read-only CRDs turn them off individually.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Default)]
+pub struct CrdActionsSpec {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edit: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delete: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "batchDelete"
+    )]
+    pub batch_delete: Option<bool>,
+}
+
+impl CrdActionsSpec {
+    pub fn create(&self) -> bool {
+        self.create.unwrap_or(true)
+    }
+
+    pub fn edit(&self) -> bool {
+        self.edit.unwrap_or(true)
+    }
+
+    pub fn delete(&self) -> bool {
+        self.delete.unwrap_or(true)
+    }
+
+    pub fn batch_delete(&self) -> bool {
+        self.batch_delete.unwrap_or(true)
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Default)]
+pub struct TableSpec {
+    // Must name a declared column whose `enableSorting` is not false.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "defaultSortKey"
+    )]
+    pub default_sort_key: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "defaultSortOrder"
+    )]
+    pub default_sort_order: Option<SortOrder>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "pageSize")]
+    pub page_size: Option<u32>,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    Asc,
+    Desc,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
@@ -151,6 +440,40 @@ pub struct ColumnSpec {
         rename = "enableHiding"
     )]
     pub enable_hiding: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        rename = "visibleToRoles"
+    )]
+    pub visible_to_roles: Vec<String>,
+    // CSS width such as `120px` or `20%`; passed to the console untouched.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub align: Option<ColumnAlign>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "defaultHidden"
+    )]
+    pub default_hidden: Option<bool>,
+    // Empty shows the column wherever the page is mounted.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub placements: Vec<MenuPlacement>,
+}
+
+impl ColumnSpec {
+    pub fn shown_in(&self, placement: MenuPlacement) -> bool {
+        self.placements.is_empty() || self.placements.contains(&placement)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ColumnAlign {
+    Left,
+    Center,
+    Right,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
@@ -163,9 +486,83 @@ pub struct ColumnRenderSpec {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pattern: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub link: Option<String>,
+    pub link: Option<ColumnLink>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub payload: Option<Map<String, Value>>,
+    // Only used by `type: status`: maps a field value to a badge color/intent.
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "statusMap")]
+    pub status_map: Option<BTreeMap<String, String>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "defaultIntent"
+    )]
+    pub default_intent: Option<String>,
+    // Only used by `type: labels|annotations` to filter the rendered map entries.
+    #[serde(default, skip_serializing_if = "Vec::is_empty", rename = "includeKeys")]
+    pub include_keys: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty", rename = "excludeKeys")]
+    pub exclude_keys: Vec<String>,
+    /// Shows a copy-to-clipboard icon next to the value; only valid on `text` and `link` columns.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub copyable: Option<bool>,
+    // Only used by `type: number`; the renderer checks them against the console formatter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub precision: Option<u8>,
+    /// Converts `bytes` values to the largest binary unit (Ki, Mi, Gi, ...) that keeps them >= 1.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub downscale: Option<bool>,
+    // Only used by `type: time`: shows "3 minutes ago" with the absolute time in a tooltip.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relative: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "tooltipFormat"
+    )]
+    pub tooltip_format: Option<String>,
+    /// Only used by `type: template`: text with `{field.path}` placeholders, e.g.
+    /// `{status.readyReplicas}/{spec.replicas}`; `path` still drives sorting.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+}
+
+// A plain string is kept verbatim for older FIs; the object form carries a route template.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum ColumnLink {
+    Plain(String),
+    Spec(ColumnLinkSpec),
+}
+
+// Structural schemas cannot express string-or-object, so the type stays open. `properties` and
+// `required` only constrain objects, which still types the object form for the API server.
+impl JsonSchema for ColumnLink {
+    fn schema_name() -> Cow<'static, str> {
+        "ColumnLink".into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let object = ColumnLinkSpec::json_schema(generator);
+        json_schema!({
+            "description": "Either a plain link string or a ColumnLinkSpec object.",
+            "x-kubernetes-preserve-unknown-fields": true,
+            "properties": object.get("properties"),
+            "required": object.get("required"),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct ColumnLinkSpec {
+    // `{field.path}` placeholders are resolved against the row object by the console.
+    pub template: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
@@ -174,6 +571,13 @@ pub enum ColumnRenderType {
     Text,
     Time,
     Link,
+    Status,
+    Labels,
+    Annotations,
+    Number,
+    Template,
+    // Rendered by the console component named in `payload.component`.
+    Custom,
 }
 
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
@@ -182,6 +586,7 @@ pub enum MenuPlacement {
     Global,
     Workspace,
     Cluster,
+    Project,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Default)]
@@ -192,6 +597,7 @@ pub enum FrontendIntegrationPhase {
     Building,
     Succeeded,
     Failed,
+    Superseded,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Default)]
@@ -219,6 +625,8 @@ pub struct LastBuildError {
     pub reason: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub occurred_at: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_tail: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Default)]
@@ -230,9 +638,17 @@ pub struct SimpleCondition {
     pub reason: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "observedGeneration"
+    )]
     pub observed_generation: Option<i64>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "lastTransitionTime"
+    )]
     pub last_transition_time: Option<DateTime<Utc>>,
 }
 
@@ -247,6 +663,9 @@ pub struct FrontendIntegrationStatus {
     pub observed_manifest_hash: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub observed_generation: Option<i64>,
+    // Last spec.forceRebuildToken a build Job was started for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub observed_force_rebuild_token: Option<String>,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -256,6 +675,11 @@ pub struct FrontendIntegrationStatus {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub bundle_ref: Option<ResourceRef>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Placements the menus resolve to; `global` when the FI declares no menus.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub effective_placements: Vec<MenuPlacement>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub last_error: Option<LastBuildError>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
@@ -311,30 +735,498 @@ pub struct JsBundleStatus {
     pub state: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub link: Option<String>,
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub conditions: Vec<Value>,
+    // Other controllers write these too; one malformed entry must not make the bundle unreadable.
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "deserialize_valid_conditions"
+    )]
+    pub conditions: Vec<SimpleCondition>,
+}
+
+fn deserialize_valid_conditions<'de, D>(deserializer: D) -> Result<Vec<SimpleCondition>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let values = Option::<Vec<Value>>::deserialize(deserializer)?.unwrap_or_default();
+    Ok(values
+        .into_iter()
+        .filter_map(|value| serde_json::from_value(value).ok())
+        .collect())
+}
+
+// Singleton by convention: the controller only reads the object named "default".
+#[derive(CustomResource, Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Default)]
+#[kube(
+    group = "frontend-forge.kubesphere.io",
+    version = "v1alpha1",
+    kind = "FrontendForgeConfig",
+    plural = "frontendforgeconfigs",
+    shortname = "ffc",
+    category = "frontend-forge"
+)]
+pub struct FrontendForgeConfigSpec {
+    #[serde(flatten)]
+    pub settings: ControllerSettings,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        rename = "namespaceOverrides"
+    )]
+    pub namespace_overrides: Vec<NamespaceConfigOverride>,
+}
+
+// Watch namespaces and reconcile concurrency are fixed at startup, so they stay env-only.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ControllerSettings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runner_image: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runner_service_account: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build_service_base_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jsbundle_config_key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build_service_timeout_seconds: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stale_check_grace_seconds: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reconcile_requeue_seconds: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub job_active_deadline_seconds: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub job_ttl_seconds_after_finished: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maintenance_window: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failed_pod_log_tail_lines: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verify_crd_exists: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_menu_icon: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_sourcemaps: Option<bool>,
+}
+
+// FIs are cluster-scoped, so an override applies to the FIs whose bundle ConfigMap lands in
+// `namespace` (`spec.bundleConfigMapNamespace`, else JSBUNDLE_CONFIGMAP_NAMESPACE).
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Default)]
+pub struct NamespaceConfigOverride {
+    pub namespace: String,
+    #[serde(flatten)]
+    pub settings: ControllerSettings,
+}
+
+#[derive(Debug, Snafu, Clone, PartialEq, Eq)]
+pub enum SpecValidationError {
+    #[snafu(display("page '{key}' has type={page_type} but also defines {conflicting} config"))]
+    ConflictingPageConfig {
+        key: String,
+        page_type: String,
+        conflicting: String,
+    },
+    #[snafu(display("builder.runnerImage '{image}' is not a valid image reference: {message}"))]
+    InvalidRunnerImage { image: String, message: String },
+    #[snafu(display("{field} '{value}' is invalid: {message}"))]
+    InvalidBundleTarget {
+        field: String,
+        value: String,
+        message: String,
+    },
+    #[snafu(display("{field} entry '{key}' is invalid: {message}"))]
+    InvalidPropagatedMetadata {
+        field: String,
+        key: String,
+        message: String,
+    },
 }
 
 impl FrontendIntegrationSpec {
+    // Engine-independent checks; renderers call this before reading the block matching `type`.
+    pub fn validate(&self) -> Result<(), SpecValidationError> {
+        for page in &self.pages {
+            let Some(conflicting) = [
+                (PageType::CrdTable, page.crd_table.is_some()),
+                (PageType::Iframe, page.iframe.is_some()),
+                (PageType::Custom, page.custom.is_some()),
+            ]
+            .into_iter()
+            .find_map(|(type_, present)| (present && type_ != page.type_).then_some(type_)) else {
+                continue;
+            };
+            return Err(SpecValidationError::ConflictingPageConfig {
+                key: page.key.clone(),
+                page_type: page.type_.as_str().to_string(),
+                conflicting: conflicting.as_str().to_string(),
+            });
+        }
+        if let Some(image) = self
+            .builder
+            .as_ref()
+            .and_then(|builder| builder.runner_image.as_deref())
+        {
+            validate_image_reference(image).map_err(|message| {
+                SpecValidationError::InvalidRunnerImage {
+                    image: image.to_string(),
+                    message,
+                }
+            })?;
+        }
+        for (field, value, validate) in [
+            (
+                "bundleName",
+                self.bundle_name.as_deref(),
+                validate_dns1123_subdomain as fn(&str) -> Result<(), String>,
+            ),
+            (
+                "bundleConfigMapNamespace",
+                self.bundle_config_map_namespace.as_deref(),
+                validate_dns1123_label,
+            ),
+        ] {
+            if let Some(value) = value {
+                validate(value).map_err(|message| SpecValidationError::InvalidBundleTarget {
+                    field: field.to_string(),
+                    value: value.to_string(),
+                    message,
+                })?;
+            }
+        }
+        for (field, entries, validate_value) in [
+            (
+                "propagateLabels",
+                self.propagate_labels.as_ref(),
+                validate_label_value as fn(&str) -> Result<(), String>,
+            ),
+            (
+                "propagateAnnotations",
+                self.propagate_annotations.as_ref(),
+                validate_annotation_value,
+            ),
+        ] {
+            if let Some(entries) = entries {
+                validate_metadata_entries(entries, validate_value).map_err(|(key, message)| {
+                    SpecValidationError::InvalidPropagatedMetadata {
+                        field: field.to_string(),
+                        key,
+                        message,
+                    }
+                })?;
+            }
+        }
+        Ok(())
+    }
+
     pub fn enabled(&self) -> bool {
         self.enabled.unwrap_or(true)
     }
 
-    pub fn without_enabled(&self) -> Self {
-        let mut spec = self.clone();
-        spec.enabled = None;
-        spec
+    pub fn paused(&self) -> bool {
+        self.paused.unwrap_or(false)
+    }
+
+    // `enabled`, `paused` and `forceRebuildToken` only drive the controller, so they stay out of
+    // the build identity.
+    // Menus of different placements never share a nav, and pages are bound by key, so neither
+    // ordering is meaningful beyond the menu order within one placement.
+    pub fn build_identity(&self) -> Self {
+        let mut spec = self.clone();
+        spec.enabled = None;
+        spec.paused = None;
+        spec.force_rebuild_token = None;
+        spec.menus.sort_by_key(|menu| menu.placement.rank());
+        spec.pages.sort_by(|a, b| a.key.cmp(&b.key));
+        spec
+    }
+
+    pub fn engine_version(&self) -> Option<&str> {
+        self.builder
+            .as_ref()
+            .and_then(|builder| builder.engine_version.as_deref())
+    }
+
+    pub fn runner_service_account(&self) -> Option<&str> {
+        self.builder
+            .as_ref()
+            .and_then(|builder| builder.service_account_name.as_deref())
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+    }
+
+    // Like `runner_image`, invalid overrides read as unset; rendering reports them.
+    pub fn bundle_name(&self) -> Option<&str> {
+        self.bundle_name
+            .as_deref()
+            .filter(|name| validate_dns1123_subdomain(name).is_ok())
+    }
+
+    pub fn bundle_config_map_namespace(&self) -> Option<&str> {
+        self.bundle_config_map_namespace
+            .as_deref()
+            .filter(|namespace| validate_dns1123_label(namespace).is_ok())
+    }
+
+    // All-or-nothing: a map with any invalid entry is left off every resource.
+    pub fn propagate_labels(&self) -> Option<&BTreeMap<String, String>> {
+        self.propagate_labels
+            .as_ref()
+            .filter(|labels| validate_metadata_entries(labels, validate_label_value).is_ok())
+    }
+
+    pub fn propagate_annotations(&self) -> Option<&BTreeMap<String, String>> {
+        self.propagate_annotations.as_ref().filter(|annotations| {
+            validate_metadata_entries(annotations, validate_annotation_value).is_ok()
+        })
+    }
+
+    // Only returns overrides that pass validation, so a bad value never reaches a Job spec.
+    pub fn runner_image(&self) -> Option<&str> {
+        self.builder
+            .as_ref()
+            .and_then(|builder| builder.runner_image.as_deref())
+            .filter(|image| validate_image_reference(image).is_ok())
+    }
+
+    pub fn effective_placements(&self) -> Vec<MenuPlacement> {
+        let mut placements = self
+            .menus
+            .iter()
+            .map(|menu| menu.placement)
+            .collect::<Vec<_>>();
+        placements.sort_unstable_by_key(|placement| placement.rank());
+        placements.dedup();
+        if placements.is_empty() {
+            placements.push(MenuPlacement::Global);
+        }
+        placements
+    }
+}
+
+pub fn validate_dns1123_label(value: &str) -> Result<(), String> {
+    if value.is_empty() || value.len() > 63 {
+        return Err("must be 1-63 characters".to_string());
+    }
+    let valid = value
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        && !value.starts_with('-')
+        && !value.ends_with('-');
+    if !valid {
+        return Err(
+            "must consist of lowercase alphanumerics or '-', starting and ending with an alphanumeric"
+                .to_string(),
+        );
+    }
+    Ok(())
+}
+
+pub fn validate_dns1123_subdomain(value: &str) -> Result<(), String> {
+    if value.is_empty() || value.len() > 253 {
+        return Err("must be 1-253 characters".to_string());
+    }
+    value.split('.').try_for_each(|label| {
+        validate_dns1123_label(label).map_err(|message| format!("label '{label}' {message}"))
+    })
+}
+
+fn validate_metadata_entries(
+    entries: &BTreeMap<String, String>,
+    validate_value: fn(&str) -> Result<(), String>,
+) -> Result<(), (String, String)> {
+    entries.iter().try_for_each(|(key, value)| {
+        validate_qualified_name(key)
+            .and_then(|()| validate_value(value))
+            .map_err(|message| (key.clone(), message))
+    })
+}
+
+fn validate_annotation_value(_: &str) -> Result<(), String> {
+    Ok(())
+}
+
+// Label and annotation keys: `[prefix/]name`, where the prefix is a DNS-1123 subdomain.
+pub fn validate_qualified_name(key: &str) -> Result<(), String> {
+    let name = match key.split_once('/') {
+        Some((prefix, name)) => {
+            validate_dns1123_subdomain(prefix).map_err(|message| format!("prefix {message}"))?;
+            name
+        }
+        None => key,
+    };
+    if name.is_empty() || name.len() > 63 {
+        return Err("name must be 1-63 characters".to_string());
+    }
+    if !is_label_token(name) {
+        return Err(
+            "name must consist of alphanumerics, '-', '_' or '.', starting and ending with an alphanumeric"
+                .to_string(),
+        );
+    }
+    Ok(())
+}
+
+pub fn validate_label_value(value: &str) -> Result<(), String> {
+    if value.len() > 63 {
+        return Err("value must be at most 63 characters".to_string());
+    }
+    if !value.is_empty() && !is_label_token(value) {
+        return Err(format!(
+            "value '{value}' must consist of alphanumerics, '-', '_' or '.', starting and ending with an alphanumeric"
+        ));
+    }
+    Ok(())
+}
+
+fn is_label_token(value: &str) -> bool {
+    value
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        && value.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && value.ends_with(|c: char| c.is_ascii_alphanumeric())
+}
+
+// A plausibility check of `[registry[:port]/]path[:tag][@algo:hex]`, not a full
+// implementation of the distribution reference grammar.
+pub fn validate_image_reference(image: &str) -> Result<(), String> {
+    if image.is_empty() || image.chars().any(char::is_whitespace) {
+        return Err("must be non-empty and contain no whitespace".to_string());
+    }
+    let (name, digest) = match image.split_once('@') {
+        Some((name, digest)) => (name, Some(digest)),
+        None => (image, None),
+    };
+    if let Some(digest) = digest {
+        let valid = digest.split_once(':').is_some_and(|(algorithm, hex)| {
+            !algorithm.is_empty()
+                && algorithm
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+                && hex.len() >= 32
+                && hex.chars().all(|c| c.is_ascii_hexdigit())
+        });
+        if !valid {
+            return Err(format!("invalid digest '{digest}'"));
+        }
+    }
+    let (repository, tag) = match name.rsplit_once(':') {
+        Some((repository, tag)) if !tag.contains('/') => (repository, Some(tag)),
+        _ => (name, None),
+    };
+    if let Some(tag) = tag {
+        let valid = tag.len() <= 128
+            && tag
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+            && tag
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'));
+        if !valid {
+            return Err(format!("invalid tag '{tag}'"));
+        }
+    }
+    let mut components = repository.split('/').peekable();
+    let first = components.next().unwrap_or_default();
+    let has_registry =
+        components.peek().is_some() && (first.contains(['.', ':']) || first == "localhost");
+    if has_registry {
+        let (host, port) = first.split_once(':').unwrap_or((first, ""));
+        if host.is_empty()
+            || !host
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-'))
+            || !port.chars().all(|c| c.is_ascii_digit())
+        {
+            return Err(format!("invalid registry '{first}'"));
+        }
+    }
+    let path = if has_registry {
+        components.collect::<Vec<_>>()
+    } else {
+        std::iter::once(first).chain(components).collect()
+    };
+    for component in path {
+        let valid = !component.is_empty()
+            && component.chars().all(|c| {
+                c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '.' | '_' | '-')
+            })
+            && component
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_ascii_alphanumeric())
+            && component
+                .chars()
+                .last()
+                .is_some_and(|c| c.is_ascii_alphanumeric());
+        if !valid {
+            return Err(format!(
+                "repository component '{component}' must be lowercase alphanumerics separated by '.', '_' or '-'"
+            ));
+        }
+    }
+    Ok(())
+}
+
+pub const MAX_ROUTING_PATH_CHARS: usize = 128;
+
+#[derive(Debug, Snafu, Clone, PartialEq, Eq)]
+pub enum RoutingPathError {
+    #[snafu(display("path must not be empty"))]
+    EmptyPath,
+    #[snafu(display("path must be relative and not start with '/'"))]
+    LeadingSlash,
+    #[snafu(display("path must not end with '/'"))]
+    TrailingSlash,
+    #[snafu(display("segment {index} is empty; '//' is not allowed"))]
+    EmptySegment { index: usize },
+    #[snafu(display("segment '{segment}' must match [a-z0-9][a-z0-9-]*"))]
+    InvalidSegment { segment: String },
+    #[snafu(display("path is {length} characters, exceeding the limit of {max}"))]
+    PathTooLong { length: usize, max: usize },
+}
+
+// Route paths are spliced into console router patterns verbatim, so only URL-safe lowercase
+// segments are allowed.
+pub fn validate_routing_path(path: &str) -> Result<(), RoutingPathError> {
+    if path.is_empty() {
+        return Err(RoutingPathError::EmptyPath);
     }
-
-    pub fn engine_version(&self) -> Option<&str> {
-        self.builder
-            .as_ref()
-            .and_then(|builder| builder.engine_version.as_deref())
+    if path.starts_with('/') {
+        return Err(RoutingPathError::LeadingSlash);
     }
+    if path.ends_with('/') {
+        return Err(RoutingPathError::TrailingSlash);
+    }
+    let length = path.chars().count();
+    if length > MAX_ROUTING_PATH_CHARS {
+        return Err(RoutingPathError::PathTooLong {
+            length,
+            max: MAX_ROUTING_PATH_CHARS,
+        });
+    }
+    for (index, segment) in path.split('/').enumerate() {
+        if segment.is_empty() {
+            return Err(RoutingPathError::EmptySegment { index });
+        }
+        let valid = segment
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+            && segment
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+        if !valid {
+            return Err(RoutingPathError::InvalidSegment {
+                segment: segment.to_string(),
+            });
+        }
+    }
+    Ok(())
 }
 
-pub fn frontend_integration_crd()
--> k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition {
+pub fn frontend_integration_crd() -> CustomResourceDefinition {
     let mut crd = FrontendIntegration::crd();
     crd.metadata
         .labels
@@ -346,9 +1238,109 @@ pub fn frontend_integration_crd()
     crd
 }
 
+pub fn frontend_forge_config_crd() -> CustomResourceDefinition {
+    FrontendForgeConfig::crd()
+}
+
+pub fn jsbundle_crd() -> CustomResourceDefinition {
+    JSBundle::crd()
+}
+
+// Catches schemars output that serializes but no longer deserializes into the CRD type.
+pub fn validate_crd_round_trip(crd: &CustomResourceDefinition) -> Result<(), String> {
+    let name = crd.metadata.name.clone().unwrap_or_default();
+    let value = serde_json::to_value(crd).map_err(|err| format!("{name}: {err}"))?;
+    let decoded: CustomResourceDefinition =
+        serde_json::from_value(value).map_err(|err| format!("{name}: {err}"))?;
+    if &decoded != crd {
+        return Err(format!("{name}: CRD changed after a JSON round-trip"));
+    }
+    if crd.spec.versions.iter().any(|version| {
+        version
+            .schema
+            .as_ref()
+            .and_then(|s| s.open_api_v3_schema.as_ref())
+            .is_none()
+    }) {
+        return Err(format!("{name}: every version needs an openAPIV3Schema"));
+    }
+    Ok(())
+}
+
+impl PageType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PageType::CrdTable => "crdTable",
+            PageType::Iframe => "iframe",
+            PageType::Custom => "custom",
+        }
+    }
+}
+
+impl MenuPlacement {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            MenuPlacement::Global => "global",
+            MenuPlacement::Workspace => "workspace",
+            MenuPlacement::Cluster => "cluster",
+            MenuPlacement::Project => "project",
+        }
+    }
+
+    // Canonical order for rendering menus and listing placements: widest scope first.
+    pub fn rank(self) -> u8 {
+        match self {
+            MenuPlacement::Global => 0,
+            MenuPlacement::Workspace => 1,
+            MenuPlacement::Cluster => 2,
+            MenuPlacement::Project => 3,
+        }
+    }
+
+    pub fn route_prefix(self) -> &'static str {
+        match self {
+            MenuPlacement::Cluster => "/clusters/:cluster",
+            MenuPlacement::Workspace => "/workspaces/:workspace",
+            MenuPlacement::Project => "/clusters/:cluster/projects/:namespace",
+            MenuPlacement::Global => "",
+        }
+    }
+}
+
+impl std::fmt::Display for MenuPlacement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Snafu, Clone, PartialEq, Eq)]
+#[snafu(display(
+    "unknown menu placement '{value}'; expected one of global, workspace, cluster, project"
+))]
+pub struct ParseMenuPlacementError {
+    pub value: String,
+}
+
+impl std::str::FromStr for MenuPlacement {
+    type Err = ParseMenuPlacementError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "global" => Ok(MenuPlacement::Global),
+            "workspace" => Ok(MenuPlacement::Workspace),
+            "cluster" => Ok(MenuPlacement::Cluster),
+            "project" => Ok(MenuPlacement::Project),
+            _ => Err(ParseMenuPlacementError {
+                value: value.to_string(),
+            }),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde_json::json;
 
     #[test]
     fn deserializes_page_and_org_menu_spec() {
@@ -537,6 +1529,25 @@ spec:
         );
     }
 
+    #[test]
+    fn skips_malformed_jsbundle_conditions() {
+        let status: JsBundleStatus = serde_json::from_value(json!({
+            "state": "Available",
+            "conditions": [
+                {"type": "Ready", "status": "True", "lastTransitionTime": "2026-01-01T00:00:00Z"},
+                {"type": "Synced"},
+                {"type": "Ready", "status": "True", "lastTransitionTime": "yesterday"},
+                "Ready"
+            ]
+        }))
+        .unwrap();
+        assert_eq!(status.conditions.len(), 1);
+        assert_eq!(status.conditions[0].type_, "Ready");
+
+        let status: JsBundleStatus = serde_json::from_value(json!({"conditions": null})).unwrap();
+        assert!(status.conditions.is_empty());
+    }
+
     #[test]
     fn deserializes_crd_table_without_kind() {
         let missing_kind: FrontendIntegration = serde_yaml::from_str(
@@ -621,6 +1632,226 @@ spec:
         );
     }
 
+    #[test]
+    fn build_output_round_trips_and_rejects_unknown_formats() {
+        let builder: BuilderSpec = serde_yaml::from_str(
+            "{output: {format: esm, externals: [react, '@kubed/components'], minify: false}}",
+        )
+        .unwrap();
+        let output = builder.output.clone().unwrap();
+        assert_eq!(output.format, Some(BuildOutputFormat::Esm));
+        assert_eq!(output.externals, vec!["react", "@kubed/components"]);
+        assert_eq!(output.minify, Some(false));
+        assert_eq!(
+            serde_json::to_value(&builder).unwrap(),
+            json!({"output": {"format": "esm", "externals": ["react", "@kubed/components"], "minify": false}})
+        );
+        assert_eq!(
+            serde_json::to_value(BuilderSpec {
+                output: Some(BuildOutputSpec::default()),
+                ..Default::default()
+            })
+            .unwrap(),
+            json!({"output": {}})
+        );
+        assert!(serde_yaml::from_str::<BuildOutputSpec>("{format: SystemJS}").is_err());
+        assert!(serde_yaml::from_str::<BuildOutputSpec>("{format: umd}").is_err());
+    }
+
+    #[test]
+    fn number_columns_use_the_lowercase_render_type() {
+        let render: ColumnRenderSpec = serde_yaml::from_str(
+            "{type: number, path: status.size, unit: bytes, precision: 2, downscale: true}",
+        )
+        .unwrap();
+        assert_eq!(render.type_, ColumnRenderType::Number);
+        assert_eq!(render.precision, Some(2));
+        assert_eq!(
+            serde_json::to_value(&render).unwrap(),
+            json!({"type": "number", "path": "status.size", "unit": "bytes", "precision": 2, "downscale": true})
+        );
+        assert!(serde_yaml::from_str::<ColumnRenderSpec>("{type: Number, path: a}").is_err());
+    }
+
+    #[test]
+    fn status_columns_round_trip_with_status_map() {
+        let column: ColumnSpec = serde_yaml::from_str(
+            r#"
+key: phase
+title: PHASE
+render:
+  type: status
+  path: status.phase
+  statusMap:
+    Succeeded: success
+    Failed: error
+  defaultIntent: warning
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(column.render.type_, ColumnRenderType::Status);
+        assert_eq!(
+            column.render.status_map,
+            Some(BTreeMap::from([
+                ("Failed".to_string(), "error".to_string()),
+                ("Succeeded".to_string(), "success".to_string()),
+            ]))
+        );
+        assert_eq!(column.render.default_intent.as_deref(), Some("warning"));
+
+        let value = serde_json::to_value(&column).unwrap();
+        assert_eq!(value["render"]["type"], "status");
+        assert_eq!(value["render"]["statusMap"]["Failed"], "error");
+        assert_eq!(serde_json::from_value::<ColumnSpec>(value).unwrap(), column);
+
+        let text: ColumnSpec = serde_yaml::from_str(
+            "{key: name, title: NAME, render: {type: text, path: metadata.name}}",
+        )
+        .unwrap();
+        let value = serde_json::to_value(&text).unwrap();
+        assert!(value["render"].get("statusMap").is_none());
+        assert!(value["render"].get("defaultIntent").is_none());
+    }
+
+    #[test]
+    fn column_layout_options_serialize_lowercase_and_skip_when_unset() {
+        let column: ColumnSpec = serde_yaml::from_str(
+            "{key: name, title: NAME, render: {type: text, path: metadata.name}, width: 120px, align: center, defaultHidden: true}",
+        )
+        .unwrap();
+        assert_eq!(column.align, Some(ColumnAlign::Center));
+        let value = serde_json::to_value(&column).unwrap();
+        assert_eq!(value["width"], "120px");
+        assert_eq!(value["align"], "center");
+        assert_eq!(value["defaultHidden"], true);
+        assert_eq!(
+            serde_json::to_value(ColumnAlign::Right).unwrap(),
+            json!("right")
+        );
+        assert!(serde_yaml::from_str::<ColumnAlign>("Left").is_err());
+
+        let plain = json!({"key": "name", "title": "NAME", "render": {"type": "text", "path": "metadata.name"}});
+        let column: ColumnSpec = serde_json::from_value(plain.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&column).unwrap(), plain);
+    }
+
+    #[test]
+    fn link_columns_accept_plain_strings_and_templates() {
+        let plain: ColumnRenderSpec =
+            serde_yaml::from_str("{type: link, path: metadata.name, link: /clusters/host}")
+                .unwrap();
+        assert_eq!(
+            plain.link,
+            Some(ColumnLink::Plain("/clusters/host".to_string()))
+        );
+
+        let templated: ColumnRenderSpec = serde_yaml::from_str(
+            r#"
+type: link
+path: metadata.name
+link:
+  template: /namespaces/{metadata.namespace}/demos/{metadata.name}
+  target: _blank
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            templated.link,
+            Some(ColumnLink::Spec(ColumnLinkSpec {
+                template: "/namespaces/{metadata.namespace}/demos/{metadata.name}".to_string(),
+                external: None,
+                target: Some("_blank".to_string()),
+            }))
+        );
+        let value = serde_json::to_value(&templated).unwrap();
+        assert_eq!(value["link"]["target"], "_blank");
+        assert!(value["link"].get("external").is_none());
+        assert_eq!(
+            serde_json::from_value::<ColumnRenderSpec>(value).unwrap(),
+            templated
+        );
+    }
+
+    #[test]
+    fn validates_runner_image_references() {
+        for image in [
+            "runner",
+            "spike2044/frontend-forge-runner:v0.2.0-rc.1",
+            "registry.example.com:5000/team/runner:canary",
+            "localhost/runner",
+            "ghcr.io/org/runner@sha256:0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef",
+        ] {
+            assert_eq!(validate_image_reference(image), Ok(()), "{image}");
+        }
+        for image in [
+            "",
+            "runner latest",
+            "Org/Runner",
+            "registry.example.com/runner:",
+            "runner:-bad",
+            "runner@sha256:xyz",
+            "team//runner",
+            "registry.example.com:port/runner",
+        ] {
+            assert!(validate_image_reference(image).is_err(), "{image}");
+        }
+
+        let mut spec: FrontendIntegrationSpec =
+            serde_yaml::from_str("{menus: [], pages: [], builder: {runnerImage: Not An Image}}")
+                .unwrap();
+        assert!(matches!(
+            spec.validate(),
+            Err(SpecValidationError::InvalidRunnerImage { .. })
+        ));
+        assert_eq!(spec.runner_image(), None);
+
+        spec.builder.as_mut().unwrap().runner_image = Some("runner:canary".to_string());
+        assert_eq!(spec.validate(), Ok(()));
+        assert_eq!(spec.runner_image(), Some("runner:canary"));
+    }
+
+    #[test]
+    fn routing_paths_must_be_lowercase_url_safe_segments() {
+        for path in ["overview", "ops/tasks", "v2/a-b/0-9", "a-"] {
+            assert_eq!(validate_routing_path(path), Ok(()), "{path}");
+        }
+        let too_long = "a".repeat(MAX_ROUTING_PATH_CHARS + 1);
+        for (path, expected) in [
+            ("", RoutingPathError::EmptyPath),
+            ("/ops", RoutingPathError::LeadingSlash),
+            ("ops/", RoutingPathError::TrailingSlash),
+            ("a//b", RoutingPathError::EmptySegment { index: 1 }),
+            (
+                "my path/\u{2713}",
+                RoutingPathError::InvalidSegment {
+                    segment: "my path".to_string(),
+                },
+            ),
+            (
+                "ops/-tasks",
+                RoutingPathError::InvalidSegment {
+                    segment: "-tasks".to_string(),
+                },
+            ),
+            (
+                "Ops",
+                RoutingPathError::InvalidSegment {
+                    segment: "Ops".to_string(),
+                },
+            ),
+            (
+                too_long.as_str(),
+                RoutingPathError::PathTooLong {
+                    length: MAX_ROUTING_PATH_CHARS + 1,
+                    max: MAX_ROUTING_PATH_CHARS,
+                },
+            ),
+        ] {
+            assert_eq!(validate_routing_path(path), Err(expected), "{path}");
+        }
+    }
+
     #[test]
     fn generated_crd_drops_legacy_fields() {
         let crd = frontend_integration_crd();
@@ -649,6 +1880,24 @@ spec:
         assert_eq!(required, &vec![Value::String("plural".to_string())]);
     }
 
+    #[test]
+    fn generated_crd_types_the_column_link_object_form() {
+        let crd = frontend_integration_crd();
+        validate_crd_round_trip(&crd).unwrap();
+        let schema = serde_json::to_value(&crd).unwrap();
+        let link = &schema["spec"]["versions"][0]["schema"]["openAPIV3Schema"]["properties"]["spec"]
+            ["properties"]["pages"]["items"]["properties"]["crdTable"]["properties"]["columns"]["items"]
+            ["properties"]["render"]["properties"]["link"];
+
+        // No `type`, so plain strings still pass; objects must match ColumnLinkSpec.
+        assert!(link.get("type").is_none());
+        assert_eq!(link["x-kubernetes-preserve-unknown-fields"], true);
+        assert_eq!(link["properties"]["template"]["type"], "string");
+        assert_eq!(link["properties"]["external"]["type"], "boolean");
+        assert_eq!(link["properties"]["target"]["type"], "string");
+        assert_eq!(link["required"], json!(["template"]));
+    }
+
     #[test]
     fn generated_crd_allows_optional_menu_icons() {
         let crd = frontend_integration_crd();
@@ -692,22 +1941,334 @@ spec:
             Some(&RESOURCE_SERVED_LABEL_VALUE.to_string())
         );
     }
-}
 
-impl MenuPlacement {
-    pub fn as_str(self) -> &'static str {
-        match self {
-            MenuPlacement::Global => "global",
-            MenuPlacement::Workspace => "workspace",
-            MenuPlacement::Cluster => "cluster",
+    #[test]
+    fn effective_placements_default_to_global_and_dedupe_in_canonical_order() {
+        let mut fi: FrontendIntegration = serde_yaml::from_str(
+            r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: demo
+spec:
+  menus: []
+  pages: []
+"#,
+        )
+        .unwrap();
+        assert_eq!(fi.spec.effective_placements(), vec![MenuPlacement::Global]);
+
+        for (key, placement) in [
+            ("a", MenuPlacement::Project),
+            ("b", MenuPlacement::Workspace),
+            ("c", MenuPlacement::Cluster),
+            ("d", MenuPlacement::Global),
+            ("e", MenuPlacement::Workspace),
+        ] {
+            fi.spec.menus.push(PrimaryMenuSpec {
+                display_name: key.to_string(),
+                key: key.to_string(),
+                icon: None,
+                order: None,
+                parent: None,
+                placement,
+                type_: MenuNodeType::Page,
+                children: vec![],
+                aliases: vec![],
+            });
         }
+        assert_eq!(
+            fi.spec.effective_placements(),
+            vec![
+                MenuPlacement::Global,
+                MenuPlacement::Workspace,
+                MenuPlacement::Cluster,
+                MenuPlacement::Project,
+            ]
+        );
+
+        let mut permuted = fi.clone();
+        permuted.spec.menus.reverse();
+        assert_eq!(
+            permuted.spec.effective_placements(),
+            fi.spec.effective_placements()
+        );
     }
 
-    pub fn route_prefix(self) -> &'static str {
-        match self {
-            MenuPlacement::Cluster => "/clusters/:cluster",
-            MenuPlacement::Workspace => "/workspaces/:workspace",
-            MenuPlacement::Global => "",
+    #[test]
+    fn null_menus_are_rejected_instead_of_defaulting() {
+        let err = serde_yaml::from_str::<FrontendIntegration>(
+            r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: demo
+spec:
+  menus: null
+  pages: []
+"#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("menus"), "{err}");
+    }
+
+    #[test]
+    fn frontend_forge_config_flattens_settings_into_spec_and_overrides() {
+        let config: FrontendForgeConfig = serde_yaml::from_str(
+            r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendForgeConfig
+metadata:
+  name: default
+spec:
+  runnerImage: registry.example/runner:v2
+  jobActiveDeadlineSeconds: 900
+  namespaceOverrides:
+    - namespace: team-a
+      runnerImage: registry.example/runner:canary
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.spec.settings.runner_image.as_deref(),
+            Some("registry.example/runner:v2")
+        );
+        assert_eq!(config.spec.settings.job_active_deadline_seconds, Some(900));
+        assert_eq!(config.spec.namespace_overrides[0].namespace, "team-a");
+        assert_eq!(
+            config.spec.namespace_overrides[0]
+                .settings
+                .runner_image
+                .as_deref(),
+            Some("registry.example/runner:canary")
+        );
+
+        let crd = frontend_forge_config_crd();
+        validate_crd_round_trip(&crd).unwrap();
+        assert_eq!(crd.spec.scope, "Cluster");
+        let schema = serde_json::to_value(&crd).unwrap();
+        let spec =
+            &schema["spec"]["versions"][0]["schema"]["openAPIV3Schema"]["properties"]["spec"];
+        assert_eq!(spec["properties"]["runnerImage"]["type"], "string");
+        assert_eq!(
+            spec["properties"]["namespaceOverrides"]["items"]["properties"]["runnerImage"]["type"],
+            "string"
+        );
+    }
+
+    #[test]
+    fn validate_rejects_page_blocks_that_do_not_match_type() {
+        let mut fi: FrontendIntegration = serde_yaml::from_str(
+            r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: demo
+spec:
+  menus: []
+  pages:
+    - key: docs
+      type: crdTable
+      crdTable:
+        names:
+          plural: inspecttasks
+        group: kubeeye.kubesphere.io
+        version: v1alpha2
+        scope: Cluster
+        columns: []
+      iframe:
+        src: http://example.test/docs
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            fi.spec.validate(),
+            Err(SpecValidationError::ConflictingPageConfig {
+                key: "docs".to_string(),
+                page_type: "crdTable".to_string(),
+                conflicting: "iframe".to_string(),
+            })
+        );
+
+        fi.spec.pages[0].type_ = PageType::Iframe;
+        assert_eq!(
+            fi.spec.validate().unwrap_err().to_string(),
+            "page 'docs' has type=iframe but also defines crdTable config"
+        );
+
+        fi.spec.pages[0].crd_table = None;
+        assert_eq!(fi.spec.validate(), Ok(()));
+
+        fi.spec.pages[0].custom = Some(CustomPageSpec {
+            type_: "Dashboard".to_string(),
+            props: None,
+        });
+        assert_eq!(
+            fi.spec.validate().unwrap_err().to_string(),
+            "page 'docs' has type=iframe but also defines custom config"
+        );
+        fi.spec.pages[0].type_ = PageType::Custom;
+        assert_eq!(
+            fi.spec.validate().unwrap_err().to_string(),
+            "page 'docs' has type=custom but also defines iframe config"
+        );
+    }
+
+    #[test]
+    fn propagated_labels_and_annotations_must_be_valid_kubernetes_metadata() {
+        let mut spec: FrontendIntegrationSpec = serde_json::from_value(json!({
+            "pages": [],
+            "menus": [],
+            "propagateLabels": {"team": "portal", "example.com/tier": "", "app.kubernetes.io/part-of": "a_b.c-1"},
+            "propagateAnnotations": {"example.com/owner": "Portal Team <portal@example.com>"},
+        }))
+        .unwrap();
+        assert_eq!(spec.validate(), Ok(()));
+
+        for (key, value, message) in [
+            ("-team", "portal", "name must consist of alphanumerics"),
+            (
+                "Example.com/team",
+                "portal",
+                "prefix label 'Example' must consist",
+            ),
+            ("team", "Portal Team", "value 'Portal Team' must consist"),
+            ("team", "-portal", "value '-portal' must consist"),
+            ("", "portal", "name must be 1-63 characters"),
+        ] {
+            spec.propagate_labels = Some(BTreeMap::from([(key.to_string(), value.to_string())]));
+            let err = spec.validate().unwrap_err();
+            assert!(
+                matches!(
+                    &err,
+                    SpecValidationError::InvalidPropagatedMetadata { field, .. } if field == "propagateLabels"
+                ),
+                "{key}={value}"
+            );
+            assert!(err.to_string().contains(message), "{err}");
+        }
+
+        spec.propagate_labels = None;
+        spec.propagate_annotations = Some(BTreeMap::from([(
+            format!("example.com/{}", "x".repeat(64)),
+            String::new(),
+        )]));
+        assert_eq!(
+            spec.validate().unwrap_err().to_string(),
+            format!(
+                "propagateAnnotations entry 'example.com/{}' is invalid: name must be 1-63 characters",
+                "x".repeat(64)
+            )
+        );
+    }
+
+    #[test]
+    fn menu_placement_display_and_from_str_round_trip() {
+        for placement in [
+            MenuPlacement::Global,
+            MenuPlacement::Workspace,
+            MenuPlacement::Cluster,
+            MenuPlacement::Project,
+        ] {
+            let text = placement.to_string();
+            assert_eq!(text, placement.as_str());
+            assert_eq!(text.parse::<MenuPlacement>(), Ok(placement));
+            assert_eq!(json!(placement), json!(text));
+        }
+        for value in ["Cluster", "", " global", "namespace"] {
+            assert_eq!(
+                value.parse::<MenuPlacement>(),
+                Err(ParseMenuPlacementError {
+                    value: value.to_string()
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn bundle_overrides_must_be_dns1123_names() {
+        let mut spec: FrontendIntegrationSpec = serde_json::from_value(json!({
+            "pages": [],
+            "menus": [],
+            "bundleName": "team-a.portal",
+            "bundleConfigMapNamespace": "team-a",
+        }))
+        .unwrap();
+        assert_eq!(spec.validate(), Ok(()));
+
+        for (name, namespace) in [
+            ("Team-A", "team-a"),
+            ("team-a.", "team-a"),
+            ("team-a", "team.a"),
+            ("team-a", "-team"),
+        ] {
+            spec.bundle_name = Some(name.to_string());
+            spec.bundle_config_map_namespace = Some(namespace.to_string());
+            assert!(
+                matches!(
+                    spec.validate(),
+                    Err(SpecValidationError::InvalidBundleTarget { .. })
+                ),
+                "{name} / {namespace}"
+            );
         }
+        spec.bundle_name = Some("x".repeat(254));
+        spec.bundle_config_map_namespace = None;
+        assert_eq!(
+            spec.validate().unwrap_err().to_string(),
+            format!(
+                "bundleName '{}' is invalid: must be 1-253 characters",
+                "x".repeat(254)
+            )
+        );
+    }
+
+    #[test]
+    fn generated_crds_round_trip_and_expose_printer_columns() {
+        let crd = frontend_integration_crd();
+        validate_crd_round_trip(&crd).unwrap();
+        validate_crd_round_trip(&jsbundle_crd()).unwrap();
+
+        assert_eq!(
+            crd.spec.names.categories,
+            Some(vec!["frontend-forge".to_string()])
+        );
+        let version = &crd.spec.versions[0];
+        let columns: Vec<_> = version
+            .additional_printer_columns
+            .iter()
+            .flatten()
+            .map(|column| {
+                (
+                    column.name.as_str(),
+                    column.json_path.as_str(),
+                    column.priority.unwrap_or_default(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            columns,
+            vec![
+                ("Phase", ".status.phase", 0),
+                ("Message", ".status.message", 1),
+                ("Hash", ".status.observed_spec_hash", 1),
+                ("Bundle", ".status.bundle_ref.name", 0),
+                ("Age", ".metadata.creationTimestamp", 0),
+            ]
+        );
+
+        let json = serde_json::to_value(&crd).unwrap();
+        let printed = &json["spec"]["versions"][0]["additionalPrinterColumns"];
+        assert_eq!(printed[1]["jsonPath"], ".status.message");
+        assert_eq!(printed[1]["priority"], 1);
+        assert!(
+            version
+                .subresources
+                .as_ref()
+                .and_then(|subresources| subresources.status.as_ref())
+                .is_some()
+        );
     }
 }
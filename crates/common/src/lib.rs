@@ -5,6 +5,8 @@ use snafu::{ResultExt, Snafu};
 use std::collections::BTreeMap;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+pub mod telemetry;
+
 pub const MANAGED_BY_VALUE: &str = "frontend-forge-builder-controller";
 pub const LABEL_MANAGED_BY: &str = "frontend-forge.io/managed-by";
 pub const LABEL_FI_NAME: &str = "frontend-forge.io/fi-name";
@@ -12,13 +14,19 @@ pub const LABEL_ENABLED: &str = "frontend-forge.io/enabled";
 pub const LABEL_SPEC_HASH: &str = "frontend-forge.io/spec-hash";
 pub const LABEL_MANIFEST_HASH: &str = "frontend-forge.io/manifest-hash";
 pub const LABEL_BUILD_KIND: &str = "frontend-forge.io/build-kind";
+pub const LABEL_OBSERVED_GENERATION: &str = "frontend-forge.io/observed-generation";
 pub const ANNO_BUILD_JOB: &str = "frontend-forge.io/build-job";
 pub const ANNO_MANIFEST_HASH: &str = "frontend-forge.io/manifest-hash";
 pub const ANNO_MANIFEST_CONTENT: &str = "frontend-forge.io/manifest-content";
+pub const ANNO_CONTENT_HASH: &str = "frontend-forge.io/content-hash";
+pub const ANNO_SOURCEMAP_REF: &str = "frontend-forge.io/sourcemap-ref";
 pub const ANNO_OBSERVED_GENERATION: &str = "frontend-forge.io/observed-generation";
+pub const ANNO_PRIORITY_CLASS: &str = "frontend-forge.io/priority-class";
+pub const ANNO_SKIP_STALE_CHECK: &str = "frontend-forge.io/skip-stale-check";
 pub const ANNO_SOURCE_SPEC: &str = "frontend-forge.io/source-spec";
 pub const ANNO_SOURCE_SPEC_HASH: &str = "frontend-forge.io/source-spec-hash";
 pub const ANNO_SOURCE_GENERATION: &str = "frontend-forge.io/source-generation";
+pub const RESERVED_KEY_PREFIX: &str = "frontend-forge.io/";
 pub const BUILD_KIND_VALUE: &str = "frontend-forge";
 pub const DEFAULT_MANIFEST_FILENAME: &str = "manifest.json";
 pub const DEFAULT_MANIFEST_MOUNT_PATH: &str = "/work/manifest/manifest.json";
@@ -97,6 +105,18 @@ pub fn hash_label_value(hash: &str) -> String {
     trimmed.chars().take(63).collect()
 }
 
+// User-supplied labels/annotations never override or add keys in the controller's own domain.
+pub fn merge_propagated(
+    owned: &mut BTreeMap<String, String>,
+    propagated: Option<&BTreeMap<String, String>>,
+) {
+    for (key, value) in propagated.into_iter().flatten() {
+        if !key.starts_with(RESERVED_KEY_PREFIX) {
+            owned.entry(key.clone()).or_insert_with(|| value.clone());
+        }
+    }
+}
+
 pub fn default_bundle_name(fi_name: &str) -> String {
     bounded_name(&format!("fi-{}", fi_name), 63)
 }
@@ -231,4 +251,24 @@ mod tests {
         assert_eq!(v.len(), 63);
         assert!(v.chars().all(|c| c.is_ascii_hexdigit()));
     }
+
+    #[test]
+    fn propagated_entries_never_touch_reserved_keys() {
+        let mut owned = BTreeMap::from([(LABEL_FI_NAME.to_string(), "demo".to_string())]);
+        let propagated = BTreeMap::from([
+            (LABEL_FI_NAME.to_string(), "spoofed".to_string()),
+            (LABEL_ENABLED.to_string(), "false".to_string()),
+            ("team".to_string(), "portal".to_string()),
+        ]);
+        merge_propagated(&mut owned, Some(&propagated));
+        assert_eq!(
+            owned,
+            BTreeMap::from([
+                (LABEL_FI_NAME.to_string(), "demo".to_string()),
+                ("team".to_string(), "portal".to_string()),
+            ])
+        );
+        merge_propagated(&mut owned, None);
+        assert_eq!(owned.len(), 2);
+    }
 }
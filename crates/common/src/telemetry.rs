@@ -0,0 +1,152 @@
+// Tracing setup shared by the controller and the runner. OTLP export is opt-in: without
+// OTEL_EXPORTER_OTLP_ENDPOINT no exporter is built and spans only feed the log output.
+
+use opentelemetry::propagation::TextMapPropagator;
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::Resource;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::SdkTracerProvider;
+use std::collections::HashMap;
+use std::env;
+use tracing::{Span, warn};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::EnvFilter;
+use tracing_subscriber::Layer;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+
+pub const OTEL_ENDPOINT_ENV: &str = "OTEL_EXPORTER_OTLP_ENDPOINT";
+// W3C trace context handed from the controller to the build Job.
+pub const TRACEPARENT_ENV: &str = "TRACEPARENT";
+pub const TRACEPARENT_HEADER: &str = "traceparent";
+
+pub struct Telemetry {
+    provider: Option<SdkTracerProvider>,
+}
+
+impl Telemetry {
+    // Flushes buffered spans; call before the process exits.
+    pub fn shutdown(self) {
+        if let Some(provider) = self.provider
+            && let Err(err) = provider.shutdown()
+        {
+            warn!(error = %err, "failed to flush OpenTelemetry spans");
+        }
+    }
+}
+
+pub fn init(service_name: &'static str, default_filter: &str, json: bool) -> Telemetry {
+    let fmt = tracing_subscriber::fmt::layer();
+    let fmt = if json {
+        fmt.json()
+            .with_current_span(true)
+            .with_span_list(false)
+            .boxed()
+    } else {
+        fmt.boxed()
+    };
+    let provider = otlp_endpoint(env::var(OTEL_ENDPOINT_ENV).ok().as_deref())
+        .map(|endpoint| tracer_provider(service_name, endpoint))
+        .transpose();
+    // The subscriber is not installed yet, so an exporter error is logged once it is.
+    let (provider, export_error) = match provider {
+        Ok(provider) => (provider, None),
+        Err(err) => (None, Some(err)),
+    };
+    let otel = provider
+        .as_ref()
+        .map(|provider| tracing_opentelemetry::layer().with_tracer(provider.tracer(service_name)));
+    tracing_subscriber::registry()
+        .with(EnvFilter::try_from_default_env().unwrap_or_else(|_| default_filter.into()))
+        .with(fmt)
+        .with(otel)
+        .init();
+    if let Some(err) = export_error {
+        warn!(error = %err, "OpenTelemetry export disabled");
+    }
+    Telemetry { provider }
+}
+
+fn otlp_endpoint(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|value| !value.is_empty())
+}
+
+fn tracer_provider(
+    service_name: &'static str,
+    endpoint: &str,
+) -> Result<SdkTracerProvider, opentelemetry_otlp::ExporterBuildError> {
+    let exporter = SpanExporter::builder()
+        .with_http()
+        .with_endpoint(format!("{}/v1/traces", endpoint.trim_end_matches('/')))
+        .build()?;
+    Ok(SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(
+            Resource::builder_empty()
+                .with_service_name(service_name)
+                .build(),
+        )
+        .build())
+}
+
+// None when the span is not recorded by an exporter, so callers can skip the env/header.
+pub fn traceparent(span: &Span) -> Option<String> {
+    let mut carrier = HashMap::new();
+    TraceContextPropagator::new().inject_context(&span.context(), &mut carrier);
+    carrier.remove(TRACEPARENT_HEADER)
+}
+
+// Malformed or empty values leave the span as a root, matching the W3C "restart trace" rule.
+pub fn set_parent_from_traceparent(span: &Span, value: Option<&str>) {
+    let Some(value) = value.map(str::trim).filter(|value| !value.is_empty()) else {
+        return;
+    };
+    let carrier = HashMap::from([(TRACEPARENT_HEADER.to_string(), value.to_string())]);
+    let cx = TraceContextPropagator::new().extract(&carrier);
+    let _ = span.set_parent(cx);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing::subscriber::with_default;
+
+    const PARENT: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+    #[test]
+    fn only_a_non_empty_endpoint_enables_export() {
+        assert_eq!(
+            otlp_endpoint(Some(" http://otel:4318 ")),
+            Some("http://otel:4318")
+        );
+        assert_eq!(otlp_endpoint(Some("  ")), None);
+        assert_eq!(otlp_endpoint(None), None);
+    }
+
+    #[test]
+    fn spans_continue_the_injected_trace() {
+        let provider = SdkTracerProvider::builder().build();
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+
+        with_default(subscriber, || {
+            let span = tracing::info_span!("build");
+            set_parent_from_traceparent(&span, Some(PARENT));
+            let child = traceparent(&span).expect("span is exported");
+            assert!(child.starts_with("00-4bf92f3577b34da6a3ce929d0e0e4736-"));
+            assert_ne!(child, PARENT);
+
+            let root = tracing::info_span!("root");
+            set_parent_from_traceparent(&root, Some("garbage"));
+            assert!(!traceparent(&root).unwrap().contains("4bf92f3577b34da6"));
+        });
+    }
+
+    #[test]
+    fn untracked_spans_have_no_traceparent() {
+        with_default(tracing_subscriber::registry(), || {
+            assert_eq!(traceparent(&tracing::info_span!("build")), None);
+        });
+    }
+}
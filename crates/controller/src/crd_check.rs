@@ -0,0 +1,416 @@
+use chrono::{DateTime, Utc};
+use frontend_forge_api::{
+    FrontendIntegration, JSBUNDLE_API_GROUP, JSBUNDLE_API_VERSION, JSBUNDLE_PLURAL, SimpleCondition,
+};
+use kube::Client;
+use std::collections::BTreeSet;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+pub(crate) const CONDITION_CRDS_AVAILABLE: &str = "CrdsAvailable";
+pub(crate) const CONDITION_DEPENDENCIES_READY: &str = "DependenciesReady";
+
+pub(crate) trait ApiDiscovery {
+    async fn serves_resource(
+        &self,
+        group: &str,
+        version: &str,
+        plural: &str,
+    ) -> Result<bool, kube::Error>;
+}
+
+impl ApiDiscovery for Client {
+    async fn serves_resource(
+        &self,
+        group: &str,
+        version: &str,
+        plural: &str,
+    ) -> Result<bool, kube::Error> {
+        match self
+            .list_api_group_resources(&format!("{group}/{version}"))
+            .await
+        {
+            Ok(resources) => Ok(resources
+                .resources
+                .iter()
+                .any(|resource| resource.name == plural)),
+            Err(kube::Error::Api(ae)) if ae.code == 404 => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+// Returns None when the FI has no crdTable pages, so the condition is dropped instead of reported.
+pub(crate) async fn crd_availability_condition(
+    discovery: &impl ApiDiscovery,
+    fi: &FrontendIntegration,
+    now: DateTime<Utc>,
+) -> Result<Option<SimpleCondition>, kube::Error> {
+    let referenced: BTreeSet<(&str, &str, &str)> = fi
+        .spec
+        .pages
+        .iter()
+        .filter_map(|page| page.crd_table.as_ref())
+        .map(|crd| {
+            (
+                crd.group.as_str(),
+                crd.version.as_str(),
+                crd.names.plural.as_str(),
+            )
+        })
+        .collect();
+    if referenced.is_empty() {
+        return Ok(None);
+    }
+
+    let mut missing = Vec::new();
+    for (group, version, plural) in referenced {
+        if !discovery.serves_resource(group, version, plural).await? {
+            missing.push(format!("{plural}.{group}/{version}"));
+        }
+    }
+
+    let (status, reason, message) = if missing.is_empty() {
+        (
+            "True",
+            "CrdsFound",
+            "all referenced CRDs are served".to_string(),
+        )
+    } else {
+        (
+            "False",
+            "CrdNotFound",
+            format!("referenced CRDs are not served: {}", missing.join(", ")),
+        )
+    };
+    Ok(Some(condition(
+        fi,
+        CONDITION_CRDS_AVAILABLE,
+        status,
+        reason,
+        message,
+        now,
+    )))
+}
+
+pub(crate) async fn jsbundle_crd_served(
+    discovery: &impl ApiDiscovery,
+) -> Result<bool, kube::Error> {
+    discovery
+        .serves_resource(JSBUNDLE_API_GROUP, JSBUNDLE_API_VERSION, JSBUNDLE_PLURAL)
+        .await
+}
+
+// Shared by every reconcile so the JSBundle CRD is discovered at most once per TTL; failed
+// lookups are not cached.
+pub(crate) struct JsBundleCrdCache {
+    ttl: Duration,
+    last: Mutex<Option<(Instant, bool)>>,
+}
+
+impl JsBundleCrdCache {
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            last: Mutex::new(None),
+        }
+    }
+
+    pub(crate) async fn served(&self, discovery: &impl ApiDiscovery) -> Result<bool, kube::Error> {
+        self.served_at(discovery, Instant::now()).await
+    }
+
+    async fn served_at(
+        &self,
+        discovery: &impl ApiDiscovery,
+        now: Instant,
+    ) -> Result<bool, kube::Error> {
+        let cached = *self.last.lock().unwrap_or_else(|err| err.into_inner());
+        if let Some((checked_at, served)) = cached
+            && now.saturating_duration_since(checked_at) < self.ttl
+        {
+            return Ok(served);
+        }
+        let served = jsbundle_crd_served(discovery).await?;
+        *self.last.lock().unwrap_or_else(|err| err.into_inner()) = Some((now, served));
+        Ok(served)
+    }
+}
+
+// Every build ends in a JSBundle, so without its CRD nothing can be published.
+pub(crate) fn dependencies_ready_condition(
+    fi: &FrontendIntegration,
+    jsbundle_served: bool,
+    now: DateTime<Utc>,
+) -> SimpleCondition {
+    let (status, reason, message) = if jsbundle_served {
+        (
+            "True",
+            "DependenciesInstalled",
+            format!("JSBundle CRD {JSBUNDLE_API_GROUP}/{JSBUNDLE_API_VERSION} is installed"),
+        )
+    } else {
+        (
+            "False",
+            "JSBundleCrdMissing",
+            jsbundle_crd_missing_message(),
+        )
+    };
+    condition(
+        fi,
+        CONDITION_DEPENDENCIES_READY,
+        status,
+        reason,
+        message,
+        now,
+    )
+}
+
+pub(crate) fn jsbundle_crd_missing_message() -> String {
+    format!("JSBundle CRD {JSBUNDLE_API_GROUP}/{JSBUNDLE_API_VERSION} not installed")
+}
+
+fn condition(
+    fi: &FrontendIntegration,
+    type_: &str,
+    status: &str,
+    reason: &str,
+    message: String,
+    now: DateTime<Utc>,
+) -> SimpleCondition {
+    let previous = fi.status.as_ref().and_then(|status| {
+        status
+            .conditions
+            .iter()
+            .find(|condition| condition.type_ == type_)
+    });
+    let last_transition_time = previous
+        .filter(|previous| previous.status == status)
+        .and_then(|previous| previous.last_transition_time)
+        .unwrap_or(now);
+
+    SimpleCondition {
+        type_: type_.to_string(),
+        status: status.to_string(),
+        reason: Some(reason.to_string()),
+        message: Some(message),
+        observed_generation: fi.metadata.generation,
+        last_transition_time: Some(last_transition_time),
+    }
+}
+
+pub(crate) fn with_condition(
+    conditions: &[SimpleCondition],
+    type_: &str,
+    condition: Option<SimpleCondition>,
+) -> Vec<SimpleCondition> {
+    let mut updated: Vec<SimpleCondition> = conditions
+        .iter()
+        .filter(|existing| existing.type_ != type_)
+        .cloned()
+        .collect();
+    updated.extend(condition);
+    updated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    struct FakeDiscovery(Vec<&'static str>);
+
+    struct CountingDiscovery {
+        served: Mutex<Result<bool, ()>>,
+        calls: Mutex<usize>,
+    }
+
+    impl ApiDiscovery for CountingDiscovery {
+        async fn serves_resource(&self, _: &str, _: &str, _: &str) -> Result<bool, kube::Error> {
+            *self.calls.lock().unwrap() += 1;
+            (*self.served.lock().unwrap())
+                .map_err(|()| kube::Error::LinesCodecMaxLineLengthExceeded)
+        }
+    }
+
+    impl ApiDiscovery for FakeDiscovery {
+        async fn serves_resource(
+            &self,
+            group: &str,
+            version: &str,
+            plural: &str,
+        ) -> Result<bool, kube::Error> {
+            Ok(self
+                .0
+                .contains(&format!("{plural}.{group}/{version}").as_str()))
+        }
+    }
+
+    fn crd_fi() -> FrontendIntegration {
+        serde_yaml::from_str(
+            r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: demo
+  generation: 3
+spec:
+  menus:
+    - displayName: Inspect Tasks
+      key: inspecttasks
+      placement: cluster
+      type: page
+  pages:
+    - key: inspecttasks
+      type: crdTable
+      crdTable:
+        names:
+          plural: inspecttasks
+        group: kubeeye.kubesphere.io
+        version: v1alpha2
+        scope: Cluster
+        columns:
+          - key: name
+            title: NAME
+            render:
+              type: text
+              path: metadata.name
+"#,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn reports_served_and_missing_crds() {
+        let now = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let fi = crd_fi();
+
+        let found = crd_availability_condition(
+            &FakeDiscovery(vec!["inspecttasks.kubeeye.kubesphere.io/v1alpha2"]),
+            &fi,
+            now,
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(found.status, "True");
+        assert_eq!(found.observed_generation, Some(3));
+
+        let missing = crd_availability_condition(&FakeDiscovery(vec![]), &fi, now)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(missing.status, "False");
+        assert_eq!(missing.reason.as_deref(), Some("CrdNotFound"));
+        assert_eq!(
+            missing.message.as_deref(),
+            Some("referenced CRDs are not served: inspecttasks.kubeeye.kubesphere.io/v1alpha2")
+        );
+    }
+
+    #[tokio::test]
+    async fn keeps_transition_time_while_status_is_unchanged() {
+        let earlier = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let later = Utc.with_ymd_and_hms(2026, 1, 2, 0, 0, 0).unwrap();
+        let mut fi = crd_fi();
+        let first = crd_availability_condition(&FakeDiscovery(vec![]), &fi, earlier)
+            .await
+            .unwrap();
+        fi.status = Some(frontend_forge_api::FrontendIntegrationStatus {
+            conditions: with_condition(&[], CONDITION_CRDS_AVAILABLE, first),
+            ..Default::default()
+        });
+
+        let second = crd_availability_condition(&FakeDiscovery(vec![]), &fi, later)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(second.last_transition_time, Some(earlier));
+    }
+
+    #[tokio::test]
+    async fn skips_fis_without_crd_pages() {
+        let mut fi = crd_fi();
+        fi.spec.pages.clear();
+
+        let condition = crd_availability_condition(&FakeDiscovery(vec![]), &fi, Utc::now())
+            .await
+            .unwrap();
+
+        assert_eq!(condition, None);
+    }
+
+    #[tokio::test]
+    async fn dependencies_follow_jsbundle_crd_discovery() {
+        let earlier = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let later = Utc.with_ymd_and_hms(2026, 1, 2, 0, 0, 0).unwrap();
+        let mut fi = crd_fi();
+
+        let served = jsbundle_crd_served(&FakeDiscovery(vec![])).await.unwrap();
+        assert!(!served);
+        let missing = dependencies_ready_condition(&fi, served, earlier);
+        assert_eq!(missing.status, "False");
+        assert_eq!(missing.reason.as_deref(), Some("JSBundleCrdMissing"));
+        assert_eq!(
+            missing.message.as_deref(),
+            Some("JSBundle CRD extensions.kubesphere.io/v1alpha1 not installed")
+        );
+        fi.status = Some(frontend_forge_api::FrontendIntegrationStatus {
+            conditions: with_condition(&[], CONDITION_DEPENDENCIES_READY, Some(missing)),
+            ..Default::default()
+        });
+        assert_eq!(
+            dependencies_ready_condition(&fi, false, later).last_transition_time,
+            Some(earlier)
+        );
+
+        // Installing the CRD later flips the condition on the next check.
+        let served = jsbundle_crd_served(&FakeDiscovery(vec![
+            "jsbundles.extensions.kubesphere.io/v1alpha1",
+        ]))
+        .await
+        .unwrap();
+        assert!(served);
+        let ready = dependencies_ready_condition(&fi, served, later);
+        assert_eq!(ready.status, "True");
+        assert_eq!(ready.last_transition_time, Some(later));
+    }
+
+    #[tokio::test]
+    async fn caches_jsbundle_crd_discovery_for_the_ttl() {
+        let cache = JsBundleCrdCache::new(Duration::from_secs(60));
+        let discovery = CountingDiscovery {
+            served: Mutex::new(Ok(false)),
+            calls: Mutex::new(0),
+        };
+        let start = Instant::now();
+
+        assert!(!cache.served_at(&discovery, start).await.unwrap());
+        *discovery.served.lock().unwrap() = Ok(true);
+        assert!(
+            !cache
+                .served_at(&discovery, start + Duration::from_secs(30))
+                .await
+                .unwrap()
+        );
+        assert_eq!(*discovery.calls.lock().unwrap(), 1);
+
+        // The CRD shows up once the cached answer expires.
+        assert!(
+            cache
+                .served_at(&discovery, start + Duration::from_secs(60))
+                .await
+                .unwrap()
+        );
+        assert_eq!(*discovery.calls.lock().unwrap(), 2);
+
+        // A failed lookup is retried on the next reconcile rather than remembered.
+        *discovery.served.lock().unwrap() = Err(());
+        let later = start + Duration::from_secs(120);
+        assert!(cache.served_at(&discovery, later).await.is_err());
+        *discovery.served.lock().unwrap() = Ok(true);
+        assert!(cache.served_at(&discovery, later).await.unwrap());
+        assert_eq!(*discovery.calls.lock().unwrap(), 4);
+    }
+}
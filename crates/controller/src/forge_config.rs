@@ -0,0 +1,272 @@
+use crate::ControllerConfig;
+use crate::maintenance::MaintenanceWindow;
+use arc_swap::ArcSwap;
+use frontend_forge_api::{
+    ControllerSettings, FRONTEND_FORGE_CONFIG_NAME, FrontendForgeConfig, FrontendForgeConfigSpec,
+    FrontendIntegration,
+};
+use futures::StreamExt;
+use kube::{Api, Client};
+use kube_runtime::{WatchStreamExt, watcher};
+use std::sync::Arc;
+use tokio::sync::watch;
+use tracing::{info, warn};
+
+// Holds the env-derived baseline plus the latest FrontendForgeConfig spec, if any.
+pub(crate) struct ConfigStore {
+    env: ControllerConfig,
+    spec: ArcSwap<Option<FrontendForgeConfigSpec>>,
+    loaded: watch::Sender<bool>,
+}
+
+impl ConfigStore {
+    pub(crate) fn new(env: ControllerConfig) -> Self {
+        Self {
+            env,
+            spec: ArcSwap::from_pointee(None),
+            loaded: watch::Sender::new(false),
+        }
+    }
+
+    pub(crate) fn env(&self) -> &ControllerConfig {
+        &self.env
+    }
+
+    pub(crate) fn set(&self, spec: Option<FrontendForgeConfigSpec>) {
+        self.spec.store(Arc::new(spec));
+        self.mark_loaded();
+    }
+
+    // Also called when the first list fails, e.g. without the CRD, so the env baseline applies.
+    fn mark_loaded(&self) {
+        self.loaded.send_replace(true);
+    }
+
+    // A reconcile before the first list would miss settings such as the maintenance window.
+    pub(crate) async fn loaded(&self) {
+        let mut loaded = self.loaded.subscribe();
+        let _ = loaded.wait_for(|loaded| *loaded).await;
+    }
+
+    // Reconciles take one snapshot up front so a swap mid-reconcile cannot mix two configs.
+    pub(crate) fn snapshot(&self, namespace: Option<&str>) -> ControllerConfig {
+        let mut config = self.env.clone();
+        let spec = self.spec.load();
+        let Some(spec) = spec.as_ref() else {
+            return config;
+        };
+        apply_settings(&mut config, &spec.settings);
+        if let Some(namespace) = namespace
+            && let Some(overrides) = spec
+                .namespace_overrides
+                .iter()
+                .find(|overrides| overrides.namespace == namespace)
+        {
+            apply_settings(&mut config, &overrides.settings);
+        }
+        config
+    }
+
+    // The bundle namespace only depends on env-only settings, so the baseline resolves it.
+    pub(crate) fn snapshot_for(&self, fi: &FrontendIntegration) -> ControllerConfig {
+        self.snapshot(Some(crate::bundle_configmap_namespace_for(fi, &self.env)))
+    }
+}
+
+fn apply_settings(config: &mut ControllerConfig, settings: &ControllerSettings) {
+    if let Some(value) = settings.runner_image.as_ref() {
+        config.runner_image = value.clone();
+    }
+    if let Some(value) = settings.runner_service_account.as_ref() {
+        config.runner_service_account = Some(value.clone());
+    }
+    if let Some(value) = settings.build_service_base_url.as_ref() {
+        config.build_service_base_url = value.clone();
+    }
+    if let Some(value) = settings.jsbundle_config_key.as_ref() {
+        config.jsbundle_config_key = value.clone();
+    }
+    if let Some(value) = settings.build_service_timeout_seconds {
+        config.build_service_timeout_seconds = value;
+    }
+    if let Some(value) = settings.stale_check_grace_seconds {
+        config.stale_check_grace_seconds = value;
+    }
+    if let Some(value) = settings.reconcile_requeue_seconds {
+        config.reconcile_requeue_seconds = value;
+    }
+    if let Some(value) = settings.job_active_deadline_seconds {
+        config.job_active_deadline_seconds = value;
+    }
+    if let Some(value) = settings.job_ttl_seconds_after_finished {
+        config.job_ttl_seconds_after_finished = Some(value);
+    }
+    if let Some(value) = settings.maintenance_window.as_deref() {
+        if value.trim().is_empty() {
+            config.maintenance_window = None;
+        } else {
+            match value.parse::<MaintenanceWindow>() {
+                Ok(window) => config.maintenance_window = Some(window),
+                Err(message) => {
+                    warn!(value, %message, "ignoring invalid maintenanceWindow override");
+                }
+            }
+        }
+    }
+    if let Some(value) = settings.failed_pod_log_tail_lines {
+        config.failed_pod_log_tail_lines = value;
+    }
+    if let Some(value) = settings.verify_crd_exists {
+        config.verify_crd_exists = value;
+    }
+    if let Some(value) = settings.include_sourcemaps {
+        config.include_sourcemaps = value;
+    }
+    if let Some(value) = settings.default_menu_icon.as_ref() {
+        config.render_options.default_menu_icon =
+            Some(value.trim().to_string()).filter(|icon| !icon.is_empty());
+    }
+}
+
+// Runs until the watch stream ends; the env baseline stays in effect while the CR is absent.
+pub(crate) async fn watch_forge_config(client: Client, store: &ConfigStore) {
+    let api = Api::<FrontendForgeConfig>::all(client);
+    let config =
+        watcher::Config::default().fields(&format!("metadata.name={FRONTEND_FORGE_CONFIG_NAME}"));
+    let mut events = watcher(api, config).default_backoff().boxed();
+    let mut initial = None;
+    while let Some(event) = events.next().await {
+        match event {
+            Ok(watcher::Event::Init) => initial = None,
+            Ok(watcher::Event::InitApply(config)) => initial = Some(config.spec),
+            Ok(watcher::Event::InitDone) => {
+                info!(
+                    present = initial.is_some(),
+                    "loaded FrontendForgeConfig {FRONTEND_FORGE_CONFIG_NAME}"
+                );
+                store.set(initial.take());
+            }
+            Ok(watcher::Event::Apply(config)) => {
+                info!("FrontendForgeConfig {FRONTEND_FORGE_CONFIG_NAME} updated");
+                store.set(Some(config.spec));
+            }
+            Ok(watcher::Event::Delete(_)) => {
+                info!("FrontendForgeConfig {FRONTEND_FORGE_CONFIG_NAME} deleted; using env config");
+                store.set(None);
+            }
+            Err(err) => {
+                warn!(error = %err, "FrontendForgeConfig watch failed");
+                store.mark_loaded();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frontend_forge_api::NamespaceConfigOverride;
+    use std::time::Duration;
+
+    fn spec() -> FrontendForgeConfigSpec {
+        FrontendForgeConfigSpec {
+            settings: ControllerSettings {
+                runner_image: Some("runner:cluster".to_string()),
+                job_active_deadline_seconds: Some(900),
+                maintenance_window: Some("not-a-window".to_string()),
+                default_menu_icon: Some("Cluster".to_string()),
+                ..Default::default()
+            },
+            namespace_overrides: vec![NamespaceConfigOverride {
+                namespace: "team-a".to_string(),
+                settings: ControllerSettings {
+                    runner_image: Some("runner:canary".to_string()),
+                    ..Default::default()
+                },
+            }],
+        }
+    }
+
+    #[test]
+    fn falls_back_to_env_until_a_config_is_set() {
+        let store = ConfigStore::new(ControllerConfig::for_tests());
+        assert_eq!(store.snapshot(None).runner_image, "runner:env");
+
+        store.set(Some(spec()));
+        let config = store.snapshot(None);
+        assert_eq!(config.runner_image, "runner:cluster");
+        assert_eq!(config.job_active_deadline_seconds, 900);
+        assert_eq!(config.maintenance_window, None);
+        assert_eq!(
+            config.render_options.default_menu_icon.as_deref(),
+            Some("Cluster")
+        );
+        assert_eq!(config.build_service_timeout_seconds, 600);
+
+        store.set(None);
+        assert_eq!(store.snapshot(None).runner_image, "runner:env");
+    }
+
+    #[test]
+    fn namespace_overrides_win_over_cluster_settings() {
+        let store = ConfigStore::new(ControllerConfig::for_tests());
+        store.set(Some(spec()));
+
+        let config = store.snapshot(Some("team-a"));
+        assert_eq!(config.runner_image, "runner:canary");
+        assert_eq!(config.job_active_deadline_seconds, 900);
+        assert_eq!(
+            store.snapshot(Some("team-b")).runner_image,
+            "runner:cluster"
+        );
+    }
+
+    #[test]
+    fn fis_pick_the_override_for_their_bundle_namespace() {
+        let mut env = ControllerConfig::for_tests();
+        env.allowed_bundle_namespaces = vec!["team-a".to_string()];
+        let store = ConfigStore::new(env);
+        store.set(Some(spec()));
+
+        let mut fi: FrontendIntegration = serde_json::from_value(serde_json::json!({
+            "apiVersion": "frontend-forge.kubesphere.io/v1alpha1",
+            "kind": "FrontendIntegration",
+            "metadata": { "name": "demo" },
+            "spec": { "menus": [], "pages": [] }
+        }))
+        .unwrap();
+        assert_eq!(store.snapshot_for(&fi).runner_image, "runner:cluster");
+        fi.spec.bundle_config_map_namespace = Some("team-a".to_string());
+        assert_eq!(store.snapshot_for(&fi).runner_image, "runner:canary");
+
+        // A namespace outside the allowlist falls back like the bundle itself does.
+        fi.spec.bundle_config_map_namespace = Some("team-b".to_string());
+        assert_eq!(store.snapshot_for(&fi).runner_image, "runner:cluster");
+    }
+
+    #[tokio::test]
+    async fn waits_for_the_first_load_even_when_no_config_exists() {
+        let store = ConfigStore::new(ControllerConfig::for_tests());
+        assert!(
+            tokio::time::timeout(Duration::from_millis(20), store.loaded())
+                .await
+                .is_err()
+        );
+
+        store.set(None);
+        tokio::time::timeout(Duration::from_millis(20), store.loaded())
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn snapshots_are_unaffected_by_later_swaps() {
+        let store = ConfigStore::new(ControllerConfig::for_tests());
+        let in_flight = store.snapshot(None);
+
+        store.set(Some(spec()));
+
+        assert_eq!(in_flight.runner_image, "runner:env");
+        assert_eq!(store.snapshot(None).runner_image, "runner:cluster");
+    }
+}
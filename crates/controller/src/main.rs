@@ -1,24 +1,46 @@
+mod crd_check;
+mod debug;
+mod forge_config;
+mod maintenance;
+mod metrics;
+mod render;
+mod runner_resources;
+mod runner_security;
 mod webhook;
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use forge_config::ConfigStore;
 use frontend_forge_api::{
     FrontendIntegration, FrontendIntegrationPhase, FrontendIntegrationStatus, JSBundle,
-    LastBuildError, LastBuildStatus, ResourceRef,
+    JsBundleNamespacedKeyRef, LastBuildError, LastBuildStatus, ResourceRef, SimpleCondition,
 };
 use frontend_forge_common::{
-    ANNO_MANIFEST_HASH, ANNO_OBSERVED_GENERATION, BUILD_KIND_VALUE, CommonError, LABEL_BUILD_KIND,
-    LABEL_ENABLED, LABEL_FI_NAME, LABEL_MANAGED_BY, LABEL_MANIFEST_HASH, LABEL_SPEC_HASH,
-    MANAGED_BY_VALUE, default_bundle_name, hash_label_value, job_name, serializable_hash,
+    ANNO_CONTENT_HASH, ANNO_MANIFEST_HASH, ANNO_OBSERVED_GENERATION, ANNO_PRIORITY_CLASS,
+    ANNO_SKIP_STALE_CHECK, BUILD_KIND_VALUE, CommonError, LABEL_BUILD_KIND, LABEL_ENABLED,
+    LABEL_FI_NAME, LABEL_MANAGED_BY, LABEL_MANIFEST_HASH, LABEL_OBSERVED_GENERATION,
+    LABEL_SPEC_HASH, MANAGED_BY_VALUE, default_bundle_name, hash_label_value, job_name,
+    manifest_hash_from_content, merge_propagated, serializable_hash,
+    telemetry::{self, OTEL_ENDPOINT_ENV, TRACEPARENT_ENV},
 };
+use frontend_forge_manifest::{ManifestRenderError, RenderOptions};
 use futures::StreamExt;
 use k8s_openapi::api::batch::v1::JobStatus;
 use k8s_openapi::api::batch::v1::{Job, JobSpec};
-use k8s_openapi::api::core::v1::{Container, EnvVar, PodSpec, PodTemplateSpec};
+use k8s_openapi::api::core::v1::{
+    ConfigMap, Container, ContainerStatus, EnvVar, Pod, PodSpec, PodTemplateSpec,
+    ResourceRequirements, Secret, SecretVolumeSource, Toleration, Volume, VolumeMount,
+};
 use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ObjectMeta, OwnerReference};
-use kube::api::{ListParams, Patch, PatchParams, PostParams};
+use kube::api::{DeleteParams, ListParams, LogParams, Patch, PatchParams, PostParams};
 use kube::{Api, Client, Resource, ResourceExt};
-use kube_runtime::controller::{Action, Controller};
+use kube_runtime::controller::{self, Action, Controller};
+use kube_runtime::events::{Event, EventType, Recorder, Reporter};
+use kube_runtime::reflector::ObjectRef;
 use kube_runtime::watcher;
+use maintenance::MaintenanceWindow;
+use runner_resources::{merge_runner_resources, validate_runner_resources};
+use runner_security::{RUNNER_SECURITY_CONTEXT_ENV, RunnerSecurityContext};
+use serde::de::DeserializeOwned;
 use serde_json::json;
 use snafu::{ResultExt, Snafu};
 use std::collections::BTreeMap;
@@ -27,31 +49,37 @@ use std::net::{AddrParseError, SocketAddr};
 use std::str::ParseBoolError;
 use std::sync::Arc;
 use std::time::Duration;
-use tracing::{error, info, warn};
+use tracing::{Span, error, info, instrument, warn};
 
 #[derive(Debug, Snafu)]
 enum Error {
     #[snafu(display("spec/hash error: {source}"))]
     Common { source: CommonError },
     #[snafu(display("failed to initialize Kubernetes client: {source}"))]
-    KubeClientInit { source: kube::Error },
+    KubeClientInit {
+        #[snafu(source(from(kube::Error, Box::new)))]
+        source: Box<kube::Error>,
+    },
     #[snafu(display("failed to patch FrontendIntegration status {namespace}/{name}: {source}"))]
     PatchFrontendIntegrationStatus {
         namespace: String,
         name: String,
-        source: kube::Error,
+        #[snafu(source(from(kube::Error, Box::new)))]
+        source: Box<kube::Error>,
     },
     #[snafu(display("failed to patch FrontendIntegration metadata {namespace}/{name}: {source}"))]
     PatchFrontendIntegrationMetadata {
         namespace: String,
         name: String,
-        source: kube::Error,
+        #[snafu(source(from(kube::Error, Box::new)))]
+        source: Box<kube::Error>,
     },
     #[snafu(display("failed to get FrontendIntegration {namespace}/{name}: {source}"))]
     GetFrontendIntegration {
         namespace: String,
         name: String,
-        source: kube::Error,
+        #[snafu(source(from(kube::Error, Box::new)))]
+        source: Box<kube::Error>,
     },
     #[snafu(display(
         "failed to serialize FrontendIntegration status patch for {namespace}/{name}: {source}"
@@ -72,31 +100,103 @@ enum Error {
         namespace: String,
         fi_name: String,
         spec_hash: String,
-        source: kube::Error,
+        #[snafu(source(from(kube::Error, Box::new)))]
+        source: Box<kube::Error>,
+    },
+    #[snafu(display("failed to list running build Jobs in {namespace}: {source}"))]
+    ListBuildJobs {
+        namespace: String,
+        #[snafu(source(from(kube::Error, Box::new)))]
+        source: Box<kube::Error>,
     },
     #[snafu(display("failed to get JSBundle {namespace}/{name}: {source}"))]
     GetJsBundle {
         namespace: String,
         name: String,
-        source: kube::Error,
+        #[snafu(source(from(kube::Error, Box::new)))]
+        source: Box<kube::Error>,
+    },
+    #[snafu(display("failed to list JSBundles for FrontendIntegration {fi_name}: {source}"))]
+    ListJsBundlesForFi {
+        fi_name: String,
+        #[snafu(source(from(kube::Error, Box::new)))]
+        source: Box<kube::Error>,
+    },
+    #[snafu(display("failed to delete orphaned JSBundle {namespace}/{name}: {source}"))]
+    DeleteOrphanJsBundle {
+        namespace: String,
+        name: String,
+        #[snafu(source(from(kube::Error, Box::new)))]
+        source: Box<kube::Error>,
     },
     #[snafu(display("failed to patch JSBundle {namespace}/{name}: {source}"))]
     PatchJsBundle {
         namespace: String,
         name: String,
-        source: kube::Error,
+        #[snafu(source(from(kube::Error, Box::new)))]
+        source: Box<kube::Error>,
+    },
+    #[snafu(display("failed to get bundle ConfigMap {namespace}/{name}: {source}"))]
+    GetBundleConfigMap {
+        namespace: String,
+        name: String,
+        #[snafu(source(from(kube::Error, Box::new)))]
+        source: Box<kube::Error>,
+    },
+    #[snafu(display("failed to delete stale build Job {namespace}/{name}: {source}"))]
+    DeleteStaleJob {
+        namespace: String,
+        name: String,
+        #[snafu(source(from(kube::Error, Box::new)))]
+        source: Box<kube::Error>,
+    },
+    #[snafu(display(
+        "failed to list Jobs in {namespace} for FrontendIntegration {fi_name}: {source}"
+    ))]
+    ListJobsForFi {
+        namespace: String,
+        fi_name: String,
+        #[snafu(source(from(kube::Error, Box::new)))]
+        source: Box<kube::Error>,
+    },
+    #[snafu(display("failed to read {kind} {namespace}/{name} of disabled integration: {source}"))]
+    GetDisabledResource {
+        kind: String,
+        namespace: String,
+        name: String,
+        #[snafu(source(from(kube::Error, Box::new)))]
+        source: Box<kube::Error>,
+    },
+    #[snafu(display(
+        "failed to delete {kind} {namespace}/{name} of disabled integration: {source}"
+    ))]
+    DeleteDisabledResource {
+        kind: String,
+        namespace: String,
+        name: String,
+        #[snafu(source(from(kube::Error, Box::new)))]
+        source: Box<kube::Error>,
+    },
+    #[snafu(display("failed to update suspend on Job {namespace}/{name}: {source}"))]
+    PatchJobSuspend {
+        namespace: String,
+        name: String,
+        #[snafu(source(from(kube::Error, Box::new)))]
+        source: Box<kube::Error>,
     },
     #[snafu(display("failed to create Job {namespace}/{name}: {source}"))]
     CreateJob {
         namespace: String,
         name: String,
-        source: kube::Error,
+        #[snafu(source(from(kube::Error, Box::new)))]
+        source: Box<kube::Error>,
     },
     #[snafu(display("failed to get existing Job after conflict {namespace}/{name}: {source}"))]
     GetJobAfterConflict {
         namespace: String,
         name: String,
-        source: kube::Error,
+        #[snafu(source(from(kube::Error, Box::new)))]
+        source: Box<kube::Error>,
     },
     #[snafu(display("invalid WEBHOOK_ENABLED value '{value}': {source}"))]
     InvalidWebhookEnabled {
@@ -121,6 +221,46 @@ enum Error {
         bind_addr: SocketAddr,
         source: std::io::Error,
     },
+    #[snafu(display("invalid METRICS_BIND_ADDR '{value}': {source}"))]
+    InvalidMetricsBindAddr {
+        value: String,
+        source: AddrParseError,
+    },
+    #[snafu(display("metrics server failed on {bind_addr}: {source}"))]
+    MetricsServer {
+        bind_addr: SocketAddr,
+        source: std::io::Error,
+    },
+    #[snafu(display("invalid DEBUG_BIND_ADDR '{value}': {message}"))]
+    InvalidDebugBindAddr { value: String, message: String },
+    #[snafu(display("debug server failed on {bind_addr}: {source}"))]
+    DebugServer {
+        bind_addr: SocketAddr,
+        source: std::io::Error,
+    },
+    #[snafu(display("invalid MAINTENANCE_WINDOW '{value}': {message}"))]
+    InvalidMaintenanceWindow { value: String, message: String },
+    #[snafu(display("invalid {RUNNER_SECURITY_CONTEXT_ENV}: {source}"))]
+    InvalidRunnerSecurityContext { source: serde_json::Error },
+    #[snafu(display("invalid RUNNER_NODE_SELECTOR, expected a JSON object of strings: {source}"))]
+    InvalidRunnerNodeSelector { source: serde_json::Error },
+    #[snafu(display("invalid RUNNER_TOLERATIONS, expected a JSON array of tolerations: {source}"))]
+    InvalidRunnerTolerations { source: serde_json::Error },
+    #[snafu(display("invalid RUNNER_RESOURCES, expected JSON ResourceRequirements: {source}"))]
+    InvalidRunnerResourcesJson { source: serde_json::Error },
+    #[snafu(display("invalid RUNNER_RESOURCES: {message}"))]
+    InvalidRunnerResources { message: String },
+    #[snafu(display("failed to read FrontendIntegration from stdin: {source}"))]
+    ReadRenderInput { source: std::io::Error },
+    #[snafu(display("failed to parse FrontendIntegration YAML/JSON: {source}"))]
+    ParseRenderInput { source: serde_yaml::Error },
+    #[snafu(display("failed to render ExtensionManifest: {source}"))]
+    RenderManifest {
+        #[snafu(source(from(ManifestRenderError, Box::new)))]
+        source: Box<ManifestRenderError>,
+    },
+    #[snafu(display("failed to write rendered manifest to stdout: {source}"))]
+    WriteRenderOutput { source: std::io::Error },
 }
 
 #[derive(Clone, Debug)]
@@ -128,29 +268,101 @@ struct ControllerConfig {
     work_namespace: String,
     runner_image: String,
     runner_service_account: Option<String>,
+    allowed_runner_service_accounts: Vec<String>,
     build_service_base_url: String,
     jsbundle_configmap_namespace: String,
+    allowed_bundle_namespaces: Vec<String>,
     jsbundle_config_key: String,
     build_service_timeout_seconds: u64,
     stale_check_grace_seconds: u64,
     reconcile_requeue_seconds: u64,
     job_active_deadline_seconds: i64,
     job_ttl_seconds_after_finished: Option<i32>,
+    maintenance_window: Option<MaintenanceWindow>,
+    failed_pod_log_tail_lines: i64,
+    verify_crd_exists: bool,
+    reconcile_concurrency: u16,
+    render_options: RenderOptions,
+    include_sourcemaps: bool,
+    runner_security_context: RunnerSecurityContext,
+    runner_priority_class: Option<String>,
+    runner_node_selector: BTreeMap<String, String>,
+    runner_tolerations: Vec<Toleration>,
+    runner_resources: ResourceRequirements,
+    max_concurrent_builds_per_namespace: usize,
+    bundle_preferred_entries: Vec<String>,
+    otel_exporter_endpoint: Option<String>,
+    // Copied verbatim into the runner Job, which only sees the env the controller gives it.
+    runner_passthrough_env: Vec<(String, String)>,
+    // Mounted read-only at BUILD_SERVICE_TLS_MOUNT_PATH for the BUILD_SERVICE_CA_FILE/CLIENT_* paths.
+    build_service_tls_secret: Option<String>,
 }
 
 impl ControllerConfig {
-    fn from_env() -> Self {
-        Self {
+    fn from_env() -> Result<Self, Error> {
+        let maintenance_window = env::var("MAINTENANCE_WINDOW")
+            .ok()
+            .filter(|value| !value.trim().is_empty())
+            .map(|value| {
+                value.parse::<MaintenanceWindow>().map_err(|message| {
+                    Error::InvalidMaintenanceWindow {
+                        value: value.clone(),
+                        message,
+                    }
+                })
+            })
+            .transpose()?;
+        // Fail at startup instead of creating Jobs that admission rejects forever.
+        let runner_security_context =
+            RunnerSecurityContext::from_json(env::var(RUNNER_SECURITY_CONTEXT_ENV).ok().as_deref())
+                .context(InvalidRunnerSecurityContextSnafu)?;
+        let runner_node_selector = parse_json_env(env::var("RUNNER_NODE_SELECTOR").ok().as_deref())
+            .context(InvalidRunnerNodeSelectorSnafu)?;
+        let runner_tolerations = parse_json_env(env::var("RUNNER_TOLERATIONS").ok().as_deref())
+            .context(InvalidRunnerTolerationsSnafu)?;
+        let runner_resources: ResourceRequirements =
+            parse_json_env(env::var("RUNNER_RESOURCES").ok().as_deref())
+                .context(InvalidRunnerResourcesJsonSnafu)?;
+        validate_runner_resources(
+            runner_resources
+                .requests
+                .as_ref()
+                .unwrap_or(&BTreeMap::new()),
+            runner_resources.limits.as_ref().unwrap_or(&BTreeMap::new()),
+        )
+        .map_err(|message| Error::InvalidRunnerResources { message })?;
+
+        Ok(Self {
             work_namespace: env::var("WORK_NAMESPACE")
                 .unwrap_or_else(|_| "extension-frontend-forge".to_string()),
             runner_image: env::var("RUNNER_IMAGE")
                 .unwrap_or_else(|_| "spike2044/frontend-forge-runner:latest".to_string()),
             runner_service_account: env::var("RUNNER_SERVICE_ACCOUNT").ok(),
+            allowed_runner_service_accounts: env::var("ALLOWED_RUNNER_SERVICE_ACCOUNTS")
+                .map(|value| {
+                    value
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|name| !name.is_empty())
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default(),
             build_service_base_url: env::var("BUILD_SERVICE_BASE_URL").unwrap_or_else(|_| {
                 "http://frontend-forge.extension-frontend-forge.svc".to_string()
             }),
             jsbundle_configmap_namespace: env::var("JSBUNDLE_CONFIGMAP_NAMESPACE")
                 .unwrap_or_else(|_| "extension-frontend-forge".to_string()),
+            allowed_bundle_namespaces: env::var("ALLOWED_BUNDLE_NAMESPACES")
+                .map(|value| {
+                    value
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|name| !name.is_empty())
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default(),
             jsbundle_config_key: env::var("JSBUNDLE_CONFIG_KEY")
                 .unwrap_or_else(|_| "index.js".to_string()),
             build_service_timeout_seconds: env::var("BUILD_SERVICE_TIMEOUT_SECONDS")
@@ -173,14 +385,131 @@ impl ControllerConfig {
                 .ok()
                 .and_then(|v| v.parse().ok())
                 .or(Some(DEFAULT_JOB_TTL_SECONDS_AFTER_FINISHED)),
+            maintenance_window,
+            failed_pod_log_tail_lines: env::var("FAILED_POD_LOG_TAIL_LINES")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(50),
+            verify_crd_exists: env::var("VERIFY_CRD_EXISTS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(false),
+            reconcile_concurrency: parse_reconcile_concurrency(
+                env::var("RECONCILE_CONCURRENCY").ok().as_deref(),
+            ),
+            render_options: RenderOptions::from_env(),
+            include_sourcemaps: env::var("INCLUDE_SOURCEMAPS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(false),
+            runner_security_context,
+            runner_priority_class: env::var("RUNNER_PRIORITY_CLASS")
+                .ok()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty()),
+            runner_node_selector,
+            runner_tolerations,
+            runner_resources,
+            max_concurrent_builds_per_namespace: env::var("MAX_CONCURRENT_BUILDS_PER_NAMESPACE")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(0),
+            bundle_preferred_entries: env::var("BUNDLE_PREFERRED_ENTRIES")
+                .map(|value| {
+                    value
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|entry| !entry.is_empty())
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            otel_exporter_endpoint: env::var(OTEL_ENDPOINT_ENV)
+                .ok()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty()),
+            runner_passthrough_env: RUNNER_PASSTHROUGH_ENV
+                .iter()
+                .filter_map(|name| {
+                    env::var(name)
+                        .ok()
+                        .filter(|value| !value.trim().is_empty())
+                        .map(|value| (name.to_string(), value))
+                })
+                .collect(),
+            build_service_tls_secret: env::var("BUILD_SERVICE_TLS_SECRET")
+                .ok()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty()),
+        })
+    }
+}
+
+#[cfg(test)]
+impl ControllerConfig {
+    fn for_tests() -> Self {
+        Self {
+            work_namespace: "extension-frontend-forge".to_string(),
+            runner_image: "runner:env".to_string(),
+            runner_service_account: None,
+            allowed_runner_service_accounts: vec![],
+            build_service_base_url: "http://build".to_string(),
+            jsbundle_configmap_namespace: "extension-frontend-forge".to_string(),
+            allowed_bundle_namespaces: vec![],
+            jsbundle_config_key: "index.js".to_string(),
+            build_service_timeout_seconds: 600,
+            stale_check_grace_seconds: 30,
+            reconcile_requeue_seconds: 5,
+            job_active_deadline_seconds: 300,
+            job_ttl_seconds_after_finished: Some(DEFAULT_JOB_TTL_SECONDS_AFTER_FINISHED),
+            maintenance_window: None,
+            failed_pod_log_tail_lines: 50,
+            verify_crd_exists: false,
+            reconcile_concurrency: DEFAULT_RECONCILE_CONCURRENCY,
+            render_options: RenderOptions::default(),
+            include_sourcemaps: false,
+            runner_security_context: RunnerSecurityContext::default(),
+            runner_priority_class: None,
+            runner_node_selector: BTreeMap::new(),
+            runner_tolerations: vec![],
+            runner_resources: ResourceRequirements::default(),
+            max_concurrent_builds_per_namespace: 0,
+            bundle_preferred_entries: vec![],
+            otel_exporter_endpoint: None,
+            runner_passthrough_env: vec![],
+            build_service_tls_secret: None,
         }
     }
 }
 
-#[derive(Clone)]
+// Unset or blank values fall back to the empty default; anything else must parse.
+fn parse_json_env<T: DeserializeOwned + Default>(
+    value: Option<&str>,
+) -> Result<T, serde_json::Error> {
+    match value.map(str::trim).filter(|value| !value.is_empty()) {
+        Some(value) => serde_json::from_str(value),
+        None => Ok(T::default()),
+    }
+}
+
+// kube-runtime treats 0 as unbounded, so the value is clamped to keep API-server load predictable.
+fn parse_reconcile_concurrency(value: Option<&str>) -> u16 {
+    value
+        .and_then(|v| v.trim().parse::<u16>().ok())
+        .unwrap_or(DEFAULT_RECONCILE_CONCURRENCY)
+        .clamp(1, MAX_RECONCILE_CONCURRENCY)
+}
+
+// Unrecognized values fall back to the human-readable format rather than failing startup.
+fn log_format_is_json(value: Option<&str>) -> bool {
+    value.is_some_and(|v| v.trim().eq_ignore_ascii_case("json"))
+}
+
 struct ContextData {
     client: Client,
-    config: ControllerConfig,
+    config: ConfigStore,
+    recorder: Recorder,
+    jsbundle_crd: crd_check::JsBundleCrdCache,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -193,10 +522,50 @@ enum ObservedJobPhase {
 
 const JSBUNDLE_STATE_AVAILABLE: &str = "Available";
 const JSBUNDLE_STATE_DISABLED: &str = "Disabled";
+const DISABLED_MESSAGE: &str = "Disabled";
+const JSBUNDLE_CONDITION_READY: &str = "Ready";
+const FAILED_POD_LOG_TAIL_MAX_BYTES: usize = 4096;
+const CONTROLLER_NAME: &str = "frontend-forge-controller";
+const CONDITIONS_FIELD_MANAGER: &str = "frontend-forge-controller-conditions";
+// Forwarded to build Jobs so the runner reaches the build service the same way the operator configured.
+const RUNNER_PASSTHROUGH_ENV: &[&str] = &[
+    "BUILD_SERVICE_PROXY",
+    "HTTP_PROXY",
+    "HTTPS_PROXY",
+    "NO_PROXY",
+    "http_proxy",
+    "https_proxy",
+    "no_proxy",
+    "BUILD_SERVICE_CA_FILE",
+    "BUILD_SERVICE_CLIENT_CERT",
+    "BUILD_SERVICE_CLIENT_KEY",
+    "BUILD_SERVICE_BASE_PATH",
+];
+const BUILD_SERVICE_TLS_VOLUME: &str = "build-service-tls";
+const BUILD_SERVICE_TLS_MOUNT_PATH: &str = "/etc/frontend-forge/build-service-tls";
+const BUILD_DEFERRED_MESSAGE: &str = "deferred: maintenance window";
+const BUILD_QUEUED_MESSAGE: &str = "Build queued";
+const BUILD_PAUSED_MESSAGE: &str = "Build paused";
+const CONDITION_PAUSED: &str = "Paused";
+const MAX_BUILD_QUEUE_BACKOFF_SECONDS: u64 = 120;
+// Nothing watches CRDs, so a missing JSBundle CRD is re-checked on this interval; it is also
+// how long one discovery result is reused across reconciles.
+const DEPENDENCY_RECHECK_SECONDS: u64 = 60;
+// The runner may still be relabeling the JSBundle when its Job reports success.
+const BUNDLE_HASH_MISMATCH_GRACE_SECONDS: i64 = 120;
+const REASON_BUNDLE_HASH_MISMATCH: &str = "BundleHashMismatch";
+// A Secret or ConfigMap the runner references may be created moments after the pod.
+const CONTAINER_START_GRACE_SECONDS: i64 = 120;
 const DEFAULT_JOB_TTL_SECONDS_AFTER_FINISHED: i32 = 60 * 60;
+const DEFAULT_RECONCILE_CONCURRENCY: u16 = 4;
+const MAX_RECONCILE_CONCURRENCY: u16 = 64;
+const REASON_SERVICE_ACCOUNT_NOT_ALLOWED: &str = "RunnerServiceAccountNotAllowed";
+const REASON_INVALID_BUILD_RESOURCES: &str = "InvalidBuildResources";
+const REASON_BUNDLE_NAME_CONFLICT: &str = "BundleNameConflict";
+const REASON_BUNDLE_NAMESPACE_NOT_ALLOWED: &str = "BundleNamespaceNotAllowed";
 
 fn build_spec_hash(fi: &FrontendIntegration) -> Result<String, CommonError> {
-    serializable_hash(&fi.spec.without_enabled())
+    serializable_hash(&fi.spec.build_identity())
 }
 
 fn install_rustls_crypto_provider() {
@@ -209,32 +578,73 @@ fn install_rustls_crypto_provider() {
 
 #[tokio::main]
 async fn main() -> Result<(), Error> {
+    if env::args().nth(1).as_deref() == Some(render::RENDER_MANIFEST_COMMAND) {
+        return render::render_manifest(std::io::stdin().lock(), std::io::stdout().lock());
+    }
+
     install_rustls_crypto_provider();
 
-    tracing_subscriber::fmt()
-        .with_env_filter(
-            tracing_subscriber::EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| "info,frontend_forge_controller=debug".into()),
-        )
-        .init();
+    // The reconcile span carries fi/work_namespace, so each JSON line names the object it is about.
+    let telemetry = telemetry::init(
+        CONTROLLER_NAME,
+        "info,frontend_forge_controller=debug",
+        log_format_is_json(env::var("LOG_FORMAT").ok().as_deref()),
+    );
+    let result = serve().await;
+    telemetry.shutdown();
+    result
+}
 
+async fn serve() -> Result<(), Error> {
     let client = Client::try_default().await.context(KubeClientInitSnafu)?;
     let ctx = Arc::new(ContextData {
         client: client.clone(),
-        config: ControllerConfig::from_env(),
+        config: ConfigStore::new(ControllerConfig::from_env()?),
+        recorder: Recorder::new(
+            client.clone(),
+            Reporter {
+                controller: CONTROLLER_NAME.to_string(),
+                instance: env::var("POD_NAME").ok(),
+            },
+        ),
+        jsbundle_crd: crd_check::JsBundleCrdCache::new(Duration::from_secs(
+            DEPENDENCY_RECHECK_SECONDS,
+        )),
     });
     let webhook_config = webhook::WebhookConfig::from_env()?;
+    let metrics_bind_addr = metrics::metrics_bind_addr_from_env()?;
+    let debug_bind_addr = debug::debug_bind_addr_from_env()?;
 
-    if webhook_config.enabled {
-        info!(bind_addr = %webhook_config.bind_addr, "admission webhook enabled");
-        tokio::try_join!(
-            run_controller(ctx),
-            webhook::run_webhook_server(webhook_config)
-        )?;
-    } else {
-        info!("admission webhook disabled");
-        run_controller(ctx).await?;
-    }
+    let webhook_server = async {
+        if webhook_config.enabled {
+            info!(bind_addr = %webhook_config.bind_addr, "admission webhook enabled");
+            webhook::run_webhook_server(webhook_config, ctx.clone()).await
+        } else {
+            info!("admission webhook disabled");
+            Ok(())
+        }
+    };
+    let metrics_server = async {
+        match metrics_bind_addr {
+            Some(bind_addr) => metrics::run_metrics_server(bind_addr).await,
+            None => {
+                info!("metrics endpoint disabled");
+                Ok(())
+            }
+        }
+    };
+    let debug_server = async {
+        match debug_bind_addr {
+            Some(bind_addr) => debug::run_debug_server(bind_addr, ctx.clone()).await,
+            None => Ok(()),
+        }
+    };
+    tokio::try_join!(
+        run_controller(ctx.clone()),
+        webhook_server,
+        metrics_server,
+        debug_server
+    )?;
 
     info!("controller shutdown complete");
 
@@ -243,35 +653,94 @@ async fn main() -> Result<(), Error> {
 
 async fn run_controller(ctx: Arc<ContextData>) -> Result<(), Error> {
     let client = ctx.client.clone();
+    // Watch scopes and concurrency are fixed for the process lifetime, so only env values apply.
+    let env_config = ctx.config.env().clone();
     let fi_api = Api::<FrontendIntegration>::all(client.clone());
-    let job_api = Api::<Job>::namespaced(client.clone(), &ctx.config.work_namespace);
-    Controller::new(fi_api, watcher::Config::default())
+    let job_api = Api::<Job>::namespaced(client.clone(), &env_config.work_namespace);
+    let configmap_api =
+        Api::<ConfigMap>::namespaced(client.clone(), &env_config.jsbundle_configmap_namespace);
+    let bundle_configmaps = watcher::Config::default().labels(&format!(
+        "{}={},{}",
+        LABEL_MANAGED_BY, MANAGED_BY_VALUE, LABEL_FI_NAME
+    ));
+    // JSBundles are not watched, so a missing CRD only holds builds in reconcile; this check
+    // also primes the discovery cache.
+    match ctx.jsbundle_crd.served(&client).await {
+        Ok(true) => {}
+        Ok(false) => warn!(
+            "{}; builds are held until it is installed",
+            crd_check::jsbundle_crd_missing_message()
+        ),
+        Err(err) => warn!(error = %err, "failed to discover the JSBundle CRD"),
+    }
+    info!(
+        concurrency = env_config.reconcile_concurrency,
+        "starting FrontendIntegration controller"
+    );
+    let mut controller = Controller::new(fi_api, watcher::Config::default())
+        .with_config(controller::Config::default().concurrency(env_config.reconcile_concurrency))
         .owns(job_api, watcher::Config::default())
+        .watches(configmap_api, bundle_configmaps.clone(), artifact_owner);
+    // FIs may only override into allowlisted namespaces, so drift there is watched as well.
+    for namespace in &env_config.allowed_bundle_namespaces {
+        if *namespace == env_config.jsbundle_configmap_namespace {
+            continue;
+        }
+        controller = controller.watches(
+            Api::<ConfigMap>::namespaced(client.clone(), namespace),
+            bundle_configmaps.clone(),
+            artifact_owner,
+        );
+    }
+    let controller = controller
         .shutdown_on_signal()
-        .run(reconcile, error_policy, ctx)
+        .run(reconcile, error_policy, ctx.clone())
         .for_each(|result| async move {
             match result {
                 Ok((obj_ref, action)) => info!(?obj_ref, ?action, "reconciled"),
                 Err(err) => error!(error = %err, "controller reconcile stream error"),
             }
-        })
-        .await;
+        });
+
+    tokio::select! {
+        _ = controller => {}
+        _ = forge_config::watch_forge_config(client, &ctx.config) => {
+            warn!("FrontendForgeConfig watch ended");
+        }
+    }
 
     Ok(())
 }
 
-fn error_policy(_fi: Arc<FrontendIntegration>, err: &Error, _ctx: Arc<ContextData>) -> Action {
-    warn!(error = %err, "reconcile failed; requeueing");
+fn artifact_owner(cm: ConfigMap) -> Option<ObjectRef<FrontendIntegration>> {
+    cm.labels()
+        .get(LABEL_FI_NAME)
+        .map(|fi_name| ObjectRef::<FrontendIntegration>::new(fi_name))
+}
+
+// Runs outside the reconcile span, so it repeats the span's fields itself.
+fn error_policy(fi: Arc<FrontendIntegration>, err: &Error, ctx: Arc<ContextData>) -> Action {
+    warn!(
+        fi = %fi.name_any(),
+        work_namespace = %ctx.config.env().work_namespace,
+        error = %err,
+        "reconcile failed; requeueing"
+    );
     Action::requeue(Duration::from_secs(10))
 }
 
+#[instrument(skip_all, fields(fi = %fi.name_any(), work_namespace = tracing::field::Empty))]
 async fn reconcile(fi: Arc<FrontendIntegration>, ctx: Arc<ContextData>) -> Result<Action, Error> {
     let fi_name = fi.name_any();
     let client = ctx.client.clone();
-    let work_ns = ctx.config.work_namespace.clone();
+    ctx.config.loaded().await;
+    let config = ctx.config.snapshot_for(&fi);
+    let work_ns = config.work_namespace.clone();
+    Span::current().record("work_namespace", work_ns.as_str());
 
     let fi_api = Api::<FrontendIntegration>::all(client.clone());
     let job_api = Api::<Job>::namespaced(client.clone(), &work_ns);
+    let pod_api = Api::<Pod>::namespaced(client.clone(), &work_ns);
     let bundle_api = Api::<JSBundle>::all(client.clone());
 
     if fi.meta().deletion_timestamp.is_some() {
@@ -279,6 +748,16 @@ async fn reconcile(fi: Arc<FrontendIntegration>, ctx: Arc<ContextData>) -> Resul
     }
 
     patch_fi_enabled_label_if_needed(&fi_api, &fi).await?;
+    let (fi, dependencies_ready) =
+        sync_dependencies_condition(&fi_api, &client, &ctx.jsbundle_crd, fi).await?;
+    if !dependencies_ready {
+        warn!(fi = %fi_name, "JSBundle CRD is not installed; holding builds");
+        let status = deferred_status(&fi, None, &crd_check::jsbundle_crd_missing_message());
+        patch_fi_status(&fi_api, &fi, status).await?;
+        return Ok(Action::requeue(Duration::from_secs(
+            DEPENDENCY_RECHECK_SECONDS,
+        )));
+    }
 
     let spec_hash = build_spec_hash(&fi).context(CommonSnafu)?;
     info!(
@@ -287,30 +766,209 @@ async fn reconcile(fi: Arc<FrontendIntegration>, ctx: Arc<ContextData>) -> Resul
         phase = ?fi.status.as_ref().map(|s| &s.phase),
         "reconcile started"
     );
-    let desired_bundle_name = default_bundle_name(&fi_name);
+    let desired_bundle_name = bundle_name_for(&fi);
 
     let current_bundle = get_bundle_opt(&bundle_api, &desired_bundle_name).await?;
+    // Another FI's bundle is never reclaimed around, torn down or adopted.
+    let bundle_conflict = current_bundle
+        .as_ref()
+        .and_then(|bundle| foreign_bundle_owner(bundle, &fi_name));
+    let current_bundle = current_bundle.filter(|_| bundle_conflict.is_none());
+    if let Some(bundle) = current_bundle.as_ref() {
+        reclaim_orphan_bundles(&bundle_api, &ctx.recorder, &fi, bundle).await?;
+    }
 
     if !fi.spec.enabled() {
-        if let Some(bundle) = current_bundle.as_ref() {
-            sync_jsbundle_enabled_state(&bundle_api, &fi, bundle, false).await?;
+        let deleted = teardown_disabled_fi(
+            &client,
+            &bundle_api,
+            &job_api,
+            &work_ns,
+            bundle_configmap_namespace_for(&fi, &config),
+            &fi_name,
+            current_bundle.as_ref(),
+        )
+        .await?;
+        if !deleted.is_empty() {
+            publish_toggle_event(
+                &ctx.recorder,
+                &fi,
+                "IntegrationDisabled",
+                format!(
+                    "Deleted {} because spec.enabled is false",
+                    deleted.join(", ")
+                ),
+            )
+            .await;
         }
-        patch_fi_status(&fi_api, &fi, disabled_status(&fi, current_bundle.as_ref())).await?;
+        patch_fi_status(&fi_api, &fi, disabled_status(&fi, None)).await?;
+        return Ok(Action::await_change());
+    }
+    if is_disabled_status(&fi) {
+        publish_toggle_event(
+            &ctx.recorder,
+            &fi,
+            "IntegrationEnabled",
+            "Rebuilding because spec.enabled is true again".to_string(),
+        )
+        .await;
+    }
+    if let Some(owner) = bundle_conflict {
+        let message = format!(
+            "JSBundle {desired_bundle_name} belongs to FrontendIntegration {owner}; set spec.bundleName to an unused name"
+        );
+        warn!(fi = %fi_name, message, "refusing to build into another FI's bundle");
+        publish_build_job_rejected(&ctx.recorder, &fi, REASON_BUNDLE_NAME_CONFLICT, &message).await;
+        let last_error = LastBuildError {
+            source: "controller".to_string(),
+            message,
+            reason: Some(REASON_BUNDLE_NAME_CONFLICT.to_string()),
+            occurred_at: Some(Utc::now()),
+            log_tail: None,
+        };
+        patch_fi_status(&fi_api, &fi, failed_status(&fi, &spec_hash, last_error)).await?;
         return Ok(Action::await_change());
     }
 
-    let needs_build = needs_new_build(&fi, &spec_hash, current_bundle.as_ref());
+    let artifact_drift = match current_bundle.as_ref() {
+        Some(bundle)
+            if fi_phase(&fi) == Some(FrontendIntegrationPhase::Succeeded)
+                && bundle_matches_spec_hash(bundle, &spec_hash) =>
+        {
+            bundle_artifact_drift(&client, bundle, &spec_hash).await?
+        }
+        _ => None,
+    };
+    let fi = if config.verify_crd_exists {
+        sync_crd_condition(&fi_api, &client, fi).await
+    } else {
+        fi
+    };
+    let fi = sync_paused_condition(&fi_api, fi).await?;
+    // Unconditional, so a Job suspended while the FI was in any other phase is resumed too.
+    sync_build_job_suspension(&job_api, &work_ns, &fi_name, &spec_hash, fi.spec.paused()).await?;
+    let needs_build =
+        artifact_drift.is_some() || needs_new_build(&fi, &spec_hash, current_bundle.as_ref());
     if needs_build {
         let existing_job = find_job_for_hash(&job_api, &work_ns, &fi_name, &spec_hash).await?;
+        // The finished Job still holds the deterministic name, so it has to go before a rebuild.
+        if let Some(reason) = artifact_drift.as_deref()
+            && let Some(job) = existing_job.as_ref().filter(|job| {
+                observed_job_phase(job.status.as_ref()) == ObservedJobPhase::Succeeded
+            })
+        {
+            delete_stale_job(&job_api, &work_ns, job).await?;
+            publish_artifact_drift(&ctx.recorder, &fi, reason).await;
+            return Ok(Action::requeue(Duration::from_secs(
+                config.reconcile_requeue_seconds,
+            )));
+        }
+        // Same for a forced rebuild, so the new Job gets a fresh run under the same name.
+        if force_rebuild_pending(&fi)
+            && let Some(job) = existing_job.as_ref().filter(|job| {
+                matches!(
+                    observed_job_phase(job.status.as_ref()),
+                    ObservedJobPhase::Succeeded | ObservedJobPhase::Failed
+                )
+            })
+        {
+            info!(fi = %fi_name, spec_hash, "forceRebuildToken changed; replacing finished build job");
+            delete_stale_job(&job_api, &work_ns, job).await?;
+            return Ok(Action::requeue(Duration::from_secs(
+                config.reconcile_requeue_seconds,
+            )));
+        }
+        // A build still running for an older spec can only publish an outdated bundle.
+        if !fi.spec.paused() {
+            let cancelled =
+                cancel_superseded_jobs(&job_api, &work_ns, &fi_name, &spec_hash).await?;
+            if !cancelled.is_empty() {
+                info!(fi = %fi_name, spec_hash, jobs = ?cancelled, "cancelled superseded build jobs");
+                patch_fi_status(&fi_api, &fi, superseded_status(&fi, &spec_hash, &cancelled))
+                    .await?;
+                return Ok(Action::requeue(Duration::from_secs(
+                    config.reconcile_requeue_seconds,
+                )));
+            }
+        }
         let chosen_job = if let Some(job) = existing_job
             .filter(|j| should_reuse_build_job(&fi, j, current_bundle.as_ref(), &spec_hash))
         {
             job
+        } else if let Some(status) = paused_hold_status(&fi, current_bundle.as_ref()) {
+            info!(fi = %fi_name, spec_hash, "paused; not creating a build job");
+            patch_fi_status(&fi_api, &fi, status).await?;
+            return Ok(Action::await_change());
         } else {
+            let job_inputs = bundle_configmap_namespace(&fi, &config)
+                .map_err(|message| (REASON_BUNDLE_NAMESPACE_NOT_ALLOWED, message))
+                .and_then(|_| {
+                    runner_service_account(&fi, &config)
+                        .map_err(|message| (REASON_SERVICE_ACCOUNT_NOT_ALLOWED, message))
+                })
+                .and_then(|service_account| {
+                    runner_resources(&fi, &config)
+                        .map(|resources| (service_account, resources))
+                        .map_err(|message| (REASON_INVALID_BUILD_RESOURCES, message))
+                });
+            let (service_account, resources) = match job_inputs {
+                Ok(inputs) => inputs,
+                Err((reason, message)) => {
+                    warn!(fi = %fi_name, message, "refusing to create build job");
+                    publish_build_job_rejected(&ctx.recorder, &fi, reason, &message).await;
+                    let last_error = LastBuildError {
+                        source: "controller".to_string(),
+                        message,
+                        reason: Some(reason.to_string()),
+                        occurred_at: Some(Utc::now()),
+                        log_tail: None,
+                    };
+                    patch_fi_status(&fi_api, &fi, failed_status(&fi, &spec_hash, last_error))
+                        .await?;
+                    return Ok(Action::await_change());
+                }
+            };
+            if let Some(remaining) = build_deferral(config.maintenance_window.as_ref(), Utc::now())
+            {
+                info!(
+                    fi = %fi_name,
+                    spec_hash,
+                    remaining_seconds = remaining.as_secs(),
+                    "maintenance window active; deferring build"
+                );
+                let status = deferred_status(&fi, current_bundle.as_ref(), BUILD_DEFERRED_MESSAGE);
+                patch_fi_status(&fi_api, &fi, status).await?;
+                return Ok(Action::requeue(remaining));
+            }
+            if config.max_concurrent_builds_per_namespace > 0 {
+                let running = count_running_build_jobs(&job_api, &work_ns).await?;
+                if let Some(delay) = build_queue_delay(
+                    running,
+                    config.max_concurrent_builds_per_namespace,
+                    config.reconcile_requeue_seconds,
+                ) {
+                    info!(
+                        fi = %fi_name,
+                        spec_hash,
+                        running,
+                        limit = config.max_concurrent_builds_per_namespace,
+                        "build concurrency limit reached; queueing build"
+                    );
+                    let message = format!(
+                        "{BUILD_QUEUED_MESSAGE}: {running}/{} build Jobs running in {work_ns}",
+                        config.max_concurrent_builds_per_namespace
+                    );
+                    let status = deferred_status(&fi, current_bundle.as_ref(), &message);
+                    patch_fi_status(&fi_api, &fi, status).await?;
+                    return Ok(Action::requeue(delay));
+                }
+            }
             let job_name = job_name(&fi_name, &spec_hash);
             let desired_job = make_build_job(
                 &fi,
-                &ctx.config,
+                &config,
+                service_account,
+                resources,
                 &job_name,
                 &desired_bundle_name,
                 &spec_hash,
@@ -318,1258 +976,4315 @@ async fn reconcile(fi: Arc<FrontendIntegration>, ctx: Arc<ContextData>) -> Resul
             create_or_get_job(&job_api, &work_ns, desired_job, &job_name).await?
         };
 
-        let status = building_status(
-            &fi,
-            &spec_hash,
-            &desired_bundle_name,
-            &chosen_job,
-            "Build in progress",
-        );
+        let message = if fi.spec.paused() {
+            BUILD_PAUSED_MESSAGE
+        } else {
+            "Build in progress"
+        };
+        let status = building_status(&fi, &spec_hash, &desired_bundle_name, &chosen_job, message);
         patch_fi_status(&fi_api, &fi, status).await?;
         return Ok(Action::requeue(Duration::from_secs(
-            ctx.config.reconcile_requeue_seconds,
+            config.reconcile_requeue_seconds,
         )));
     }
 
     let action = sync_status_from_children(
         &fi,
-        &fi_api,
-        &job_api,
-        &bundle_api,
-        &work_ns,
-        &desired_bundle_name,
-        &spec_hash,
-        ctx.config.reconcile_requeue_seconds,
+        ChildSync {
+            recorder: &ctx.recorder,
+            fi_api: &fi_api,
+            job_api: &job_api,
+            pod_api: &pod_api,
+            bundle_api: &bundle_api,
+            namespace: &work_ns,
+            bundle_name: &desired_bundle_name,
+            spec_hash: &spec_hash,
+            requeue_seconds: config.reconcile_requeue_seconds,
+            log_tail_lines: config.failed_pod_log_tail_lines,
+        },
     )
     .await?;
 
     Ok(action)
 }
 
-fn needs_new_build(fi: &FrontendIntegration, spec_hash: &str, bundle: Option<&JSBundle>) -> bool {
-    let status = fi.status.as_ref();
-    let observed_hash = status
-        .and_then(|s| s.observed_spec_hash.as_deref())
-        .or_else(|| status.and_then(|s| s.observed_manifest_hash.as_deref()));
-    let phase = status.map(|s| s.phase.clone());
-
-    let hash_changed = observed_hash != Some(spec_hash);
-    let pending_initial = status.is_none();
-    let missing_matching_bundle = observed_hash == Some(spec_hash)
-        && !matches!(
-            phase,
-            Some(FrontendIntegrationPhase::Building | FrontendIntegrationPhase::Failed)
-        )
-        && !bundle
-            .map(|bundle| bundle_matches_spec_hash(bundle, spec_hash))
-            .unwrap_or(false);
-
-    hash_changed || pending_initial || missing_matching_bundle
-}
-
-fn should_reuse_build_job(
-    fi: &FrontendIntegration,
-    job: &Job,
-    bundle: Option<&JSBundle>,
-    spec_hash: &str,
-) -> bool {
-    match observed_job_phase(job.status.as_ref()) {
-        ObservedJobPhase::Pending | ObservedJobPhase::Running => true,
-        ObservedJobPhase::Succeeded => {
-            let bundle_ready = bundle
-                .map(|bundle| bundle_matches_spec_hash(bundle, spec_hash))
-                .unwrap_or(false);
-            bundle_ready
-                && !matches!(
-                    fi.status.as_ref().map(|s| s.phase.clone()),
-                    Some(FrontendIntegrationPhase::Failed)
-                )
+// A failed discovery call is treated as installed so an API hiccup never holds builds.
+async fn sync_dependencies_condition(
+    fi_api: &Api<FrontendIntegration>,
+    client: &Client,
+    jsbundle_crd: &crd_check::JsBundleCrdCache,
+    fi: Arc<FrontendIntegration>,
+) -> Result<(Arc<FrontendIntegration>, bool), Error> {
+    let served = match jsbundle_crd.served(client).await {
+        Ok(served) => served,
+        Err(err) => {
+            warn!(fi = %fi.name_any(), error = %err, "failed to discover the JSBundle CRD");
+            return Ok((fi, true));
         }
-        ObservedJobPhase::Failed => false,
+    };
+    let current = current_conditions(&fi);
+    let conditions = crd_check::with_condition(
+        &current,
+        crd_check::CONDITION_DEPENDENCIES_READY,
+        Some(crd_check::dependencies_ready_condition(
+            &fi,
+            served,
+            Utc::now(),
+        )),
+    );
+    if conditions == current {
+        return Ok((fi, served));
     }
+    let fi_name = fi.name_any();
+    apply_fi_conditions(fi_api, &fi_name, &conditions)
+        .await
+        .with_context(|_| PatchFrontendIntegrationStatusSnafu {
+            namespace: fi.namespace().unwrap_or_else(|| "<cluster>".to_string()),
+            name: fi_name.clone(),
+        })?;
+
+    let mut updated = (*fi).clone();
+    updated
+        .status
+        .get_or_insert_with(Default::default)
+        .conditions = conditions;
+    Ok((Arc::new(updated), served))
 }
 
-async fn sync_status_from_children(
-    fi: &FrontendIntegration,
+// Advisory only: discovery or patch failures are logged and never block the build.
+async fn sync_crd_condition(
     fi_api: &Api<FrontendIntegration>,
-    job_api: &Api<Job>,
-    bundle_api: &Api<JSBundle>,
-    namespace: &str,
-    bundle_name: &str,
-    spec_hash: &str,
-    requeue_seconds: u64,
-) -> Result<Action, Error> {
+    client: &Client,
+    fi: Arc<FrontendIntegration>,
+) -> Arc<FrontendIntegration> {
     let fi_name = fi.name_any();
-    let current_job = find_job_for_hash(job_api, namespace, &fi_name, spec_hash).await?;
-
-    if let Some(job) = current_job {
-        match observed_job_phase(job.status.as_ref()) {
-            ObservedJobPhase::Pending | ObservedJobPhase::Running => {
-                let live_fi = get_live_fi(fi_api, &fi_name).await?;
-                let status =
-                    building_status(&live_fi, spec_hash, bundle_name, &job, "Build in progress");
-                patch_fi_status(fi_api, &live_fi, status).await?;
-                return Ok(Action::requeue(Duration::from_secs(requeue_seconds)));
-            }
-            ObservedJobPhase::Failed => {
-                let live_fi = get_live_fi(fi_api, &fi_name).await?;
-                let status = failed_status(
-                    &live_fi,
-                    spec_hash,
-                    failure_error_for_status(&live_fi, spec_hash, &job),
-                );
-                patch_fi_status(fi_api, &live_fi, status).await?;
-                return Ok(Action::await_change());
-            }
-            ObservedJobPhase::Succeeded => {
-                let bundle = get_bundle_opt(bundle_api, bundle_name).await?;
-                if let Some(bundle) = bundle {
-                    if bundle_matches_spec_hash(&bundle, spec_hash) {
-                        sync_jsbundle_enabled_state(bundle_api, fi, &bundle, true).await?;
-                        let status = succeeded_status(fi, spec_hash, &bundle, &job);
-                        patch_fi_status(fi_api, fi, status).await?;
-                        return Ok(Action::await_change());
-                    }
-                    let status = building_status(
-                        fi,
-                        spec_hash,
-                        bundle_name,
-                        &job,
-                        "Job succeeded; waiting for JSBundle with matching spec-hash",
-                    );
-                    patch_fi_status(fi_api, fi, status).await?;
-                    return Ok(Action::requeue(Duration::from_secs(requeue_seconds)));
-                }
-
-                let status = building_status(
-                    fi,
-                    spec_hash,
-                    bundle_name,
-                    &job,
-                    "Job succeeded; waiting for JSBundle materialization",
-                );
-                patch_fi_status(fi_api, fi, status).await?;
-                return Ok(Action::requeue(Duration::from_secs(requeue_seconds)));
-            }
+    let condition = match crd_check::crd_availability_condition(client, &fi, Utc::now()).await {
+        Ok(condition) => condition,
+        Err(err) => {
+            warn!(fi = %fi_name, error = %err, "failed to discover CRDs referenced by pages");
+            return fi;
         }
+    };
+    if let Some(condition) = condition.as_ref()
+        && condition.status != "True"
+    {
+        warn!(fi = %fi_name, message = ?condition.message, "referenced CRDs are missing");
     }
 
-    if let Some(bundle) = get_bundle_opt(bundle_api, bundle_name).await? {
-        if bundle_matches_spec_hash(&bundle, spec_hash) {
-            sync_jsbundle_enabled_state(bundle_api, fi, &bundle, true).await?;
-            let status = FrontendIntegrationStatus {
-                phase: FrontendIntegrationPhase::Succeeded,
-                observed_spec_hash: Some(spec_hash.to_string()),
-                observed_manifest_hash: bundle_manifest_hash(&bundle),
-                observed_generation: Some(fi.metadata.generation.unwrap_or_default()),
-                last_build: fi.status.as_ref().and_then(|s| s.last_build.clone()),
-                bundle_ref: Some(resource_ref(&bundle)),
-                last_error: None,
-                message: Some("JSBundle ready".to_string()),
-                conditions: vec![],
-            };
-            patch_fi_status(fi_api, fi, status).await?;
-        }
+    let current = current_conditions(&fi);
+    let conditions =
+        crd_check::with_condition(&current, crd_check::CONDITION_CRDS_AVAILABLE, condition);
+    if conditions == current {
+        return fi;
+    }
+    if let Err(err) = apply_fi_conditions(fi_api, &fi_name, &conditions).await {
+        warn!(fi = %fi_name, error = %err, "failed to patch CRD availability condition");
+        return fi;
     }
 
-    Ok(Action::await_change())
+    let mut updated = (*fi).clone();
+    updated
+        .status
+        .get_or_insert_with(Default::default)
+        .conditions = conditions;
+    Arc::new(updated)
 }
 
-async fn get_live_fi(
+async fn sync_paused_condition(
     fi_api: &Api<FrontendIntegration>,
-    fi_name: &str,
-) -> Result<FrontendIntegration, Error> {
-    fi_api
-        .get(fi_name)
+    fi: Arc<FrontendIntegration>,
+) -> Result<Arc<FrontendIntegration>, Error> {
+    let current = current_conditions(&fi);
+    let conditions = crd_check::with_condition(
+        &current,
+        CONDITION_PAUSED,
+        paused_condition(&fi, Utc::now()),
+    );
+    if conditions == current {
+        return Ok(fi);
+    }
+    let fi_name = fi.name_any();
+    apply_fi_conditions(fi_api, &fi_name, &conditions)
         .await
-        .with_context(|_| GetFrontendIntegrationSnafu {
-            namespace: "<cluster>".to_string(),
-            name: fi_name.to_string(),
-        })
+        .with_context(|_| PatchFrontendIntegrationStatusSnafu {
+            namespace: fi.namespace().unwrap_or_else(|| "<cluster>".to_string()),
+            name: fi_name.clone(),
+        })?;
+
+    let mut updated = (*fi).clone();
+    updated
+        .status
+        .get_or_insert_with(Default::default)
+        .conditions = conditions;
+    Ok(Arc::new(updated))
 }
 
-async fn find_job_for_hash(
-    job_api: &Api<Job>,
-    namespace: &str,
+// Only present while paused; resuming drops it instead of leaving Paused=False behind.
+fn paused_condition(fi: &FrontendIntegration, now: DateTime<Utc>) -> Option<SimpleCondition> {
+    if !fi.spec.paused() {
+        return None;
+    }
+    let since = current_conditions(fi)
+        .into_iter()
+        .find(|condition| condition.type_ == CONDITION_PAUSED && condition.status == "True")
+        .and_then(|condition| condition.last_transition_time)
+        .unwrap_or(now);
+    Some(SimpleCondition {
+        type_: CONDITION_PAUSED.to_string(),
+        status: "True".to_string(),
+        reason: Some("SpecPaused".to_string()),
+        message: Some("spec.paused is true; no new build Jobs are created".to_string()),
+        observed_generation: fi.metadata.generation,
+        last_transition_time: Some(since),
+    })
+}
+
+// Keeps the last observed hashes, so unpausing rebuilds whatever changed in the meantime.
+fn paused_hold_status(
+    fi: &FrontendIntegration,
+    bundle: Option<&JSBundle>,
+) -> Option<FrontendIntegrationStatus> {
+    fi.spec
+        .paused()
+        .then(|| deferred_status(fi, bundle, BUILD_PAUSED_MESSAGE))
+}
+
+// Conditions have their own field manager: the main status apply leaves them out, and an apply
+// under one manager drops whatever that manager owned but did not send.
+async fn apply_fi_conditions(
+    fi_api: &Api<FrontendIntegration>,
     fi_name: &str,
+    conditions: &[SimpleCondition],
+) -> Result<FrontendIntegration, kube::Error> {
+    let patch = json!({
+        "apiVersion": FrontendIntegration::api_version(&()),
+        "kind": FrontendIntegration::kind(&()),
+        "status": { "conditions": conditions },
+    });
+    fi_api
+        .patch_status(
+            fi_name,
+            &PatchParams::apply(CONDITIONS_FIELD_MANAGER).force(),
+            &Patch::Apply(&patch),
+        )
+        .await
+}
+
+fn current_conditions(fi: &FrontendIntegration) -> Vec<SimpleCondition> {
+    fi.status
+        .as_ref()
+        .map(|status| status.conditions.clone())
+        .unwrap_or_default()
+}
+
+fn fi_phase(fi: &FrontendIntegration) -> Option<FrontendIntegrationPhase> {
+    fi.status.as_ref().map(|status| status.phase.clone())
+}
+
+async fn bundle_artifact_drift(
+    client: &Client,
+    bundle: &JSBundle,
     spec_hash: &str,
-) -> Result<Option<Job>, Error> {
-    let selector = format!(
-        "{}={},{}={}",
-        LABEL_FI_NAME,
-        fi_name,
-        LABEL_SPEC_HASH,
-        hash_label_value(spec_hash)
-    );
-    let jobs = job_api
-        .list(&ListParams::default().labels(&selector))
+) -> Result<Option<String>, Error> {
+    let Some(key_ref) = bundle
+        .spec
+        .raw_from
+        .as_ref()
+        .and_then(|raw_from| raw_from.config_map_key_ref.as_ref())
+    else {
+        return Ok(None);
+    };
+    let configmap = Api::<ConfigMap>::namespaced(client.clone(), &key_ref.namespace)
+        .get_opt(&key_ref.name)
         .await
-        .with_context(|_| ListJobsForHashSnafu {
-            namespace: namespace.to_string(),
-            fi_name: fi_name.to_string(),
-            spec_hash: spec_hash.to_string(),
+        .with_context(|_| GetBundleConfigMapSnafu {
+            namespace: key_ref.namespace.clone(),
+            name: key_ref.name.clone(),
         })?;
-    let mut items = jobs.items;
-    items.sort_by_key(|j| j.metadata.creation_timestamp.clone());
-    let latest_job = items.pop();
-    if items.len() > 0 {
-        if let Some(job) = latest_job.as_ref() {
-            let job_name = job.name_any();
-            warn!(
-                fi = %fi_name,
-                job = %job_name,
-                "multiple jobs found for same spec_hash, using latest"
-            );
-        }
-    }
-    Ok(latest_job)
+    Ok(configmap_drift_reason(
+        key_ref,
+        configmap.as_ref(),
+        spec_hash,
+    ))
 }
 
-fn observed_job_phase(status: Option<&JobStatus>) -> ObservedJobPhase {
-    let Some(status) = status else {
-        return ObservedJobPhase::Pending;
+fn configmap_drift_reason(
+    key_ref: &JsBundleNamespacedKeyRef,
+    configmap: Option<&ConfigMap>,
+    spec_hash: &str,
+) -> Option<String> {
+    let location = format!("{}/{}", key_ref.namespace, key_ref.name);
+    let Some(configmap) = configmap else {
+        return Some(format!("bundle ConfigMap {location} was deleted"));
     };
-
-    if status.failed.unwrap_or(0) > 0 {
-        return ObservedJobPhase::Failed;
+    let Some(content) = configmap
+        .data
+        .as_ref()
+        .and_then(|data| data.get(&key_ref.key))
+    else {
+        return Some(format!(
+            "bundle ConfigMap {location} no longer has key '{}'",
+            key_ref.key
+        ));
+    };
+    if configmap.labels().get(LABEL_SPEC_HASH) != Some(&hash_label_value(spec_hash)) {
+        return Some(format!(
+            "bundle ConfigMap {location} no longer matches the current spec"
+        ));
     }
-    if status.succeeded.unwrap_or(0) > 0 {
-        return ObservedJobPhase::Succeeded;
+    // ConfigMaps written before the content hash existed are trusted as-is.
+    if let Some(expected) = configmap.annotations().get(ANNO_CONTENT_HASH)
+        && expected != &manifest_hash_from_content(content)
+    {
+        return Some(format!("bundle ConfigMap {location} was modified"));
     }
-    if status.active.unwrap_or(0) > 0 {
-        return ObservedJobPhase::Running;
+    None
+}
+
+// Jobs go first so no runner can republish the bundle mid-teardown, and artifacts go before the
+// JSBundle that points at them, so an interrupted teardown can resume.
+async fn teardown_disabled_fi(
+    client: &Client,
+    bundle_api: &Api<JSBundle>,
+    job_api: &Api<Job>,
+    work_ns: &str,
+    artifact_ns: &str,
+    fi_name: &str,
+    bundle: Option<&JSBundle>,
+) -> Result<Vec<String>, Error> {
+    let mut deleted = Vec::new();
+    let jobs = job_api
+        .list(&ListParams::default().labels(&format!("{LABEL_FI_NAME}={fi_name}")))
+        .await
+        .with_context(|_| ListJobsForFiSnafu {
+            namespace: work_ns.to_string(),
+            fi_name: fi_name.to_string(),
+        })?;
+    for job in jobs.items {
+        let name = job.name_any();
+        if delete_if_present(job_api, "Job", work_ns, &name).await? {
+            deleted.push(format!("Job {work_ns}/{name}"));
+        }
     }
 
-    if let Some(conditions) = &status.conditions {
-        for cond in conditions {
-            if cond.status != "True" {
-                continue;
-            }
-            if cond.type_ == "Failed" {
-                return ObservedJobPhase::Failed;
-            }
-            if cond.type_ == "Complete" {
-                return ObservedJobPhase::Succeeded;
+    if let Some(bundle) = bundle {
+        for (kind, key_ref) in bundle_artifact_refs(bundle, artifact_ns) {
+            let removed = if kind == "ConfigMap" {
+                delete_owned_artifact(
+                    &Api::<ConfigMap>::namespaced(client.clone(), &key_ref.namespace),
+                    kind,
+                    &key_ref.namespace,
+                    &key_ref.name,
+                    fi_name,
+                )
+                .await?
+            } else {
+                delete_owned_artifact(
+                    &Api::<Secret>::namespaced(client.clone(), &key_ref.namespace),
+                    kind,
+                    &key_ref.namespace,
+                    &key_ref.name,
+                    fi_name,
+                )
+                .await?
+            };
+            if removed {
+                deleted.push(format!("{kind} {}/{}", key_ref.namespace, key_ref.name));
             }
         }
+        let name = bundle.name_any();
+        if delete_if_present(bundle_api, "JSBundle", "<cluster>", &name).await? {
+            deleted.push(format!("JSBundle {name}"));
+        }
     }
+    Ok(deleted)
+}
 
-    ObservedJobPhase::Pending
+// `rawFrom` is writable by anyone who can edit the JSBundle, so only refs into the bundle
+// namespace are ever followed.
+fn bundle_artifact_refs<'a>(
+    bundle: &'a JSBundle,
+    artifact_ns: &str,
+) -> Vec<(&'static str, &'a JsBundleNamespacedKeyRef)> {
+    let Some(raw_from) = bundle.spec.raw_from.as_ref() else {
+        return Vec::new();
+    };
+    raw_from
+        .config_map_key_ref
+        .iter()
+        .map(|key_ref| ("ConfigMap", key_ref))
+        .chain(
+            raw_from
+                .secret_key_ref
+                .iter()
+                .map(|key_ref| ("Secret", key_ref)),
+        )
+        .filter(|(_, key_ref)| key_ref.namespace == artifact_ns)
+        .collect()
 }
 
-fn extract_job_message(job: &Job) -> Option<String> {
-    let status = job.status.as_ref()?;
-    if let Some(conditions) = &status.conditions {
-        if let Some(cond) = conditions
-            .iter()
-            .find(|c| c.status == "True" && c.type_ == "Failed")
-        {
-            return cond.message.clone().or_else(|| cond.reason.clone());
+async fn delete_owned_artifact<K>(
+    api: &Api<K>,
+    kind: &str,
+    namespace: &str,
+    name: &str,
+    fi_name: &str,
+) -> Result<bool, Error>
+where
+    K: Resource + Clone + DeserializeOwned + std::fmt::Debug,
+{
+    let object = api
+        .get_opt(name)
+        .await
+        .with_context(|_| GetDisabledResourceSnafu {
+            kind: kind.to_string(),
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+        })?;
+    match object {
+        Some(object) if is_owned_artifact(object.labels(), fi_name) => {
+            delete_if_present(api, kind, namespace, name).await
         }
+        Some(_) => {
+            warn!(fi = %fi_name, kind, namespace, name, "not deleting an artifact this FI does not own");
+            Ok(false)
+        }
+        None => Ok(false),
     }
-    None
 }
 
-fn extract_job_error(job: &Job) -> Option<LastBuildError> {
-    let status = job.status.as_ref()?;
-    let cond = status
-        .conditions
-        .as_ref()?
-        .iter()
-        .find(|c| c.status == "True" && c.type_ == "Failed")?;
-    let message = cond.message.clone().or_else(|| cond.reason.clone())?;
+fn is_owned_artifact(labels: &BTreeMap<String, String>, fi_name: &str) -> bool {
+    labels.get(LABEL_MANAGED_BY).map(String::as_str) == Some(MANAGED_BY_VALUE)
+        && labels.get(LABEL_FI_NAME).map(String::as_str) == Some(fi_name)
+}
 
-    Some(LastBuildError {
-        source: "job".to_string(),
-        message,
-        reason: cond.reason.clone(),
-        occurred_at: Some(Utc::now()),
+async fn delete_if_present<K>(
+    api: &Api<K>,
+    kind: &str,
+    namespace: &str,
+    name: &str,
+) -> Result<bool, Error>
+where
+    K: Clone + DeserializeOwned + std::fmt::Debug,
+{
+    match api.delete(name, &DeleteParams::background()).await {
+        Ok(_) => Ok(true),
+        Err(kube::Error::Api(ae)) if ae.code == 404 => Ok(false),
+        Err(source) => Err(Error::DeleteDisabledResource {
+            kind: kind.to_string(),
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+            source: Box::new(source),
+        }),
+    }
+}
+
+fn is_disabled_status(fi: &FrontendIntegration) -> bool {
+    fi.status.as_ref().is_some_and(|status| {
+        status.phase == FrontendIntegrationPhase::Pending
+            && status.message.as_deref() == Some(DISABLED_MESSAGE)
     })
 }
 
-fn failure_error_for_status(
+async fn publish_toggle_event(
+    recorder: &Recorder,
     fi: &FrontendIntegration,
+    reason: &str,
+    note: String,
+) {
+    info!(fi = %fi.name_any(), reason, %note, "integration toggled");
+    let event = Event {
+        type_: EventType::Normal,
+        reason: reason.to_string(),
+        note: Some(note),
+        action: "Toggle".to_string(),
+        secondary: None,
+    };
+    if let Err(err) = recorder.publish(&event, &fi.object_ref(&())).await {
+        warn!(error = %err, fi = %fi.name_any(), "failed to publish toggle event");
+    }
+}
+
+async fn publish_bundle_hash_mismatch(recorder: &Recorder, fi: &FrontendIntegration, note: &str) {
+    warn!(fi = %fi.name_any(), note, "JSBundle spec-hash does not match the succeeded build");
+    let event = Event {
+        type_: EventType::Warning,
+        reason: REASON_BUNDLE_HASH_MISMATCH.to_string(),
+        note: Some(note.to_string()),
+        action: "Reconcile".to_string(),
+        secondary: None,
+    };
+    if let Err(err) = recorder.publish(&event, &fi.object_ref(&())).await {
+        warn!(error = %err, fi = %fi.name_any(), "failed to publish bundle hash mismatch event");
+    }
+}
+
+async fn delete_stale_job(job_api: &Api<Job>, namespace: &str, job: &Job) -> Result<(), Error> {
+    let name = job.name_any();
+    match job_api.delete(&name, &DeleteParams::background()).await {
+        Ok(_) => Ok(()),
+        Err(kube::Error::Api(ae)) if ae.code == 404 => Ok(()),
+        Err(source) => Err(Error::DeleteStaleJob {
+            namespace: namespace.to_string(),
+            name,
+            source: Box::new(source),
+        }),
+    }
+}
+
+async fn cancel_superseded_jobs(
+    job_api: &Api<Job>,
+    namespace: &str,
+    fi_name: &str,
     spec_hash: &str,
-    job: &Job,
-) -> LastBuildError {
-    if let Some(last_error) = current_last_error(fi, spec_hash) {
-        return last_error;
+) -> Result<Vec<String>, Error> {
+    let jobs = job_api
+        .list(&ListParams::default().labels(&format!("{LABEL_FI_NAME}={fi_name}")))
+        .await
+        .with_context(|_| ListJobsForFiSnafu {
+            namespace: namespace.to_string(),
+            fi_name: fi_name.to_string(),
+        })?;
+    let mut cancelled = Vec::new();
+    for job in superseded_jobs(&jobs.items, spec_hash) {
+        delete_stale_job(job_api, namespace, job).await?;
+        cancelled.push(job.name_any());
     }
+    Ok(cancelled)
+}
 
-    extract_job_error(job).unwrap_or_else(|| LastBuildError {
-        source: "job".to_string(),
-        message: extract_job_message(job).unwrap_or_else(|| "Build job failed".to_string()),
-        reason: Some("JobFailed".to_string()),
-        occurred_at: Some(Utc::now()),
-    })
+// Finished Jobs of older hashes are history; only unfinished ones still compete for the bundle.
+// Jobs already being deleted were cancelled by an earlier reconcile and are left to terminate.
+fn superseded_jobs<'a>(jobs: &'a [Job], spec_hash: &str) -> Vec<&'a Job> {
+    let current = hash_label_value(spec_hash);
+    jobs.iter()
+        .filter(|job| job.labels().get(LABEL_SPEC_HASH) != Some(&current))
+        .filter(|job| job.metadata.deletion_timestamp.is_none())
+        .filter(|job| {
+            matches!(
+                observed_job_phase(job.status.as_ref()),
+                ObservedJobPhase::Pending | ObservedJobPhase::Running
+            )
+        })
+        .collect()
 }
 
-fn current_last_error(fi: &FrontendIntegration, spec_hash: &str) -> Option<LastBuildError> {
-    let status = fi.status.as_ref()?;
-    if status.observed_spec_hash.as_deref() != Some(spec_hash) {
-        return None;
+async fn publish_artifact_drift(recorder: &Recorder, fi: &FrontendIntegration, reason: &str) {
+    info!(fi = %fi.name_any(), reason, "bundle artifact drifted; rebuilding");
+    let event = Event {
+        type_: EventType::Warning,
+        reason: "BundleArtifactDrifted".to_string(),
+        note: Some(format!("{reason}; rebuilding")),
+        action: "Rebuild".to_string(),
+        secondary: None,
+    };
+    if let Err(err) = recorder.publish(&event, &fi.object_ref(&())).await {
+        warn!(error = %err, fi = %fi.name_any(), "failed to publish drift event");
     }
-    status.last_error.clone()
 }
 
-fn bundle_matches_spec_hash(bundle: &JSBundle, spec_hash: &str) -> bool {
-    let expected = hash_label_value(spec_hash);
-    bundle
-        .metadata
-        .labels
-        .as_ref()
-        .and_then(|labels| labels.get(LABEL_SPEC_HASH))
-        .map(|v| v == &expected)
-        .unwrap_or(false)
+fn needs_new_build(fi: &FrontendIntegration, spec_hash: &str, bundle: Option<&JSBundle>) -> bool {
+    let status = fi.status.as_ref();
+    let observed_hash = status
+        .and_then(|s| s.observed_spec_hash.as_deref())
+        .or_else(|| status.and_then(|s| s.observed_manifest_hash.as_deref()));
+    let phase = status.map(|s| s.phase.clone());
+
+    let hash_changed = observed_hash != Some(spec_hash);
+    let pending_initial = status.is_none();
+    let has_matching_bundle = bundle
+        .map(|bundle| bundle_matches_spec_hash(bundle, spec_hash))
+        .unwrap_or(false);
+    let force_rebuild = force_rebuild_pending(fi);
+    // A bundle already built for this hash is adopted by sync_status_from_children instead of
+    // being rebuilt, e.g. when the status was lost or reverted across a controller restart.
+    if has_matching_bundle && (hash_changed || pending_initial) && !force_rebuild {
+        return false;
+    }
+    let missing_matching_bundle = observed_hash == Some(spec_hash)
+        && !matches!(
+            phase,
+            Some(FrontendIntegrationPhase::Building | FrontendIntegrationPhase::Failed)
+        )
+        && !has_matching_bundle;
+
+    hash_changed || pending_initial || missing_matching_bundle || force_rebuild
 }
 
-fn labels_for(fi_name: &str, spec_hash: &str) -> BTreeMap<String, String> {
-    BTreeMap::from([
-        (LABEL_MANAGED_BY.to_string(), MANAGED_BY_VALUE.to_string()),
-        (LABEL_FI_NAME.to_string(), fi_name.to_string()),
-        (LABEL_SPEC_HASH.to_string(), hash_label_value(spec_hash)),
-    ])
+// Only a token that differs from the last one acted on counts; clearing it never rebuilds.
+fn force_rebuild_pending(fi: &FrontendIntegration) -> bool {
+    fi.spec
+        .force_rebuild_token
+        .as_deref()
+        .is_some_and(|token| Some(token) != observed_force_rebuild_token(fi).as_deref())
 }
 
-fn base_owner_ref<T>(obj: &T) -> Option<OwnerReference>
-where
-    T: Resource<DynamicType = ()>,
-{
-    obj.controller_owner_ref(&())
+fn observed_force_rebuild_token(fi: &FrontendIntegration) -> Option<String> {
+    fi.status
+        .as_ref()
+        .and_then(|status| status.observed_force_rebuild_token.clone())
 }
 
-fn make_build_job(
-    fi: &FrontendIntegration,
-    config: &ControllerConfig,
-    job_name: &str,
-    jsbundle_name: &str,
-    spec_hash: &str,
-) -> Job {
-    let fi_name = fi.name_any();
-    let mut labels = labels_for(&fi_name, spec_hash);
-    labels.insert(LABEL_BUILD_KIND.to_string(), BUILD_KIND_VALUE.to_string());
+// Jobs already running for the hash keep going; only new builds wait for the window to close.
+fn build_deferral(window: Option<&MaintenanceWindow>, now: DateTime<Utc>) -> Option<Duration> {
+    window.and_then(|window| window.remaining(now))
+}
 
-    let mut annotations = BTreeMap::new();
-    if let Some(generation) = fi.metadata.generation {
-        annotations.insert(ANNO_OBSERVED_GENERATION.to_string(), generation.to_string());
+// The backoff doubles with every Job above the limit so a large queue does not poll in lockstep.
+fn build_queue_delay(running: usize, limit: usize, base_seconds: u64) -> Option<Duration> {
+    if limit == 0 || running < limit {
+        return None;
     }
+    let excess = u32::try_from(running - limit).unwrap_or(u32::MAX).min(16);
+    let seconds = base_seconds
+        .max(1)
+        .saturating_mul(1 << excess)
+        .min(MAX_BUILD_QUEUE_BACKOFF_SECONDS);
+    Some(Duration::from_secs(seconds))
+}
 
-    let env = vec![
-        EnvVar {
-            name: "FI_NAME".to_string(),
-            value: Some(fi_name.clone()),
-            ..Default::default()
-        },
-        EnvVar {
-            name: "SPEC_HASH".to_string(),
-            value: Some(spec_hash.to_string()),
-            ..Default::default()
-        },
-        EnvVar {
-            name: "JSBUNDLE_NAME".to_string(),
-            value: Some(jsbundle_name.to_string()),
-            ..Default::default()
-        },
-        EnvVar {
-            name: "BUILD_SERVICE_BASE_URL".to_string(),
-            value: Some(config.build_service_base_url.clone()),
-            ..Default::default()
-        },
-        EnvVar {
-            name: "JSBUNDLE_CONFIGMAP_NAMESPACE".to_string(),
-            value: Some(config.jsbundle_configmap_namespace.clone()),
-            ..Default::default()
-        },
-        EnvVar {
-            name: "JSBUNDLE_CONFIG_KEY".to_string(),
-            value: Some(config.jsbundle_config_key.clone()),
-            ..Default::default()
-        },
-        EnvVar {
-            name: "BUILD_SERVICE_TIMEOUT_SECONDS".to_string(),
-            value: Some(config.build_service_timeout_seconds.to_string()),
-            ..Default::default()
-        },
-        EnvVar {
-            name: "STALE_CHECK_GRACE_SECONDS".to_string(),
-            value: Some(config.stale_check_grace_seconds.to_string()),
-            ..Default::default()
-        },
-    ];
-
-    let container = Container {
-        name: "runner".to_string(),
-        image: Some(config.runner_image.clone()),
-        env: Some(env),
-        ..Default::default()
-    };
+async fn count_running_build_jobs(job_api: &Api<Job>, namespace: &str) -> Result<usize, Error> {
+    let selector = format!("{LABEL_BUILD_KIND}={BUILD_KIND_VALUE}");
+    let jobs = job_api
+        .list(&ListParams::default().labels(&selector))
+        .await
+        .with_context(|_| ListBuildJobsSnafu {
+            namespace: namespace.to_string(),
+        })?;
+    Ok(jobs
+        .items
+        .iter()
+        .filter(|job| {
+            matches!(
+                observed_job_phase(job.status.as_ref()),
+                ObservedJobPhase::Pending | ObservedJobPhase::Running
+            )
+        })
+        .count())
+}
 
-    Job {
-        metadata: ObjectMeta {
-            name: Some(job_name.to_string()),
-            namespace: Some(config.work_namespace.clone()),
-            labels: Some(labels),
-            annotations: Some(annotations),
-            owner_references: base_owner_ref(fi).map(|o| vec![o]),
-            ..Default::default()
-        },
-        spec: Some(JobSpec {
-            active_deadline_seconds: Some(config.job_active_deadline_seconds),
-            ttl_seconds_after_finished: config.job_ttl_seconds_after_finished,
-            template: PodTemplateSpec {
-                metadata: Some(ObjectMeta {
-                    labels: Some(BTreeMap::from([(
-                        "app.kubernetes.io/name".to_string(),
-                        "frontend-forge-runner".to_string(),
-                    )])),
-                    ..Default::default()
-                }),
-                spec: Some(PodSpec {
-                    restart_policy: Some("Never".to_string()),
-                    service_account_name: config.runner_service_account.clone(),
-                    containers: vec![container],
-                    ..Default::default()
-                }),
-            },
-            backoff_limit: Some(0),
-            ..Default::default()
-        }),
-        status: None,
+fn should_reuse_build_job(
+    fi: &FrontendIntegration,
+    job: &Job,
+    bundle: Option<&JSBundle>,
+    spec_hash: &str,
+) -> bool {
+    match observed_job_phase(job.status.as_ref()) {
+        ObservedJobPhase::Pending | ObservedJobPhase::Running => true,
+        ObservedJobPhase::Succeeded => {
+            let bundle_ready = bundle
+                .map(|bundle| bundle_matches_spec_hash(bundle, spec_hash))
+                .unwrap_or(false);
+            bundle_ready
+                && !matches!(
+                    fi.status.as_ref().map(|s| s.phase.clone()),
+                    Some(FrontendIntegrationPhase::Failed)
+                )
+        }
+        ObservedJobPhase::Failed => false,
     }
 }
 
-async fn create_or_get_job(
-    job_api: &Api<Job>,
-    namespace: &str,
-    job: Job,
-    name: &str,
-) -> Result<Job, Error> {
-    match job_api.create(&PostParams::default(), &job).await {
-        Ok(created) => Ok(created),
-        Err(kube::Error::Api(ae)) if ae.code == 409 => {
-            Ok(job_api
-                .get(name)
-                .await
-                .with_context(|_| GetJobAfterConflictSnafu {
-                    namespace: namespace.to_string(),
-                    name: name.to_string(),
-                })?)
+// What `sync_status_from_children` reads and writes for one reconcile of an FI.
+struct ChildSync<'a> {
+    recorder: &'a Recorder,
+    fi_api: &'a Api<FrontendIntegration>,
+    job_api: &'a Api<Job>,
+    pod_api: &'a Api<Pod>,
+    bundle_api: &'a Api<JSBundle>,
+    namespace: &'a str,
+    bundle_name: &'a str,
+    spec_hash: &'a str,
+    requeue_seconds: u64,
+    log_tail_lines: i64,
+}
+
+async fn sync_status_from_children(
+    fi: &FrontendIntegration,
+    children: ChildSync<'_>,
+) -> Result<Action, Error> {
+    let ChildSync {
+        recorder,
+        fi_api,
+        job_api,
+        pod_api,
+        bundle_api,
+        namespace,
+        bundle_name,
+        spec_hash,
+        requeue_seconds,
+        log_tail_lines,
+    } = children;
+    let fi_name = fi.name_any();
+    let current_job = find_job_for_hash(job_api, namespace, &fi_name, spec_hash).await?;
+
+    if let Some(job) = current_job {
+        match observed_job_phase(job.status.as_ref()) {
+            ObservedJobPhase::Pending | ObservedJobPhase::Running => {
+                let live_fi = get_live_fi(fi_api, &fi_name).await?;
+                // With backoffLimit 0 a pod stuck in e.g. ImagePullBackOff never fails the Job;
+                // delete it so the FI fails now instead of at activeDeadlineSeconds. Pods are not
+                // watched, so the requeue below is what re-runs this check.
+                if let Some(failure) = newest_job_pod(pod_api, &job)
+                    .await
+                    .and_then(|pod| settled_stuck_pod_failure(&pod, Utc::now()))
+                {
+                    delete_stale_job(job_api, namespace, &job).await?;
+                    let status = failed_status(&live_fi, spec_hash, failure.into_last_error(None));
+                    patch_fi_status(fi_api, &live_fi, status).await?;
+                    return Ok(Action::await_change());
+                }
+                let status =
+                    building_status(&live_fi, spec_hash, bundle_name, &job, "Build in progress");
+                patch_fi_status(fi_api, &live_fi, status).await?;
+                return Ok(Action::requeue(Duration::from_secs(requeue_seconds)));
+            }
+            ObservedJobPhase::Failed => {
+                let live_fi = get_live_fi(fi_api, &fi_name).await?;
+                let mut last_error = failure_error_for_status(&live_fi, spec_hash, &job);
+                if last_error.log_tail.is_none()
+                    && let Some(pod) = newest_job_pod(pod_api, &job).await
+                {
+                    // The Job condition only says the backoff limit was hit; the pod knows why.
+                    if last_error.source == "job"
+                        && let Some(failure) = pod_failure(&pod)
+                    {
+                        last_error = failure.into_last_error(None);
+                    }
+                    last_error.log_tail = failed_pod_log_tail(pod_api, &pod, log_tail_lines).await;
+                }
+                let status = failed_status(&live_fi, spec_hash, last_error);
+                patch_fi_status(fi_api, &live_fi, status).await?;
+                return Ok(Action::await_change());
+            }
+            ObservedJobPhase::Succeeded => {
+                let bundle = get_bundle_opt(bundle_api, bundle_name).await?;
+                if let Some(bundle) = bundle {
+                    let Some(mismatch) = bundle_hash_mismatch(&job, &bundle, spec_hash, Utc::now())
+                    else {
+                        sync_jsbundle_enabled_state(bundle_api, fi, &bundle, true).await?;
+                        if let Some(reason) = bundle_not_ready_reason(&bundle) {
+                            let status = building_status(
+                                fi,
+                                spec_hash,
+                                bundle_name,
+                                &job,
+                                &format!("Job succeeded; JSBundle not ready: {reason}"),
+                            );
+                            patch_fi_status(fi_api, fi, status).await?;
+                            return Ok(Action::requeue(Duration::from_secs(requeue_seconds)));
+                        }
+                        let status = succeeded_status(fi, spec_hash, &bundle, &job);
+                        patch_fi_status(fi_api, fi, status).await?;
+                        return Ok(Action::await_change());
+                    };
+                    let message = format!(
+                        "Job succeeded but JSBundle spec-hash is {}, expected {}",
+                        mismatch.actual.as_deref().unwrap_or("<none>"),
+                        hash_label_value(spec_hash)
+                    );
+                    if mismatch.grace_expired {
+                        if current_last_error(fi, spec_hash).is_some_and(|error| {
+                            error.reason.as_deref() == Some(REASON_BUNDLE_HASH_MISMATCH)
+                        }) {
+                            return Ok(Action::await_change());
+                        }
+                        let message =
+                            format!("{message} after {BUNDLE_HASH_MISMATCH_GRACE_SECONDS}s");
+                        publish_bundle_hash_mismatch(recorder, fi, &message).await;
+                        let last_error = LastBuildError {
+                            source: "controller".to_string(),
+                            message,
+                            reason: Some(REASON_BUNDLE_HASH_MISMATCH.to_string()),
+                            occurred_at: Some(Utc::now()),
+                            log_tail: None,
+                        };
+                        let status = failed_status(fi, spec_hash, last_error);
+                        patch_fi_status(fi_api, fi, status).await?;
+                        return Ok(Action::await_change());
+                    }
+                    let message = format!("{message}; waiting for the runner");
+                    // Only the first observation is announced; requeues keep the same message.
+                    if fi.status.as_ref().and_then(|s| s.message.as_deref())
+                        != Some(message.as_str())
+                    {
+                        publish_bundle_hash_mismatch(recorder, fi, &message).await;
+                    }
+                    let status = building_status(fi, spec_hash, bundle_name, &job, &message);
+                    patch_fi_status(fi_api, fi, status).await?;
+                    return Ok(Action::requeue(Duration::from_secs(requeue_seconds)));
+                }
+
+                let status = building_status(
+                    fi,
+                    spec_hash,
+                    bundle_name,
+                    &job,
+                    "Job succeeded; waiting for JSBundle materialization",
+                );
+                patch_fi_status(fi_api, fi, status).await?;
+                return Ok(Action::requeue(Duration::from_secs(requeue_seconds)));
+            }
+        }
+    }
+
+    if let Some(bundle) = get_bundle_opt(bundle_api, bundle_name).await?
+        && bundle_matches_spec_hash(&bundle, spec_hash)
+    {
+        sync_jsbundle_enabled_state(bundle_api, fi, &bundle, true).await?;
+        let not_ready = bundle_not_ready_reason(&bundle);
+        let status = FrontendIntegrationStatus {
+            phase: if not_ready.is_some() {
+                FrontendIntegrationPhase::Building
+            } else {
+                FrontendIntegrationPhase::Succeeded
+            },
+            observed_spec_hash: Some(spec_hash.to_string()),
+            observed_manifest_hash: bundle_manifest_hash(&bundle),
+            observed_generation: Some(fi.metadata.generation.unwrap_or_default()),
+            observed_force_rebuild_token: observed_force_rebuild_token(fi),
+            last_build: fi.status.as_ref().and_then(|s| s.last_build.clone()),
+            bundle_ref: Some(resource_ref(&bundle)),
+            url: bundle_url(&bundle),
+            effective_placements: fi.spec.effective_placements(),
+            last_error: None,
+            message: Some(match not_ready.as_deref() {
+                Some(reason) => format!("JSBundle not ready: {reason}"),
+                None => "JSBundle ready".to_string(),
+            }),
+            conditions: current_conditions(fi),
+        };
+        patch_fi_status(fi_api, fi, status).await?;
+        if not_ready.is_some() {
+            return Ok(Action::requeue(Duration::from_secs(requeue_seconds)));
         }
-        Err(err) => Err(Error::CreateJob {
-            namespace: namespace.to_string(),
-            name: name.to_string(),
-            source: err,
-        }),
     }
+
+    Ok(Action::await_change())
 }
 
-async fn get_bundle_opt(bundle_api: &Api<JSBundle>, name: &str) -> Result<Option<JSBundle>, Error> {
-    bundle_api
-        .get_opt(name)
+async fn get_live_fi(
+    fi_api: &Api<FrontendIntegration>,
+    fi_name: &str,
+) -> Result<FrontendIntegration, Error> {
+    fi_api
+        .get(fi_name)
         .await
-        .with_context(|_| GetJsBundleSnafu {
+        .with_context(|_| GetFrontendIntegrationSnafu {
             namespace: "<cluster>".to_string(),
-            name: name.to_string(),
+            name: fi_name.to_string(),
         })
 }
 
-fn enabled_label_value(enabled: bool) -> &'static str {
-    if enabled { "true" } else { "false" }
-}
-
-async fn patch_fi_enabled_label_if_needed(
-    fi_api: &Api<FrontendIntegration>,
-    fi: &FrontendIntegration,
+// Suspending stops the runner Pod; resuming also restarts the Job's activeDeadlineSeconds.
+async fn sync_build_job_suspension(
+    job_api: &Api<Job>,
+    namespace: &str,
+    fi_name: &str,
+    spec_hash: &str,
+    paused: bool,
 ) -> Result<(), Error> {
-    let desired = enabled_label_value(fi.spec.enabled());
-    let current = fi
-        .metadata
-        .labels
-        .as_ref()
-        .and_then(|labels| labels.get(LABEL_ENABLED))
-        .map(String::as_str);
-    if current == Some(desired) {
+    let Some(job) = find_job_for_hash(job_api, namespace, fi_name, spec_hash).await? else {
         return Ok(());
-    }
-
-    let fi_name = fi.name_any();
-    let namespace = fi.namespace().unwrap_or_else(|| "<cluster>".to_string());
-    let patch = json!({
-        "metadata": {
-            "labels": {
-                LABEL_ENABLED: desired,
-            }
-        }
-    });
-    fi_api
-        .patch(&fi_name, &PatchParams::default(), &Patch::Merge(&patch))
+    };
+    let Some(suspend) = job_suspend_patch(&job, paused) else {
+        return Ok(());
+    };
+    let name = job.name_any();
+    info!(fi = %fi_name, job = %name, suspend, "updating build Job suspension");
+    job_api
+        .patch(
+            &name,
+            &PatchParams::default(),
+            &Patch::Merge(json!({ "spec": { "suspend": suspend } })),
+        )
         .await
-        .with_context(|_| PatchFrontendIntegrationMetadataSnafu {
-            namespace,
-            name: fi_name.clone(),
+        .with_context(|_| PatchJobSuspendSnafu {
+            namespace: namespace.to_string(),
+            name: name.clone(),
         })?;
     Ok(())
 }
 
-async fn sync_jsbundle_enabled_state(
-    bundle_api: &Api<JSBundle>,
-    fi: &FrontendIntegration,
-    bundle: &JSBundle,
-    enabled: bool,
-) -> Result<(), Error> {
-    patch_jsbundle_owner_ref_if_needed(bundle_api, fi, bundle).await?;
-    patch_jsbundle_enabled_label_if_needed(bundle_api, bundle, enabled).await?;
-    let desired_state = if enabled {
-        JSBUNDLE_STATE_AVAILABLE
-    } else {
-        JSBUNDLE_STATE_DISABLED
-    };
-    patch_jsbundle_state_if_needed(bundle_api, bundle, desired_state).await?;
-    Ok(())
+// Finished Jobs are left alone; only the suspend flag of an unfinished Job follows `paused`.
+fn job_suspend_patch(job: &Job, paused: bool) -> Option<bool> {
+    if matches!(
+        observed_job_phase(job.status.as_ref()),
+        ObservedJobPhase::Succeeded | ObservedJobPhase::Failed
+    ) {
+        return None;
+    }
+    let suspended = job
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.suspend)
+        .unwrap_or(false);
+    (suspended != paused).then_some(paused)
 }
 
-async fn patch_jsbundle_owner_ref_if_needed(
-    bundle_api: &Api<JSBundle>,
-    fi: &FrontendIntegration,
-    bundle: &JSBundle,
-) -> Result<(), Error> {
-    let Some(owner_ref) = base_owner_ref(fi) else {
-        return Ok(());
-    };
-
-    let mut owners = bundle.metadata.owner_references.clone().unwrap_or_default();
-    if owners.iter().any(|owner| owner.uid == owner_ref.uid) {
-        return Ok(());
-    }
-    owners.push(owner_ref);
-
-    let name = bundle.name_any();
-    let patch = json!({
-        "metadata": {
-            "ownerReferences": owners,
-        }
-    });
-    match bundle_api
-        .patch(&name, &PatchParams::default(), &Patch::Merge(&patch))
+async fn find_job_for_hash(
+    job_api: &Api<Job>,
+    namespace: &str,
+    fi_name: &str,
+    spec_hash: &str,
+) -> Result<Option<Job>, Error> {
+    let selector = format!(
+        "{}={},{}={}",
+        LABEL_FI_NAME,
+        fi_name,
+        LABEL_SPEC_HASH,
+        hash_label_value(spec_hash)
+    );
+    let jobs = job_api
+        .list(&ListParams::default().labels(&selector))
         .await
+        .with_context(|_| ListJobsForHashSnafu {
+            namespace: namespace.to_string(),
+            fi_name: fi_name.to_string(),
+            spec_hash: spec_hash.to_string(),
+        })?;
+    let count = jobs.items.len();
+    let job = select_job_for_hash(jobs.items);
+    if count > 1
+        && let Some(job) = job.as_ref()
     {
-        Ok(_) => Ok(()),
-        Err(kube::Error::Api(ae)) if ae.code == 404 => Ok(()),
-        Err(source) => Err(Error::PatchJsBundle {
-            namespace: "<cluster>".to_string(),
-            name,
-            source,
-        }),
+        let job_name = job.name_any();
+        warn!(
+            fi = %fi_name,
+            job = %job_name,
+            "multiple jobs found for same spec_hash, using latest non-failed"
+        );
     }
+    Ok(job)
 }
 
-async fn patch_jsbundle_enabled_label_if_needed(
-    bundle_api: &Api<JSBundle>,
-    bundle: &JSBundle,
-    enabled: bool,
-) -> Result<(), Error> {
-    let desired = enabled_label_value(enabled);
-    let current = bundle
-        .metadata
-        .labels
-        .as_ref()
-        .and_then(|labels| labels.get(LABEL_ENABLED))
-        .map(String::as_str);
-    if current == Some(desired) {
-        return Ok(());
-    }
-
-    let name = bundle.name_any();
-    let patch = json!({
-        "metadata": {
-            "labels": {
-                LABEL_ENABLED: desired,
-            }
-        }
+// Retries leave failed Jobs behind for the same hash; one that can still produce (or already
+// produced) the bundle wins over any failed one, newest first within each group. Timestamps
+// only have second precision, so the name breaks ties to keep the pick stable across reconciles.
+fn select_job_for_hash(mut jobs: Vec<Job>) -> Option<Job> {
+    jobs.sort_by_cached_key(|job| {
+        (
+            observed_job_phase(job.status.as_ref()) != ObservedJobPhase::Failed,
+            job.metadata.creation_timestamp.clone(),
+            job.name_any(),
+        )
     });
-    match bundle_api
-        .patch(&name, &PatchParams::default(), &Patch::Merge(&patch))
-        .await
-    {
-        Ok(_) => Ok(()),
-        Err(kube::Error::Api(ae)) if ae.code == 404 => Ok(()),
-        Err(source) => Err(Error::PatchJsBundle {
-            namespace: "<cluster>".to_string(),
-            name,
-            source,
-        }),
-    }
+    jobs.pop()
 }
 
-async fn patch_jsbundle_state_if_needed(
-    bundle_api: &Api<JSBundle>,
-    bundle: &JSBundle,
-    desired_state: &str,
-) -> Result<(), Error> {
-    let current = bundle
-        .status
-        .as_ref()
-        .and_then(|status| status.state.as_deref());
-    if current == Some(desired_state) {
-        return Ok(());
+fn observed_job_phase(status: Option<&JobStatus>) -> ObservedJobPhase {
+    let Some(status) = status else {
+        return ObservedJobPhase::Pending;
+    };
+
+    if status.failed.unwrap_or(0) > 0 {
+        return ObservedJobPhase::Failed;
+    }
+    if status.succeeded.unwrap_or(0) > 0 {
+        return ObservedJobPhase::Succeeded;
+    }
+    if status.active.unwrap_or(0) > 0 {
+        return ObservedJobPhase::Running;
     }
 
-    let name = bundle.name_any();
-    let patch = json!({
-        "status": {
-            "state": desired_state,
-        }
-    });
-    match bundle_api
-        .patch_status(&name, &PatchParams::default(), &Patch::Merge(&patch))
-        .await
-    {
-        Ok(_) => Ok(()),
-        Err(kube::Error::Api(ae)) if ae.code == 404 => {
-            match bundle_api
-                .patch(&name, &PatchParams::default(), &Patch::Merge(&patch))
-                .await
-            {
-                Ok(_) => Ok(()),
-                Err(kube::Error::Api(ae)) if ae.code == 404 => Ok(()),
-                Err(source) => Err(Error::PatchJsBundle {
-                    namespace: "<cluster>".to_string(),
-                    name,
-                    source,
-                }),
+    if let Some(conditions) = &status.conditions {
+        for cond in conditions {
+            if cond.status != "True" {
+                continue;
+            }
+            if cond.type_ == "Failed" {
+                return ObservedJobPhase::Failed;
+            }
+            if cond.type_ == "Complete" {
+                return ObservedJobPhase::Succeeded;
             }
         }
-        Err(source) => Err(Error::PatchJsBundle {
-            namespace: "<cluster>".to_string(),
-            name,
-            source,
-        }),
     }
-}
 
-fn resource_ref<K: ResourceExt>(obj: &K) -> ResourceRef {
-    ResourceRef {
-        name: obj.name_any(),
-        namespace: obj.namespace(),
-        uid: obj.meta().uid.clone(),
-    }
+    ObservedJobPhase::Pending
 }
 
-fn disabled_status(
-    fi: &FrontendIntegration,
-    bundle: Option<&JSBundle>,
-) -> FrontendIntegrationStatus {
-    FrontendIntegrationStatus {
-        phase: FrontendIntegrationPhase::Pending,
-        observed_spec_hash: fi
-            .status
-            .as_ref()
-            .and_then(|s| s.observed_spec_hash.clone()),
-        observed_manifest_hash: fi
-            .status
-            .as_ref()
-            .and_then(|s| s.observed_manifest_hash.clone()),
-        observed_generation: Some(fi.metadata.generation.unwrap_or_default()),
-        last_build: None,
-        bundle_ref: bundle.map(resource_ref),
-        last_error: None,
-        message: Some("Disabled".to_string()),
-        conditions: vec![],
+fn extract_job_message(job: &Job) -> Option<String> {
+    let status = job.status.as_ref()?;
+    if let Some(conditions) = &status.conditions
+        && let Some(cond) = conditions
+            .iter()
+            .find(|c| c.status == "True" && c.type_ == "Failed")
+    {
+        return cond.message.clone().or_else(|| cond.reason.clone());
     }
+    None
 }
 
-fn building_status(
-    fi: &FrontendIntegration,
-    spec_hash: &str,
-    bundle_name: &str,
-    job: &Job,
-    message: &str,
-) -> FrontendIntegrationStatus {
-    let started_at = existing_build_started_at(fi, spec_hash, job).unwrap_or_else(Utc::now);
+fn extract_job_error(job: &Job) -> Option<LastBuildError> {
+    let status = job.status.as_ref()?;
+    let cond = status
+        .conditions
+        .as_ref()?
+        .iter()
+        .find(|c| c.status == "True" && c.type_ == "Failed")?;
+    let message = cond.message.clone().or_else(|| cond.reason.clone())?;
 
-    FrontendIntegrationStatus {
-        phase: FrontendIntegrationPhase::Building,
-        observed_spec_hash: Some(spec_hash.to_string()),
-        observed_manifest_hash: fi
-            .status
-            .as_ref()
-            .and_then(|s| s.observed_manifest_hash.clone()),
-        observed_generation: Some(fi.metadata.generation.unwrap_or_default()),
-        last_build: Some(LastBuildStatus {
-            job_ref: Some(resource_ref(job)),
-            started_at: Some(started_at),
-        }),
-        bundle_ref: Some(ResourceRef {
-            name: bundle_name.to_string(),
-            namespace: None,
-            uid: None,
-        }),
-        last_error: current_last_error(fi, spec_hash),
-        message: Some(message.to_string()),
-        conditions: vec![],
-    }
+    Some(LastBuildError {
+        source: "job".to_string(),
+        message,
+        reason: cond.reason.clone(),
+        occurred_at: Some(Utc::now()),
+        log_tail: None,
+    })
 }
 
-fn succeeded_status(
+fn failure_error_for_status(
     fi: &FrontendIntegration,
     spec_hash: &str,
-    bundle: &JSBundle,
     job: &Job,
-) -> FrontendIntegrationStatus {
-    FrontendIntegrationStatus {
-        phase: FrontendIntegrationPhase::Succeeded,
-        observed_spec_hash: Some(spec_hash.to_string()),
-        observed_manifest_hash: bundle_manifest_hash(bundle),
-        observed_generation: Some(fi.metadata.generation.unwrap_or_default()),
-        last_build: Some(LastBuildStatus {
-            job_ref: Some(resource_ref(job)),
-            started_at: fi
-                .status
-                .as_ref()
-                .and_then(|s| s.last_build.clone())
-                .and_then(|b| b.started_at),
-        }),
-        bundle_ref: Some(resource_ref(bundle)),
-        last_error: None,
-        message: Some("Build succeeded".to_string()),
-        conditions: vec![],
+) -> LastBuildError {
+    if let Some(last_error) = current_last_error(fi, spec_hash) {
+        return last_error;
     }
+
+    extract_job_error(job).unwrap_or_else(|| LastBuildError {
+        source: "job".to_string(),
+        message: extract_job_message(job).unwrap_or_else(|| "Build job failed".to_string()),
+        reason: Some("JobFailed".to_string()),
+        occurred_at: Some(Utc::now()),
+        log_tail: None,
+    })
 }
 
-fn bundle_manifest_hash(bundle: &JSBundle) -> Option<String> {
-    if let Some(v) = bundle
-        .metadata
-        .annotations
-        .as_ref()
-        .and_then(|annos| annos.get(ANNO_MANIFEST_HASH))
-        .cloned()
+// Best effort: the pod may already be gone (TTL, eviction) and that must not block the Failed status.
+async fn newest_job_pod(pod_api: &Api<Pod>, job: &Job) -> Option<Pod> {
+    let job_name = job.name_any();
+    match pod_api
+        .list(&ListParams::default().labels(&format!("job-name={job_name}")))
+        .await
     {
-        return Some(v);
+        Ok(pods) => pods
+            .items
+            .into_iter()
+            .max_by(|a, b| a.creation_timestamp().cmp(&b.creation_timestamp())),
+        Err(err) => {
+            warn!(job = %job_name, error = %err, "failed to list pods of build job");
+            None
+        }
     }
+}
 
-    bundle
-        .metadata
-        .labels
-        .as_ref()
-        .and_then(|labels| labels.get(LABEL_MANIFEST_HASH))
-        .map(|v| {
-            if v.starts_with("sha256:") {
-                v.clone()
-            } else {
-                format!("sha256:{}", v)
+async fn failed_pod_log_tail(pod_api: &Api<Pod>, pod: &Pod, tail_lines: i64) -> Option<String> {
+    if tail_lines <= 0 {
+        return None;
+    }
+    let pod_name = pod.name_any();
+    let params = LogParams {
+        tail_lines: Some(tail_lines),
+        ..LogParams::default()
+    };
+    match pod_api.logs(&pod_name, &params).await {
+        Ok(logs) => truncate_log_tail(&logs, FAILED_POD_LOG_TAIL_MAX_BYTES),
+        Err(err) => {
+            warn!(pod = %pod_name, error = %err, "failed to read logs of failed pod");
+            None
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct PodFailure {
+    reason: &'static str,
+    message: String,
+}
+
+impl PodFailure {
+    fn into_last_error(self, log_tail: Option<String>) -> LastBuildError {
+        LastBuildError {
+            source: "pod".to_string(),
+            message: self.message,
+            reason: Some(self.reason.to_string()),
+            occurred_at: Some(Utc::now()),
+            log_tail,
+        }
+    }
+}
+
+// Waiting states that will not clear on their own; ErrImagePull is left alone because the
+// kubelet retries it and only escalates to ImagePullBackOff once the pull keeps failing.
+fn stuck_pod_failure(pod: &Pod) -> Option<PodFailure> {
+    runner_container_statuses(pod).find_map(|status| {
+        let waiting = status.state.as_ref()?.waiting.as_ref()?;
+        let reason = waiting.reason.as_deref()?;
+        let detail = waiting
+            .message
+            .as_deref()
+            .map(|message| format!(": {message}"))
+            .unwrap_or_default();
+        let (reason, message) = match reason {
+            "ImagePullBackOff" | "InvalidImageName" => (
+                "RunnerImagePullFailed",
+                format!(
+                    "Infrastructure failure: runner image {} cannot be pulled ({reason}{detail})",
+                    status.image
+                ),
+            ),
+            "CreateContainerConfigError" | "CreateContainerError" => (
+                "RunnerContainerStartFailed",
+                format!("Infrastructure failure: runner container cannot start ({reason}{detail})"),
+            ),
+            "CrashLoopBackOff" => (
+                "RunnerCrashLoopBackOff",
+                format!(
+                    "Infrastructure failure: runner container is crash-looping{}",
+                    last_exit_code(status)
+                        .map(|code| format!(" (last exit code {code})"))
+                        .unwrap_or_default()
+                ),
+            ),
+            _ => return None,
+        };
+        Some(PodFailure { reason, message })
+    })
+}
+
+// Container start errors are often a referenced Secret or ConfigMap that does not exist yet, so
+// they only count once the pod has been stuck past the grace period.
+fn settled_stuck_pod_failure(pod: &Pod, now: DateTime<Utc>) -> Option<PodFailure> {
+    let failure = stuck_pod_failure(pod)?;
+    if failure.reason == "RunnerContainerStartFailed" {
+        let created = pod.metadata.creation_timestamp.as_ref()?;
+        if now.timestamp() - created.0.as_second() < CONTAINER_START_GRACE_SECONDS {
+            return None;
+        }
+    }
+    Some(failure)
+}
+
+// Infrastructure failures (image, eviction, OOM) get a distinct reason from a runner that ran
+// and exited non-zero, which is a failure of the build itself.
+fn pod_failure(pod: &Pod) -> Option<PodFailure> {
+    if let Some(status) = pod.status.as_ref()
+        && status.reason.as_deref() == Some("Evicted")
+    {
+        return Some(PodFailure {
+            reason: "RunnerPodEvicted",
+            message: format!(
+                "Infrastructure failure: runner pod was evicted{}",
+                status
+                    .message
+                    .as_deref()
+                    .map(|message| format!(": {message}"))
+                    .unwrap_or_default()
+            ),
+        });
+    }
+    stuck_pod_failure(pod).or_else(|| {
+        runner_container_statuses(pod).find_map(|status| {
+            let terminated = status.state.as_ref()?.terminated.as_ref()?;
+            if terminated.reason.as_deref() == Some("OOMKilled") {
+                return Some(PodFailure {
+                    reason: "RunnerOOMKilled",
+                    message: "Infrastructure failure: runner container was OOM-killed; raise spec.builder.resources.limits.memory".to_string(),
+                });
             }
+            (terminated.exit_code != 0).then(|| PodFailure {
+                reason: "BuildFailed",
+                message: format!(
+                    "Build failed: runner exited with code {}{}",
+                    terminated.exit_code,
+                    terminated
+                        .message
+                        .as_deref()
+                        .map(|message| format!(": {}", message.trim()))
+                        .unwrap_or_default()
+                ),
+            })
         })
+    })
 }
 
-fn failed_status(
-    fi: &FrontendIntegration,
-    spec_hash: &str,
-    last_error: LastBuildError,
-) -> FrontendIntegrationStatus {
-    FrontendIntegrationStatus {
-        phase: FrontendIntegrationPhase::Failed,
-        observed_spec_hash: Some(spec_hash.to_string()),
-        observed_manifest_hash: fi
-            .status
-            .as_ref()
-            .and_then(|s| s.observed_manifest_hash.clone()),
-        observed_generation: Some(fi.metadata.generation.unwrap_or_default()),
-        last_build: fi.status.as_ref().and_then(|s| s.last_build.clone()),
-        bundle_ref: fi.status.as_ref().and_then(|s| s.bundle_ref.clone()),
-        message: Some(last_error.message.clone()),
-        last_error: Some(last_error),
-        conditions: vec![],
+fn runner_container_statuses(pod: &Pod) -> impl Iterator<Item = &ContainerStatus> {
+    pod.status
+        .iter()
+        .flat_map(|status| {
+            status
+                .init_container_statuses
+                .iter()
+                .chain(status.container_statuses.iter())
+        })
+        .flatten()
+}
+
+fn last_exit_code(status: &ContainerStatus) -> Option<i32> {
+    Some(status.last_state.as_ref()?.terminated.as_ref()?.exit_code)
+}
+
+fn truncate_log_tail(logs: &str, max_bytes: usize) -> Option<String> {
+    let logs = logs.trim_end();
+    if logs.trim().is_empty() {
+        return None;
+    }
+    if logs.len() <= max_bytes {
+        return Some(logs.to_string());
+    }
+    let mut start = logs.len() - max_bytes;
+    while !logs.is_char_boundary(start) {
+        start += 1;
     }
+    Some(format!("...{}", &logs[start..]))
 }
 
-fn existing_build_started_at(
-    fi: &FrontendIntegration,
-    spec_hash: &str,
-    job: &Job,
-) -> Option<chrono::DateTime<Utc>> {
+fn current_last_error(fi: &FrontendIntegration, spec_hash: &str) -> Option<LastBuildError> {
     let status = fi.status.as_ref()?;
-    let observed_hash = status
-        .observed_spec_hash
-        .as_deref()
-        .or(status.observed_manifest_hash.as_deref());
-    if observed_hash != Some(spec_hash) {
+    if status.observed_spec_hash.as_deref() != Some(spec_hash) {
         return None;
     }
+    status.last_error.clone()
+}
 
-    let last_build = status.last_build.as_ref()?;
-    let current_job_name = last_build.job_ref.as_ref()?.name.as_str();
-    if current_job_name != job.name_any() {
+fn bundle_matches_spec_hash(bundle: &JSBundle, spec_hash: &str) -> bool {
+    let expected = hash_label_value(spec_hash);
+    bundle
+        .metadata
+        .labels
+        .as_ref()
+        .and_then(|labels| labels.get(LABEL_SPEC_HASH))
+        .map(|v| v == &expected)
+        .unwrap_or(false)
+}
+
+#[derive(Debug, PartialEq)]
+struct BundleHashMismatch {
+    actual: Option<String>,
+    grace_expired: bool,
+}
+
+// The grace window runs from Job completion; a Job without completionTime keeps waiting.
+fn bundle_hash_mismatch(
+    job: &Job,
+    bundle: &JSBundle,
+    spec_hash: &str,
+    now: DateTime<Utc>,
+) -> Option<BundleHashMismatch> {
+    if bundle_matches_spec_hash(bundle, spec_hash) {
         return None;
     }
+    let actual = bundle
+        .metadata
+        .labels
+        .as_ref()
+        .and_then(|labels| labels.get(LABEL_SPEC_HASH))
+        .cloned();
+    let grace_expired = job
+        .status
+        .as_ref()
+        .and_then(|status| status.completion_time.as_ref())
+        .is_some_and(|completed| {
+            now.timestamp() - completed.0.as_second() >= BUNDLE_HASH_MISMATCH_GRACE_SECONDS
+        });
+    Some(BundleHashMismatch {
+        actual,
+        grace_expired,
+    })
+}
 
-    last_build.started_at
+fn labels_for(fi_name: &str, spec_hash: &str) -> BTreeMap<String, String> {
+    BTreeMap::from([
+        (LABEL_MANAGED_BY.to_string(), MANAGED_BY_VALUE.to_string()),
+        (LABEL_FI_NAME.to_string(), fi_name.to_string()),
+        (LABEL_SPEC_HASH.to_string(), hash_label_value(spec_hash)),
+    ])
 }
 
-fn fi_status_needs_patch(
-    fi: &FrontendIntegration,
-    desired_status: &FrontendIntegrationStatus,
-) -> bool {
-    fi.status.as_ref() != Some(desired_status)
+fn base_owner_ref<T>(obj: &T) -> Option<OwnerReference>
+where
+    T: Resource<DynamicType = ()>,
+{
+    obj.controller_owner_ref(&())
 }
 
-async fn patch_fi_status(
-    fi_api: &Api<FrontendIntegration>,
+// An empty allowlist trusts every FI; the env default is operator-chosen and never checked.
+fn runner_service_account(
     fi: &FrontendIntegration,
-    status: FrontendIntegrationStatus,
-) -> Result<(), Error> {
-    if !fi_status_needs_patch(fi, &status) {
-        return Ok(());
+    config: &ControllerConfig,
+) -> Result<Option<String>, String> {
+    let Some(requested) = fi.spec.runner_service_account() else {
+        return Ok(config.runner_service_account.clone());
+    };
+    let allowed = &config.allowed_runner_service_accounts;
+    if allowed.is_empty() || allowed.iter().any(|name| name == requested) {
+        return Ok(Some(requested.to_string()));
     }
+    Err(format!(
+        "builder.serviceAccountName '{requested}' is not in ALLOWED_RUNNER_SERVICE_ACCOUNTS"
+    ))
+}
 
-    let fi_name = fi.name_any();
-    let namespace = fi.namespace().unwrap_or_else(|| "<cluster>".to_string());
-    let patch = frontend_integration_status_patch(&status, &namespace, &fi_name)?;
-
-    fi_api
-        .patch_status(&fi_name, &PatchParams::default(), &Patch::Merge(&patch))
-        .await
-        .with_context(|_| PatchFrontendIntegrationStatusSnafu {
-            namespace,
-            name: fi_name.clone(),
-        })?;
+// Builder settings that fail validation are reported once on the FI instead of producing a
+// Job the API server rejects on every reconcile.
+fn runner_resources(
+    fi: &FrontendIntegration,
+    config: &ControllerConfig,
+) -> Result<Option<ResourceRequirements>, String> {
+    let overrides = fi
+        .spec
+        .builder
+        .as_ref()
+        .and_then(|builder| builder.resources.as_ref());
+    merge_runner_resources(&config.runner_resources, overrides)
+        .map_err(|message| format!("builder.resources: {message}"))
+}
 
-    Ok(())
+async fn publish_build_job_rejected(
+    recorder: &Recorder,
+    fi: &FrontendIntegration,
+    reason: &str,
+    message: &str,
+) {
+    let event = Event {
+        type_: EventType::Warning,
+        reason: reason.to_string(),
+        note: Some(message.to_string()),
+        action: "CreateBuildJob".to_string(),
+        secondary: None,
+    };
+    if let Err(err) = recorder.publish(&event, &fi.object_ref(&())).await {
+        warn!(error = %err, fi = %fi.name_any(), "failed to publish build job rejection event");
+    }
 }
 
-fn frontend_integration_status_patch(
-    status: &FrontendIntegrationStatus,
-    namespace: &str,
-    name: &str,
-) -> Result<serde_json::Value, Error> {
-    let mut status_value = serde_json::to_value(status).with_context(|_| {
-        SerializeFrontendIntegrationStatusPatchSnafu {
-            namespace: namespace.to_string(),
-            name: name.to_string(),
+fn make_build_job(
+    fi: &FrontendIntegration,
+    config: &ControllerConfig,
+    service_account: Option<String>,
+    resources: Option<ResourceRequirements>,
+    job_name: &str,
+    jsbundle_name: &str,
+    spec_hash: &str,
+) -> Job {
+    let fi_name = fi.name_any();
+    let mut labels = labels_for(&fi_name, spec_hash);
+    labels.insert(LABEL_BUILD_KIND.to_string(), BUILD_KIND_VALUE.to_string());
+
+    let mut annotations = BTreeMap::new();
+    if let Some(generation) = fi.metadata.generation {
+        annotations.insert(ANNO_OBSERVED_GENERATION.to_string(), generation.to_string());
+        // Lets `kubectl get jobs -l` find the Job for a generation; a label value cannot start
+        // with '-', so the annotation stays the source of truth.
+        if generation >= 0 {
+            labels.insert(
+                LABEL_OBSERVED_GENERATION.to_string(),
+                generation.to_string(),
+            );
         }
-    })?;
-    let status_object = status_value.as_object_mut().ok_or_else(|| {
-        Error::InvalidFrontendIntegrationStatusPatchShape {
-            namespace: namespace.to_string(),
-            name: name.to_string(),
+    }
+    // Recorded for debugging only; scheduling config is not part of the spec hash.
+    if let Some(priority_class) = config.runner_priority_class.as_ref() {
+        annotations.insert(ANNO_PRIORITY_CLASS.to_string(), priority_class.clone());
+    }
+    merge_propagated(&mut labels, fi.spec.propagate_labels());
+    merge_propagated(&mut annotations, fi.spec.propagate_annotations());
+
+    let mut env = vec![
+        EnvVar {
+            name: "FI_NAME".to_string(),
+            value: Some(fi_name.clone()),
+            ..Default::default()
+        },
+        EnvVar {
+            name: "SPEC_HASH".to_string(),
+            value: Some(spec_hash.to_string()),
+            ..Default::default()
+        },
+        EnvVar {
+            name: "JSBUNDLE_NAME".to_string(),
+            value: Some(jsbundle_name.to_string()),
+            ..Default::default()
+        },
+        EnvVar {
+            name: "BUILD_SERVICE_BASE_URL".to_string(),
+            value: Some(config.build_service_base_url.clone()),
+            ..Default::default()
+        },
+        EnvVar {
+            name: "JSBUNDLE_CONFIGMAP_NAMESPACE".to_string(),
+            value: Some(bundle_configmap_namespace_for(fi, config).to_string()),
+            ..Default::default()
+        },
+        EnvVar {
+            name: "JSBUNDLE_CONFIG_KEY".to_string(),
+            value: Some(config.jsbundle_config_key.clone()),
+            ..Default::default()
+        },
+        EnvVar {
+            name: "BUILD_SERVICE_TIMEOUT_SECONDS".to_string(),
+            value: Some(config.build_service_timeout_seconds.to_string()),
+            ..Default::default()
+        },
+        EnvVar {
+            name: "STALE_CHECK_GRACE_SECONDS".to_string(),
+            value: Some(config.stale_check_grace_seconds.to_string()),
+            ..Default::default()
+        },
+    ];
+    // The runner renders the manifest, so it must see the same render options as the webhook.
+    if let Some(icon) = config.render_options.default_menu_icon.as_ref() {
+        env.push(EnvVar {
+            name: "DEFAULT_MENU_ICON".to_string(),
+            value: Some(icon.clone()),
+            ..Default::default()
+        });
+    }
+    if let Some(max) = config.render_options.menu_title_max_chars {
+        env.push(EnvVar {
+            name: "MENU_TITLE_MAX_CHARS".to_string(),
+            value: Some(max.to_string()),
+            ..Default::default()
+        });
+    }
+    env.push(EnvVar {
+        name: "MENU_TITLE_OVERFLOW".to_string(),
+        value: Some(
+            config
+                .render_options
+                .menu_title_overflow
+                .as_str()
+                .to_string(),
+        ),
+        ..Default::default()
+    });
+    if !config.render_options.iframe_allowed_hosts.is_empty() {
+        env.push(EnvVar {
+            name: "IFRAME_ALLOWED_HOSTS".to_string(),
+            value: Some(config.render_options.iframe_allowed_hosts.join(",")),
+            ..Default::default()
+        });
+    }
+    if let Some(strategy) = config.render_options.page_id_strategy {
+        env.push(EnvVar {
+            name: "PAGE_ID_STRATEGY".to_string(),
+            value: Some(strategy.as_str().to_string()),
+            ..Default::default()
+        });
+        env.push(EnvVar {
+            name: "PAGE_ID_NAMESPACE".to_string(),
+            value: Some(config.render_options.page_id_namespace.clone()),
+            ..Default::default()
+        });
+    }
+    // Empty keeps the runner's built-in preference list.
+    if !config.bundle_preferred_entries.is_empty() {
+        env.push(EnvVar {
+            name: "BUNDLE_PREFERRED_ENTRIES".to_string(),
+            value: Some(config.bundle_preferred_entries.join(",")),
+            ..Default::default()
+        });
+    }
+    if config.include_sourcemaps {
+        env.push(EnvVar {
+            name: "INCLUDE_SOURCEMAPS".to_string(),
+            value: Some("true".to_string()),
+            ..Default::default()
+        });
+    }
+    if skip_stale_check_requested(fi) {
+        env.push(EnvVar {
+            name: "SKIP_STALE_CHECK".to_string(),
+            value: Some("true".to_string()),
+            ..Default::default()
+        });
+    }
+    for (name, value) in &config.runner_passthrough_env {
+        env.push(EnvVar {
+            name: name.clone(),
+            value: Some(value.clone()),
+            ..Default::default()
+        });
+    }
+    // The runner exports to the same collector and continues the reconcile trace.
+    if let Some(endpoint) = config.otel_exporter_endpoint.as_ref() {
+        env.push(EnvVar {
+            name: OTEL_ENDPOINT_ENV.to_string(),
+            value: Some(endpoint.clone()),
+            ..Default::default()
+        });
+        if let Some(traceparent) = telemetry::traceparent(&Span::current()) {
+            env.push(EnvVar {
+                name: TRACEPARENT_ENV.to_string(),
+                value: Some(traceparent),
+                ..Default::default()
+            });
         }
-    })?;
+    }
+
+    let container = Container {
+        name: "runner".to_string(),
+        image: Some(
+            fi.spec
+                .runner_image()
+                .unwrap_or(&config.runner_image)
+                .to_string(),
+        ),
+        env: Some(env),
+        resources,
+        security_context: Some(config.runner_security_context.container.clone()),
+        volume_mounts: config.build_service_tls_secret.as_ref().map(|_| {
+            vec![VolumeMount {
+                name: BUILD_SERVICE_TLS_VOLUME.to_string(),
+                mount_path: BUILD_SERVICE_TLS_MOUNT_PATH.to_string(),
+                read_only: Some(true),
+                ..Default::default()
+            }]
+        }),
+        ..Default::default()
+    };
+    let volumes = config.build_service_tls_secret.as_ref().map(|secret_name| {
+        vec![Volume {
+            name: BUILD_SERVICE_TLS_VOLUME.to_string(),
+            secret: Some(SecretVolumeSource {
+                secret_name: Some(secret_name.clone()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }]
+    });
+
+    Job {
+        metadata: ObjectMeta {
+            name: Some(job_name.to_string()),
+            namespace: Some(config.work_namespace.clone()),
+            labels: Some(labels),
+            annotations: Some(annotations),
+            owner_references: base_owner_ref(fi).map(|o| vec![o]),
+            ..Default::default()
+        },
+        spec: Some(JobSpec {
+            active_deadline_seconds: Some(config.job_active_deadline_seconds),
+            ttl_seconds_after_finished: config.job_ttl_seconds_after_finished,
+            template: PodTemplateSpec {
+                metadata: Some(ObjectMeta {
+                    labels: Some(BTreeMap::from([(
+                        "app.kubernetes.io/name".to_string(),
+                        "frontend-forge-runner".to_string(),
+                    )])),
+                    ..Default::default()
+                }),
+                spec: Some(PodSpec {
+                    restart_policy: Some("Never".to_string()),
+                    service_account_name: service_account,
+                    containers: vec![container],
+                    security_context: Some(config.runner_security_context.pod.clone()),
+                    priority_class_name: config.runner_priority_class.clone(),
+                    node_selector: Some(config.runner_node_selector.clone())
+                        .filter(|selector| !selector.is_empty()),
+                    tolerations: Some(config.runner_tolerations.clone())
+                        .filter(|tolerations| !tolerations.is_empty()),
+                    volumes,
+                    ..Default::default()
+                }),
+            },
+            backoff_limit: Some(0),
+            ..Default::default()
+        }),
+        status: None,
+    }
+}
+
+// Opt-in for CI/ephemeral clusters where the runner's stale-check grace loop only adds latency.
+fn skip_stale_check_requested(fi: &FrontendIntegration) -> bool {
+    fi.annotations()
+        .get(ANNO_SKIP_STALE_CHECK)
+        .is_some_and(|value| value.trim().eq_ignore_ascii_case("true"))
+}
+
+async fn create_or_get_job(
+    job_api: &Api<Job>,
+    namespace: &str,
+    job: Job,
+    name: &str,
+) -> Result<Job, Error> {
+    match job_api.create(&PostParams::default(), &job).await {
+        Ok(created) => Ok(created),
+        Err(kube::Error::Api(ae)) if ae.code == 409 => {
+            Ok(job_api
+                .get(name)
+                .await
+                .with_context(|_| GetJobAfterConflictSnafu {
+                    namespace: namespace.to_string(),
+                    name: name.to_string(),
+                })?)
+        }
+        Err(err) => Err(Error::CreateJob {
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+            source: Box::new(err),
+        }),
+    }
+}
+
+// Unlike the service account allowlist, an empty list allows no overrides: the runner and
+// controller only hold RBAC in the namespaces the operator set up.
+fn bundle_configmap_namespace<'a>(
+    fi: &'a FrontendIntegration,
+    config: &'a ControllerConfig,
+) -> Result<&'a str, String> {
+    let Some(requested) = fi.spec.bundle_config_map_namespace() else {
+        return Ok(&config.jsbundle_configmap_namespace);
+    };
+    if requested == config.jsbundle_configmap_namespace
+        || config
+            .allowed_bundle_namespaces
+            .iter()
+            .any(|namespace| namespace == requested)
+    {
+        return Ok(requested);
+    }
+    Err(format!(
+        "bundleConfigMapNamespace '{requested}' is not in ALLOWED_BUNDLE_NAMESPACES"
+    ))
+}
+
+// Reconcile rejects disallowed overrides before any Job exists, so falling back is never
+// observed by a runner.
+fn bundle_configmap_namespace_for<'a>(
+    fi: &'a FrontendIntegration,
+    config: &'a ControllerConfig,
+) -> &'a str {
+    bundle_configmap_namespace(fi, config).unwrap_or(&config.jsbundle_configmap_namespace)
+}
+
+fn bundle_name_for(fi: &FrontendIntegration) -> String {
+    fi.spec
+        .bundle_name()
+        .map(str::to_string)
+        .unwrap_or_else(|| default_bundle_name(&fi.name_any()))
+}
+
+// Bundles without the label predate it or were made by hand, and are adopted as before.
+fn foreign_bundle_owner(bundle: &JSBundle, fi_name: &str) -> Option<String> {
+    bundle
+        .labels()
+        .get(LABEL_FI_NAME)
+        .filter(|owner| owner.as_str() != fi_name)
+        .cloned()
+}
+
+async fn get_bundle_opt(bundle_api: &Api<JSBundle>, name: &str) -> Result<Option<JSBundle>, Error> {
+    bundle_api
+        .get_opt(name)
+        .await
+        .with_context(|_| GetJsBundleSnafu {
+            namespace: "<cluster>".to_string(),
+            name: name.to_string(),
+        })
+}
+
+// Bundles left behind under an older naming scheme are only removed once the
+// desired bundle exists, so the live route never goes without a bundle.
+async fn reclaim_orphan_bundles(
+    bundle_api: &Api<JSBundle>,
+    recorder: &Recorder,
+    fi: &FrontendIntegration,
+    desired_bundle: &JSBundle,
+) -> Result<(), Error> {
+    let fi_name = fi.name_any();
+    let desired_name = desired_bundle.name_any();
+    let bundles = bundle_api
+        .list(&ListParams::default().labels(&format!("{}={}", LABEL_FI_NAME, fi_name)))
+        .await
+        .with_context(|_| ListJsBundlesForFiSnafu {
+            fi_name: fi_name.clone(),
+        })?;
+
+    for orphan in orphan_bundles(&bundles.items, &desired_name) {
+        let name = orphan.name_any();
+        match bundle_api.delete(&name, &DeleteParams::background()).await {
+            Ok(_) => {}
+            Err(kube::Error::Api(ae)) if ae.code == 404 => continue,
+            Err(source) => {
+                return Err(Error::DeleteOrphanJsBundle {
+                    namespace: "<cluster>".to_string(),
+                    name,
+                    source: Box::new(source),
+                });
+            }
+        }
+        info!(fi = %fi_name, bundle = %name, desired = %desired_name, "reclaimed orphaned JSBundle");
+
+        let event = Event {
+            type_: EventType::Normal,
+            reason: "OrphanedBundleReclaimed".to_string(),
+            note: Some(format!(
+                "Deleted orphaned JSBundle {name}; {desired_name} is the active bundle"
+            )),
+            action: "Reclaim".to_string(),
+            secondary: Some(orphan.object_ref(&())),
+        };
+        if let Err(err) = recorder.publish(&event, &fi.object_ref(&())).await {
+            warn!(error = %err, fi = %fi_name, bundle = %name, "failed to publish reclaim event");
+        }
+    }
+
+    Ok(())
+}
+
+fn orphan_bundles<'a>(bundles: &'a [JSBundle], desired_name: &str) -> Vec<&'a JSBundle> {
+    bundles
+        .iter()
+        .filter(|bundle| bundle.name_any() != desired_name)
+        .filter(|bundle| {
+            bundle
+                .metadata
+                .labels
+                .as_ref()
+                .and_then(|labels| labels.get(LABEL_MANAGED_BY))
+                .is_some_and(|value| value == MANAGED_BY_VALUE)
+        })
+        .collect()
+}
+
+fn enabled_label_value(enabled: bool) -> &'static str {
+    if enabled { "true" } else { "false" }
+}
+
+async fn patch_fi_enabled_label_if_needed(
+    fi_api: &Api<FrontendIntegration>,
+    fi: &FrontendIntegration,
+) -> Result<(), Error> {
+    let desired = enabled_label_value(fi.spec.enabled());
+    let current = fi
+        .metadata
+        .labels
+        .as_ref()
+        .and_then(|labels| labels.get(LABEL_ENABLED))
+        .map(String::as_str);
+    if current == Some(desired) {
+        return Ok(());
+    }
+
+    let fi_name = fi.name_any();
+    let namespace = fi.namespace().unwrap_or_else(|| "<cluster>".to_string());
+    let patch = json!({
+        "metadata": {
+            "labels": {
+                LABEL_ENABLED: desired,
+            }
+        }
+    });
+    fi_api
+        .patch(&fi_name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await
+        .with_context(|_| PatchFrontendIntegrationMetadataSnafu {
+            namespace,
+            name: fi_name.clone(),
+        })?;
+    Ok(())
+}
+
+async fn sync_jsbundle_enabled_state(
+    bundle_api: &Api<JSBundle>,
+    fi: &FrontendIntegration,
+    bundle: &JSBundle,
+    enabled: bool,
+) -> Result<(), Error> {
+    patch_jsbundle_owner_ref_if_needed(bundle_api, fi, bundle).await?;
+    patch_jsbundle_enabled_label_if_needed(bundle_api, bundle, enabled).await?;
+    let desired_state = if enabled {
+        JSBUNDLE_STATE_AVAILABLE
+    } else {
+        JSBUNDLE_STATE_DISABLED
+    };
+    patch_jsbundle_state_if_needed(bundle_api, bundle, desired_state).await?;
+    Ok(())
+}
+
+async fn patch_jsbundle_owner_ref_if_needed(
+    bundle_api: &Api<JSBundle>,
+    fi: &FrontendIntegration,
+    bundle: &JSBundle,
+) -> Result<(), Error> {
+    let Some(owner_ref) = base_owner_ref(fi) else {
+        return Ok(());
+    };
+
+    let mut owners = bundle.metadata.owner_references.clone().unwrap_or_default();
+    if owners.iter().any(|owner| owner.uid == owner_ref.uid) {
+        return Ok(());
+    }
+    owners.push(owner_ref);
+
+    let name = bundle.name_any();
+    let patch = json!({
+        "metadata": {
+            "ownerReferences": owners,
+        }
+    });
+    match bundle_api
+        .patch(&name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(kube::Error::Api(ae)) if ae.code == 404 => Ok(()),
+        Err(source) => Err(Error::PatchJsBundle {
+            namespace: "<cluster>".to_string(),
+            name,
+            source: Box::new(source),
+        }),
+    }
+}
+
+async fn patch_jsbundle_enabled_label_if_needed(
+    bundle_api: &Api<JSBundle>,
+    bundle: &JSBundle,
+    enabled: bool,
+) -> Result<(), Error> {
+    let desired = enabled_label_value(enabled);
+    let current = bundle
+        .metadata
+        .labels
+        .as_ref()
+        .and_then(|labels| labels.get(LABEL_ENABLED))
+        .map(String::as_str);
+    if current == Some(desired) {
+        return Ok(());
+    }
+
+    let name = bundle.name_any();
+    let patch = json!({
+        "metadata": {
+            "labels": {
+                LABEL_ENABLED: desired,
+            }
+        }
+    });
+    match bundle_api
+        .patch(&name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(kube::Error::Api(ae)) if ae.code == 404 => Ok(()),
+        Err(source) => Err(Error::PatchJsBundle {
+            namespace: "<cluster>".to_string(),
+            name,
+            source: Box::new(source),
+        }),
+    }
+}
+
+async fn patch_jsbundle_state_if_needed(
+    bundle_api: &Api<JSBundle>,
+    bundle: &JSBundle,
+    desired_state: &str,
+) -> Result<(), Error> {
+    let current = bundle
+        .status
+        .as_ref()
+        .and_then(|status| status.state.as_deref());
+    if current == Some(desired_state) {
+        return Ok(());
+    }
+
+    let name = bundle.name_any();
+    let patch = json!({
+        "status": {
+            "state": desired_state,
+        }
+    });
+    match bundle_api
+        .patch_status(&name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(kube::Error::Api(ae)) if ae.code == 404 => {
+            match bundle_api
+                .patch(&name, &PatchParams::default(), &Patch::Merge(&patch))
+                .await
+            {
+                Ok(_) => Ok(()),
+                Err(kube::Error::Api(ae)) if ae.code == 404 => Ok(()),
+                Err(source) => Err(Error::PatchJsBundle {
+                    namespace: "<cluster>".to_string(),
+                    name,
+                    source: Box::new(source),
+                }),
+            }
+        }
+        Err(source) => Err(Error::PatchJsBundle {
+            namespace: "<cluster>".to_string(),
+            name,
+            source: Box::new(source),
+        }),
+    }
+}
+
+fn resource_ref<K: ResourceExt>(obj: &K) -> ResourceRef {
+    ResourceRef {
+        name: obj.name_any(),
+        namespace: obj.namespace(),
+        uid: obj.meta().uid.clone(),
+    }
+}
+
+fn disabled_status(
+    fi: &FrontendIntegration,
+    bundle: Option<&JSBundle>,
+) -> FrontendIntegrationStatus {
+    FrontendIntegrationStatus {
+        phase: FrontendIntegrationPhase::Pending,
+        observed_spec_hash: fi
+            .status
+            .as_ref()
+            .and_then(|s| s.observed_spec_hash.clone()),
+        observed_manifest_hash: fi
+            .status
+            .as_ref()
+            .and_then(|s| s.observed_manifest_hash.clone()),
+        observed_generation: Some(fi.metadata.generation.unwrap_or_default()),
+        observed_force_rebuild_token: observed_force_rebuild_token(fi),
+        last_build: None,
+        bundle_ref: bundle.map(resource_ref),
+        url: None,
+        effective_placements: fi.spec.effective_placements(),
+        last_error: None,
+        message: Some(DISABLED_MESSAGE.to_string()),
+        conditions: current_conditions(fi),
+    }
+}
+
+fn deferred_status(
+    fi: &FrontendIntegration,
+    bundle: Option<&JSBundle>,
+    message: &str,
+) -> FrontendIntegrationStatus {
+    let current = fi.status.clone().unwrap_or_default();
+    FrontendIntegrationStatus {
+        phase: FrontendIntegrationPhase::Pending,
+        observed_generation: Some(fi.metadata.generation.unwrap_or_default()),
+        bundle_ref: bundle.map(resource_ref).or(current.bundle_ref),
+        effective_placements: fi.spec.effective_placements(),
+        message: Some(message.to_string()),
+        ..current
+    }
+}
+
+// Keeps the observed hashes of the replaced build until the new Job reports Building.
+fn superseded_status(
+    fi: &FrontendIntegration,
+    spec_hash: &str,
+    cancelled_jobs: &[String],
+) -> FrontendIntegrationStatus {
+    let current = fi.status.clone().unwrap_or_default();
+    let generation = fi.metadata.generation.unwrap_or_default();
+    let observed = current
+        .observed_spec_hash
+        .as_deref()
+        .map(|hash| format!("; observed spec hash {hash} is the cancelled build's"))
+        .unwrap_or_default();
+    FrontendIntegrationStatus {
+        phase: FrontendIntegrationPhase::Superseded,
+        observed_generation: Some(generation),
+        effective_placements: fi.spec.effective_placements(),
+        message: Some(format!(
+            "Superseded by generation {generation} (spec hash {spec_hash}); cancelled Job {}{observed}",
+            cancelled_jobs.join(", ")
+        )),
+        ..current
+    }
+}
+
+fn building_status(
+    fi: &FrontendIntegration,
+    spec_hash: &str,
+    bundle_name: &str,
+    job: &Job,
+    message: &str,
+) -> FrontendIntegrationStatus {
+    let started_at = existing_build_started_at(fi, spec_hash, job).unwrap_or_else(Utc::now);
+
+    FrontendIntegrationStatus {
+        phase: FrontendIntegrationPhase::Building,
+        observed_spec_hash: Some(spec_hash.to_string()),
+        observed_manifest_hash: fi
+            .status
+            .as_ref()
+            .and_then(|s| s.observed_manifest_hash.clone()),
+        observed_generation: Some(fi.metadata.generation.unwrap_or_default()),
+        observed_force_rebuild_token: fi
+            .spec
+            .force_rebuild_token
+            .clone()
+            .or_else(|| observed_force_rebuild_token(fi)),
+        last_build: Some(LastBuildStatus {
+            job_ref: Some(resource_ref(job)),
+            started_at: Some(started_at),
+        }),
+        bundle_ref: Some(ResourceRef {
+            name: bundle_name.to_string(),
+            namespace: None,
+            uid: None,
+        }),
+        url: fi.status.as_ref().and_then(|s| s.url.clone()),
+        effective_placements: fi.spec.effective_placements(),
+        last_error: current_last_error(fi, spec_hash),
+        message: Some(message.to_string()),
+        conditions: current_conditions(fi),
+    }
+}
+
+fn succeeded_status(
+    fi: &FrontendIntegration,
+    spec_hash: &str,
+    bundle: &JSBundle,
+    job: &Job,
+) -> FrontendIntegrationStatus {
+    FrontendIntegrationStatus {
+        phase: FrontendIntegrationPhase::Succeeded,
+        observed_spec_hash: Some(spec_hash.to_string()),
+        observed_manifest_hash: bundle_manifest_hash(bundle),
+        observed_generation: Some(fi.metadata.generation.unwrap_or_default()),
+        observed_force_rebuild_token: observed_force_rebuild_token(fi),
+        last_build: Some(LastBuildStatus {
+            job_ref: Some(resource_ref(job)),
+            started_at: fi
+                .status
+                .as_ref()
+                .and_then(|s| s.last_build.clone())
+                .and_then(|b| b.started_at),
+        }),
+        bundle_ref: Some(resource_ref(bundle)),
+        url: bundle_url(bundle),
+        effective_placements: fi.spec.effective_placements(),
+        last_error: None,
+        message: Some("Build succeeded".to_string()),
+        conditions: current_conditions(fi),
+    }
+}
+
+// Platforms that report a Ready condition get the final say on whether the bundle
+// was accepted; bundles without one are treated as ready once they exist.
+fn bundle_not_ready_reason(bundle: &JSBundle) -> Option<String> {
+    let ready = bundle
+        .status
+        .as_ref()?
+        .conditions
+        .iter()
+        .find(|condition| condition.type_ == JSBUNDLE_CONDITION_READY)?;
+    if ready.status == "True" {
+        return None;
+    }
+    Some(
+        ready
+            .message
+            .clone()
+            .or_else(|| ready.reason.clone())
+            .unwrap_or_else(|| format!("Ready condition is {}", ready.status)),
+    )
+}
+
+fn bundle_url(bundle: &JSBundle) -> Option<String> {
+    let status = bundle.status.as_ref()?;
+    match status.state.as_deref() {
+        None | Some(JSBUNDLE_STATE_AVAILABLE) => status.link.clone(),
+        Some(_) => None,
+    }
+}
+
+fn bundle_manifest_hash(bundle: &JSBundle) -> Option<String> {
+    if let Some(v) = bundle
+        .metadata
+        .annotations
+        .as_ref()
+        .and_then(|annos| annos.get(ANNO_MANIFEST_HASH))
+        .cloned()
+    {
+        return Some(v);
+    }
+
+    bundle
+        .metadata
+        .labels
+        .as_ref()
+        .and_then(|labels| labels.get(LABEL_MANIFEST_HASH))
+        .map(|v| {
+            if v.starts_with("sha256:") {
+                v.clone()
+            } else {
+                format!("sha256:{}", v)
+            }
+        })
+}
+
+fn failed_status(
+    fi: &FrontendIntegration,
+    spec_hash: &str,
+    last_error: LastBuildError,
+) -> FrontendIntegrationStatus {
+    FrontendIntegrationStatus {
+        phase: FrontendIntegrationPhase::Failed,
+        observed_spec_hash: Some(spec_hash.to_string()),
+        observed_manifest_hash: fi
+            .status
+            .as_ref()
+            .and_then(|s| s.observed_manifest_hash.clone()),
+        observed_generation: Some(fi.metadata.generation.unwrap_or_default()),
+        observed_force_rebuild_token: observed_force_rebuild_token(fi),
+        last_build: fi.status.as_ref().and_then(|s| s.last_build.clone()),
+        bundle_ref: fi.status.as_ref().and_then(|s| s.bundle_ref.clone()),
+        url: fi.status.as_ref().and_then(|s| s.url.clone()),
+        effective_placements: fi.spec.effective_placements(),
+        message: Some(last_error.message.clone()),
+        last_error: Some(last_error),
+        conditions: current_conditions(fi),
+    }
+}
+
+fn existing_build_started_at(
+    fi: &FrontendIntegration,
+    spec_hash: &str,
+    job: &Job,
+) -> Option<chrono::DateTime<Utc>> {
+    let status = fi.status.as_ref()?;
+    let observed_hash = status
+        .observed_spec_hash
+        .as_deref()
+        .or(status.observed_manifest_hash.as_deref());
+    if observed_hash != Some(spec_hash) {
+        return None;
+    }
+
+    let last_build = status.last_build.as_ref()?;
+    let current_job_name = last_build.job_ref.as_ref()?.name.as_str();
+    if current_job_name != job.name_any() {
+        return None;
+    }
+
+    last_build.started_at
+}
+
+// Rebuilt statuses carry fresh timestamps, and conditions never go out with this patch, so
+// neither counts as a change; otherwise steady-state FIs would be rewritten every reconcile.
+fn fi_status_needs_patch(
+    fi: &FrontendIntegration,
+    desired_status: &FrontendIntegrationStatus,
+) -> bool {
+    fi.status
+        .as_ref()
+        .is_none_or(|current| comparable_status(current) != comparable_status(desired_status))
+}
+
+fn comparable_status(status: &FrontendIntegrationStatus) -> FrontendIntegrationStatus {
+    let mut status = status.clone();
+    status.conditions.clear();
+    if let Some(last_error) = status.last_error.as_mut() {
+        last_error.occurred_at = None;
+    }
+    status
+}
+
+async fn patch_fi_status(
+    fi_api: &Api<FrontendIntegration>,
+    fi: &FrontendIntegration,
+    status: FrontendIntegrationStatus,
+) -> Result<(), Error> {
+    if !fi_status_needs_patch(fi, &status) {
+        return Ok(());
+    }
+
+    let fi_name = fi.name_any();
+    let namespace = fi.namespace().unwrap_or_else(|| "<cluster>".to_string());
+    let patch = frontend_integration_status_patch(&status, &namespace, &fi_name)?;
+
+    fi_api
+        .patch_status(
+            &fi_name,
+            &PatchParams::apply(CONTROLLER_NAME).force(),
+            &Patch::Apply(&patch),
+        )
+        .await
+        .with_context(|_| PatchFrontendIntegrationStatusSnafu {
+            namespace: namespace.clone(),
+            name: fi_name.clone(),
+        })?;
+    if let Some(clear) = cleared_status_refs_patch(fi, &status) {
+        let params = PatchParams {
+            field_manager: Some(CONTROLLER_NAME.to_string()),
+            ..PatchParams::default()
+        };
+        fi_api
+            .patch_status(&fi_name, &params, &Patch::Merge(&clear))
+            .await
+            .with_context(|_| PatchFrontendIntegrationStatusSnafu {
+                namespace,
+                name: fi_name.clone(),
+            })?;
+    }
+    metrics::metrics().observe_status_transition(fi, &status, Utc::now());
+
+    Ok(())
+}
+
+// Server-side apply: status fields the controller leaves unset are dropped from its managed
+// set instead of being nulled, so values other writers own survive. Conditions are left to
+// their own field manager.
+fn frontend_integration_status_patch(
+    status: &FrontendIntegrationStatus,
+    namespace: &str,
+    name: &str,
+) -> Result<serde_json::Value, Error> {
+    let mut status_value = serde_json::to_value(status).with_context(|_| {
+        SerializeFrontendIntegrationStatusPatchSnafu {
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+        }
+    })?;
+    let status_object = status_value.as_object_mut().ok_or_else(|| {
+        Error::InvalidFrontendIntegrationStatusPatchShape {
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+        }
+    })?;
+
+    status_object.remove("conditions");
+
+    Ok(json!({
+        "apiVersion": FrontendIntegration::api_version(&()),
+        "kind": FrontendIntegration::kind(&()),
+        "status": status_value,
+    }))
+}
+
+// Omitting a field from an apply only removes it when the controller is its sole owner. These
+// refs may still be owned by the runner or by merge patches from older releases, so clearing
+// them takes explicit nulls.
+fn cleared_status_refs_patch(
+    fi: &FrontendIntegration,
+    desired: &FrontendIntegrationStatus,
+) -> Option<serde_json::Value> {
+    let current = fi.status.as_ref()?;
+    let cleared: serde_json::Map<String, serde_json::Value> = [
+        (
+            "last_build",
+            current.last_build.is_some() && desired.last_build.is_none(),
+        ),
+        (
+            "bundle_ref",
+            current.bundle_ref.is_some() && desired.bundle_ref.is_none(),
+        ),
+        ("url", current.url.is_some() && desired.url.is_none()),
+        (
+            "last_error",
+            current.last_error.is_some() && desired.last_error.is_none(),
+        ),
+    ]
+    .into_iter()
+    .filter(|(_, cleared)| *cleared)
+    .map(|(field, _)| (field.to_string(), serde_json::Value::Null))
+    .collect();
+    (!cleared.is_empty()).then(|| json!({ "status": cleared }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frontend_forge_api::{
+        BuildOutputFormat, BuildOutputSpec, BuilderResourcesSpec, BuilderSpec,
+        FrontendIntegrationSpec, IframePageSpec, LastBuildError, MenuNodeType, MenuPlacement,
+        PageSpec, PageType, PrimaryMenuSpec,
+    };
+    use k8s_openapi::api::batch::v1::JobStatus;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+    use kube::core::ObjectMeta;
+
+    fn fi(name: &str, status: Option<FrontendIntegrationStatus>) -> FrontendIntegration {
+        FrontendIntegration {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                namespace: Some("default".to_string()),
+                generation: Some(3),
+                ..Default::default()
+            },
+            spec: FrontendIntegrationSpec {
+                display_name: None,
+                description: None,
+                metadata: None,
+                locales: BTreeMap::new(),
+                enabled: Some(true),
+                paused: None,
+                force_rebuild_token: None,
+                bundle_name: None,
+                bundle_config_map_namespace: None,
+                propagate_labels: None,
+                propagate_annotations: None,
+                menus: vec![PrimaryMenuSpec {
+                    display_name: "demo".to_string(),
+                    key: "demo".to_string(),
+                    icon: None,
+                    order: None,
+                    parent: None,
+                    placement: MenuPlacement::Global,
+                    type_: MenuNodeType::Page,
+                    children: vec![],
+                    aliases: vec![],
+                }],
+                pages: vec![PageSpec {
+                    key: "demo".to_string(),
+                    type_: PageType::Iframe,
+                    crd_table: None,
+                    iframe: Some(IframePageSpec {
+                        src: "http://example.test".to_string(),
+                        sandbox: None,
+                        allow: None,
+                        height: None,
+                        loading: None,
+                    }),
+                    custom: None,
+                    permissions: vec![],
+                }],
+                builder: None,
+            },
+            status,
+        }
+    }
+
+    fn spec_hash(fi: &FrontendIntegration) -> Result<String, CommonError> {
+        build_spec_hash(fi)
+    }
+
+    fn bundle_for_hash(name: &str, spec_hash: &str) -> JSBundle {
+        JSBundle {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                labels: Some(BTreeMap::from([(
+                    LABEL_SPEC_HASH.to_string(),
+                    hash_label_value(spec_hash),
+                )])),
+                ..Default::default()
+            },
+            spec: frontend_forge_api::JsBundleSpec {
+                raw: None,
+                raw_from: None,
+            },
+            status: None,
+        }
+    }
+
+    #[test]
+    fn job_selection_prefers_non_failed_jobs_for_a_hash() {
+        let job = |name: &str, created: &str, status: serde_json::Value| -> Job {
+            serde_json::from_value(json!({
+                "metadata": {"name": name, "creationTimestamp": created},
+                "status": status
+            }))
+            .unwrap()
+        };
+        let selected = |jobs: Vec<Job>| select_job_for_hash(jobs).map(|job| job.name_any());
+        let failed_old = job("failed-old", "2026-01-01T00:00:00Z", json!({"failed": 1}));
+        let running = job("running", "2026-01-01T00:01:00Z", json!({"active": 1}));
+        let succeeded = job("succeeded", "2026-01-01T00:02:00Z", json!({"succeeded": 1}));
+        let failed_new = job("failed-new", "2026-01-01T00:03:00Z", json!({"failed": 1}));
+        let pending = job("pending", "2026-01-01T00:04:00Z", json!({}));
+
+        assert_eq!(
+            selected(vec![
+                failed_new.clone(),
+                running.clone(),
+                failed_old.clone()
+            ]),
+            Some("running".to_string())
+        );
+        assert_eq!(
+            selected(vec![running.clone(), succeeded.clone(), failed_new.clone()]),
+            Some("succeeded".to_string())
+        );
+        assert_eq!(
+            selected(vec![failed_new.clone(), pending, running]),
+            Some("pending".to_string())
+        );
+        assert_eq!(
+            selected(vec![failed_new, failed_old]),
+            Some("failed-new".to_string())
+        );
+        assert_eq!(selected(vec![]), None);
+    }
+
+    #[test]
+    fn job_selection_breaks_timestamp_ties_by_name() {
+        let job = |name: &str| -> Job {
+            serde_json::from_value(json!({
+                "metadata": {"name": name, "creationTimestamp": "2026-01-01T00:00:00Z"},
+                "status": {"active": 1}
+            }))
+            .unwrap()
+        };
+        let (first, second) = (job("fi-demo-build-abc-1"), job("fi-demo-build-abc-2"));
+
+        for jobs in [
+            vec![first.clone(), second.clone()],
+            vec![second.clone(), first.clone()],
+        ] {
+            assert_eq!(
+                select_job_for_hash(jobs).map(|job| job.name_any()),
+                Some("fi-demo-build-abc-2".to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn bundle_hash_mismatch_waits_for_the_runner_before_failing() {
+        let job: Job = serde_json::from_value(json!({
+            "metadata": {"name": "fi-demo-build"},
+            "status": {"succeeded": 1, "completionTime": "2026-01-01T00:00:00Z"}
+        }))
+        .unwrap();
+        let completed = "2026-01-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let mut bundle = bundle_for_hash("fi-demo", "sha256:old");
+
+        assert_eq!(
+            bundle_hash_mismatch(
+                &job,
+                &bundle,
+                "sha256:new",
+                completed + chrono::Duration::seconds(10)
+            ),
+            Some(BundleHashMismatch {
+                actual: Some("old".to_string()),
+                grace_expired: false,
+            })
+        );
+
+        // The runner finishes relabeling within the grace window.
+        bundle = bundle_for_hash("fi-demo", "sha256:new");
+        assert_eq!(
+            bundle_hash_mismatch(
+                &job,
+                &bundle,
+                "sha256:new",
+                completed + chrono::Duration::seconds(30)
+            ),
+            None
+        );
+
+        bundle.metadata.labels = None;
+        assert_eq!(
+            bundle_hash_mismatch(
+                &job,
+                &bundle,
+                "sha256:new",
+                completed + chrono::Duration::seconds(BUNDLE_HASH_MISMATCH_GRACE_SECONDS)
+            ),
+            Some(BundleHashMismatch {
+                actual: None,
+                grace_expired: true,
+            })
+        );
+        assert_eq!(
+            bundle_hash_mismatch(
+                &Job::default(),
+                &bundle,
+                "sha256:new",
+                completed + chrono::Duration::days(1)
+            ),
+            Some(BundleHashMismatch {
+                actual: None,
+                grace_expired: false,
+            })
+        );
+    }
+
+    #[test]
+    fn teardown_covers_every_artifact_a_bundle_points_at() {
+        let key_ref = |name: &str| JsBundleNamespacedKeyRef {
+            key: "index.js".to_string(),
+            name: name.to_string(),
+            namespace: "extension-frontend-forge".to_string(),
+            optional: None,
+        };
+        let mut bundle = bundle_for_hash("fi-demo", "sha256:demo");
+        assert!(bundle_artifact_refs(&bundle, "extension-frontend-forge").is_empty());
+
+        bundle.spec.raw_from = Some(frontend_forge_api::JsBundleRawFromSpec {
+            config_map_key_ref: Some(key_ref("fi-demo-config")),
+            secret_key_ref: Some(key_ref("fi-demo-secret")),
+            url: None,
+        });
+        let refs = bundle_artifact_refs(&bundle, "extension-frontend-forge")
+            .into_iter()
+            .map(|(kind, key_ref)| (kind, key_ref.name.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            refs,
+            [
+                ("ConfigMap", "fi-demo-config"),
+                ("Secret", "fi-demo-secret")
+            ]
+        );
+
+        // Refs into any other namespace are never followed.
+        bundle
+            .spec
+            .raw_from
+            .as_mut()
+            .unwrap()
+            .secret_key_ref
+            .as_mut()
+            .unwrap()
+            .namespace = "kube-system".to_string();
+        let refs = bundle_artifact_refs(&bundle, "extension-frontend-forge");
+        assert_eq!(refs.len(), 1);
+        assert_eq!(refs[0].0, "ConfigMap");
+    }
+
+    #[test]
+    fn only_artifacts_labeled_for_the_fi_are_owned() {
+        let labels = |pairs: &[(&str, &str)]| {
+            pairs
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect::<BTreeMap<_, _>>()
+        };
+        assert!(is_owned_artifact(
+            &labels(&[
+                (LABEL_MANAGED_BY, MANAGED_BY_VALUE),
+                (LABEL_FI_NAME, "demo")
+            ]),
+            "demo"
+        ));
+        assert!(!is_owned_artifact(
+            &labels(&[(LABEL_FI_NAME, "demo")]),
+            "demo"
+        ));
+        assert!(!is_owned_artifact(
+            &labels(&[
+                (LABEL_MANAGED_BY, MANAGED_BY_VALUE),
+                (LABEL_FI_NAME, "other")
+            ]),
+            "demo"
+        ));
+        assert!(!is_owned_artifact(&labels(&[]), "demo"));
+    }
+
+    #[test]
+    fn reenable_is_detected_only_from_the_disabled_status() {
+        let mut fi = fi("demo", None);
+        assert!(!is_disabled_status(&fi));
+
+        fi.status = Some(disabled_status(&fi, None));
+        assert!(is_disabled_status(&fi));
+        assert_eq!(fi.status.as_ref().unwrap().bundle_ref, None);
+
+        fi.status.as_mut().unwrap().phase = FrontendIntegrationPhase::Building;
+        assert!(!is_disabled_status(&fi));
+    }
+
+    #[test]
+    fn configmap_drift_detects_deleted_edited_and_stale_bundles() {
+        let key_ref = JsBundleNamespacedKeyRef {
+            key: "index.js".to_string(),
+            name: "fi-demo-config".to_string(),
+            namespace: "extension-frontend-forge".to_string(),
+            optional: None,
+        };
+        let content = "console.log('ok')";
+        let configmap = ConfigMap {
+            metadata: ObjectMeta {
+                labels: Some(BTreeMap::from([(
+                    LABEL_SPEC_HASH.to_string(),
+                    hash_label_value("sha256:abc"),
+                )])),
+                annotations: Some(BTreeMap::from([(
+                    ANNO_CONTENT_HASH.to_string(),
+                    manifest_hash_from_content(content),
+                )])),
+                ..Default::default()
+            },
+            data: Some(BTreeMap::from([(
+                "index.js".to_string(),
+                content.to_string(),
+            )])),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            configmap_drift_reason(&key_ref, Some(&configmap), "sha256:abc"),
+            None
+        );
+        assert_eq!(
+            configmap_drift_reason(&key_ref, None, "sha256:abc").as_deref(),
+            Some("bundle ConfigMap extension-frontend-forge/fi-demo-config was deleted")
+        );
+        assert!(configmap_drift_reason(&key_ref, Some(&configmap), "sha256:def").is_some());
+
+        let mut edited = configmap.clone();
+        edited
+            .data
+            .get_or_insert_with(BTreeMap::new)
+            .insert("index.js".to_string(), "alert('edited')".to_string());
+        assert_eq!(
+            configmap_drift_reason(&key_ref, Some(&edited), "sha256:abc").as_deref(),
+            Some("bundle ConfigMap extension-frontend-forge/fi-demo-config was modified")
+        );
+
+        let mut emptied = configmap;
+        emptied.data = None;
+        assert!(configmap_drift_reason(&key_ref, Some(&emptied), "sha256:abc").is_some());
+    }
+
+    #[test]
+    fn orphan_bundles_skip_desired_and_unmanaged_bundles() {
+        let managed = |name: &str| {
+            let mut bundle = bundle_for_hash(name, "sha256:abc");
+            bundle
+                .labels_mut()
+                .insert(LABEL_MANAGED_BY.to_string(), MANAGED_BY_VALUE.to_string());
+            bundle
+        };
+        let bundles = vec![
+            managed("fi-demo"),
+            managed("demo-legacy"),
+            bundle_for_hash("demo-foreign", "sha256:abc"),
+        ];
+
+        let orphans = orphan_bundles(&bundles, "fi-demo");
+        let names: Vec<String> = orphans.iter().map(|bundle| bundle.name_any()).collect();
+
+        assert_eq!(names, vec!["demo-legacy".to_string()]);
+    }
+
+    #[test]
+    fn build_hash_ignores_enabled() -> Result<(), CommonError> {
+        let mut enabled_fi = fi("demo", None);
+        enabled_fi.spec.enabled = Some(true);
+
+        let mut disabled_fi = fi("demo", None);
+        disabled_fi.spec.enabled = Some(false);
+
+        assert_eq!(spec_hash(&enabled_fi)?, spec_hash(&disabled_fi)?);
+        Ok(())
+    }
+
+    #[test]
+    fn build_hash_ignores_placement_and_page_order() -> Result<(), CommonError> {
+        let mut fi = fi("demo", None);
+        let mut workspace_menu = fi.spec.menus[0].clone();
+        workspace_menu.key = "guide".to_string();
+        workspace_menu.placement = MenuPlacement::Workspace;
+        let mut guide_page = fi.spec.pages[0].clone();
+        guide_page.key = "guide".to_string();
+        fi.spec.menus.push(workspace_menu);
+        fi.spec.pages.push(guide_page);
+
+        let mut reordered = fi.clone();
+        reordered.spec.menus.reverse();
+        reordered.spec.pages.reverse();
+        assert_eq!(spec_hash(&fi)?, spec_hash(&reordered)?);
+
+        // Reordering menus that share a placement changes the nav, so it is a new build.
+        fi.spec.menus[1].placement = MenuPlacement::Global;
+        reordered.spec.menus[0].placement = MenuPlacement::Global;
+        assert_ne!(spec_hash(&fi)?, spec_hash(&reordered)?);
+        Ok(())
+    }
+
+    #[test]
+    fn manifest_hash_ignores_placement_order_across_menus() {
+        let manifest_hash = |fi: &FrontendIntegration| {
+            let manifest = frontend_forge_manifest::render_extension_manifest_with_options(
+                fi,
+                &RenderOptions::default(),
+            )
+            .unwrap();
+            frontend_forge_common::manifest_content_and_hash(&manifest).unwrap()
+        };
+        let mut fi = fi("demo", None);
+        for (key, placement) in [
+            ("guide", MenuPlacement::Workspace),
+            ("docs", MenuPlacement::Global),
+            ("more", MenuPlacement::Workspace),
+            ("ops", MenuPlacement::Project),
+            ("tools", MenuPlacement::Cluster),
+            ("logs", MenuPlacement::Project),
+        ] {
+            let mut menu = fi.spec.menus[0].clone();
+            menu.key = key.to_string();
+            menu.placement = placement;
+            let mut page = fi.spec.pages[0].clone();
+            page.key = key.to_string();
+            fi.spec.menus.push(menu);
+            fi.spec.pages.push(page);
+        }
+
+        // Same relative order within each placement, different order across them.
+        let mut permuted = fi.clone();
+        permuted.spec.menus = [4, 5, 1, 6, 3, 0, 2]
+            .map(|idx| fi.spec.menus[idx].clone())
+            .to_vec();
+        permuted.spec.pages.reverse();
+        assert_ne!(fi.spec.menus, permuted.spec.menus);
+        let (content, hash) = manifest_hash(&fi);
+        assert_eq!((content.clone(), hash), manifest_hash(&permuted));
+        assert_eq!(
+            fi.spec.effective_placements(),
+            permuted.spec.effective_placements()
+        );
+
+        // The rendered menus follow the same order effective_placements lists.
+        let manifest: serde_json::Value = serde_json::from_str(&content).unwrap();
+        let mut parents = manifest["menus"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|menu| menu["parent"].as_str().unwrap().to_string())
+            .collect::<Vec<_>>();
+        parents.dedup();
+        assert_eq!(
+            parents,
+            fi.spec
+                .effective_placements()
+                .iter()
+                .map(|placement| placement.as_str())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn builder_output_changes_the_manifest_hash() {
+        let manifest_hash = |fi: &FrontendIntegration| {
+            let manifest = frontend_forge_manifest::render_extension_manifest_with_options(
+                fi,
+                &RenderOptions::default(),
+            )
+            .unwrap();
+            frontend_forge_common::manifest_content_and_hash(&manifest)
+                .unwrap()
+                .1
+        };
+        let base = fi("demo", None);
+        let mut hashes = vec![manifest_hash(&base)];
+        for output in [
+            BuildOutputSpec {
+                format: Some(BuildOutputFormat::Esm),
+                ..Default::default()
+            },
+            BuildOutputSpec {
+                externals: vec!["react".to_string()],
+                ..Default::default()
+            },
+            BuildOutputSpec {
+                minify: Some(false),
+                ..Default::default()
+            },
+        ] {
+            let mut fi = base.clone();
+            fi.spec.builder = Some(BuilderSpec {
+                output: Some(output),
+                ..Default::default()
+            });
+            hashes.push(manifest_hash(&fi));
+        }
+        let unique = hashes.iter().collect::<std::collections::HashSet<_>>();
+        assert_eq!(unique.len(), hashes.len());
+    }
+
+    #[test]
+    fn needs_build_when_hash_changes() {
+        let mut fi = fi(
+            "demo",
+            Some(FrontendIntegrationStatus {
+                observed_spec_hash: Some("sha256:old".to_string()),
+                phase: FrontendIntegrationPhase::Succeeded,
+                ..Default::default()
+            }),
+        );
+        fi.spec.enabled = Some(true);
+        assert!(needs_new_build(&fi, "sha256:new", None));
+    }
+
+    #[test]
+    fn does_not_build_when_observed_hash_matches() -> Result<(), CommonError> {
+        let mut fi = fi("demo", None);
+        fi.spec.enabled = Some(true);
+        let hash = spec_hash(&fi)?;
+        let bundle = bundle_for_hash("fi-demo", &hash);
+        fi.status = Some(FrontendIntegrationStatus {
+            observed_spec_hash: Some(hash.clone()),
+            phase: FrontendIntegrationPhase::Succeeded,
+            ..Default::default()
+        });
+
+        assert!(!needs_new_build(&fi, &hash, Some(&bundle)));
+        Ok(())
+    }
+
+    #[test]
+    fn adopts_existing_matching_bundle_instead_of_rebuilding() -> Result<(), CommonError> {
+        let mut fi = fi("demo", None);
+        fi.spec.enabled = Some(true);
+        let hash = spec_hash(&fi)?;
+        let bundle = bundle_for_hash("fi-demo", &hash);
+
+        assert!(needs_new_build(&fi, &hash, None));
+        assert!(!needs_new_build(&fi, &hash, Some(&bundle)));
+        assert!(needs_new_build(
+            &fi,
+            &hash,
+            Some(&bundle_for_hash("fi-demo", "sha256:other"))
+        ));
+
+        fi.status = Some(FrontendIntegrationStatus {
+            observed_spec_hash: Some("sha256:old".to_string()),
+            phase: FrontendIntegrationPhase::Succeeded,
+            ..Default::default()
+        });
+        assert!(!needs_new_build(&fi, &hash, Some(&bundle)));
+        Ok(())
+    }
+
+    #[test]
+    fn force_rebuild_token_set_build_then_cleared() -> Result<(), CommonError> {
+        let mut fi = fi("demo", None);
+        let hash = spec_hash(&fi)?;
+        let bundle = bundle_for_hash("fi-demo", &hash);
+        let manifest = |fi: &FrontendIntegration| {
+            frontend_forge_manifest::render_extension_manifest_with_options(
+                fi,
+                &RenderOptions::default(),
+            )
+            .unwrap()
+        };
+        let built_manifest = manifest(&fi);
+        fi.status = Some(FrontendIntegrationStatus {
+            observed_spec_hash: Some(hash.clone()),
+            phase: FrontendIntegrationPhase::Succeeded,
+            ..Default::default()
+        });
+        assert!(!needs_new_build(&fi, &hash, Some(&bundle)));
+
+        // Setting a token rebuilds the same spec under the same hash and manifest.
+        fi.spec.force_rebuild_token = Some("2026-10-16".to_string());
+        assert_eq!(spec_hash(&fi)?, hash);
+        assert_eq!(manifest(&fi), built_manifest);
+        assert!(needs_new_build(&fi, &hash, Some(&bundle)));
+
+        // Starting the Job records the token, which settles the build.
+        let job = make_build_job(
+            &fi,
+            &ControllerConfig::for_tests(),
+            None,
+            None,
+            "fi-demo-build",
+            "fi-demo",
+            &hash,
+        );
+        fi.status = Some(building_status(
+            &fi,
+            &hash,
+            "fi-demo",
+            &job,
+            "Build in progress",
+        ));
+        assert_eq!(
+            fi.status
+                .as_ref()
+                .unwrap()
+                .observed_force_rebuild_token
+                .as_deref(),
+            Some("2026-10-16")
+        );
+        assert!(!needs_new_build(&fi, &hash, Some(&bundle)));
+        fi.status = Some(succeeded_status(&fi, &hash, &bundle, &job));
+        assert!(!needs_new_build(&fi, &hash, Some(&bundle)));
+
+        // Clearing the token is not a rebuild, and the observed token is carried forward.
+        fi.spec.force_rebuild_token = None;
+        assert!(!needs_new_build(&fi, &hash, Some(&bundle)));
+        assert_eq!(
+            succeeded_status(&fi, &hash, &bundle, &job)
+                .observed_force_rebuild_token
+                .as_deref(),
+            Some("2026-10-16")
+        );
+
+        // Only a different token rebuilds again.
+        fi.spec.force_rebuild_token = Some("2026-10-16".to_string());
+        assert!(!needs_new_build(&fi, &hash, Some(&bundle)));
+        fi.spec.force_rebuild_token = Some("2026-10-17".to_string());
+        assert!(needs_new_build(&fi, &hash, Some(&bundle)));
+        Ok(())
+    }
+
+    #[test]
+    fn does_not_auto_retry_failed_build_when_hash_is_unchanged() -> Result<(), CommonError> {
+        let mut fi = fi("demo", None);
+        fi.spec.enabled = Some(true);
+        let hash = spec_hash(&fi)?;
+        fi.status = Some(FrontendIntegrationStatus {
+            observed_spec_hash: Some(hash.clone()),
+            phase: FrontendIntegrationPhase::Failed,
+            ..Default::default()
+        });
+
+        assert!(!needs_new_build(&fi, &hash, None));
+        Ok(())
+    }
+
+    #[test]
+    fn builds_when_matching_bundle_is_missing_after_reenable() -> Result<(), CommonError> {
+        let mut fi = fi("demo", None);
+        fi.spec.enabled = Some(true);
+        let hash = spec_hash(&fi)?;
+        fi.status = Some(FrontendIntegrationStatus {
+            observed_spec_hash: Some(hash.clone()),
+            phase: FrontendIntegrationPhase::Pending,
+            message: Some("Disabled".to_string()),
+            ..Default::default()
+        });
+
+        assert!(needs_new_build(&fi, &hash, None));
+        Ok(())
+    }
+
+    #[test]
+    fn hash_label_value_is_dns_safe() {
+        assert_eq!(hash_label_value("sha256:abcd"), "abcd");
+        assert_eq!(hash_label_value("abcd"), "abcd");
+    }
+
+    fn job_with_status(active: Option<i32>, succeeded: Option<i32>, failed: Option<i32>) -> Job {
+        Job {
+            status: Some(JobStatus {
+                active,
+                succeeded,
+                failed,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn job_with_failed_condition(message: Option<&str>, reason: Option<&str>) -> Job {
+        Job {
+            status: Some(JobStatus {
+                conditions: Some(vec![k8s_openapi::api::batch::v1::JobCondition {
+                    message: message.map(str::to_string),
+                    reason: reason.map(str::to_string),
+                    status: "True".to_string(),
+                    type_: "Failed".to_string(),
+                    ..Default::default()
+                }]),
+                failed: Some(1),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn does_not_reuse_failed_job_when_retrying_failed_phase() {
+        let fi = fi(
+            "demo",
+            Some(FrontendIntegrationStatus {
+                phase: FrontendIntegrationPhase::Failed,
+                ..Default::default()
+            }),
+        );
+        let failed_job = job_with_status(None, None, Some(1));
+
+        assert!(!should_reuse_build_job(
+            &fi,
+            &failed_job,
+            None,
+            "sha256:demo"
+        ));
+    }
+
+    #[test]
+    fn reuses_running_job_when_retrying_failed_phase() {
+        let fi = fi(
+            "demo",
+            Some(FrontendIntegrationStatus {
+                phase: FrontendIntegrationPhase::Failed,
+                ..Default::default()
+            }),
+        );
+        let running_job = job_with_status(Some(1), None, None);
+
+        assert!(should_reuse_build_job(
+            &fi,
+            &running_job,
+            None,
+            "sha256:demo"
+        ));
+    }
+
+    #[test]
+    fn does_not_reuse_succeeded_job_when_matching_bundle_is_missing() {
+        let fi = fi(
+            "demo",
+            Some(FrontendIntegrationStatus {
+                phase: FrontendIntegrationPhase::Succeeded,
+                ..Default::default()
+            }),
+        );
+        let succeeded_job = job_with_status(None, Some(1), None);
+
+        assert!(!should_reuse_build_job(
+            &fi,
+            &succeeded_job,
+            None,
+            "sha256:demo",
+        ));
+    }
+
+    #[test]
+    fn bundle_hash_match_uses_build_hash_label() -> Result<(), CommonError> {
+        let mut fi = fi("demo", None);
+        fi.spec.enabled = Some(true);
+        let hash = spec_hash(&fi)?;
+        let bundle = bundle_for_hash("fi-demo", &hash);
+
+        assert!(bundle_matches_spec_hash(&bundle, &hash));
+        Ok(())
+    }
+
+    #[test]
+    fn spec_change_is_deferred_until_maintenance_window_closes() -> Result<(), CommonError> {
+        let window: MaintenanceWindow = "01:00-03:00".parse().unwrap();
+        let fi = fi(
+            "demo",
+            Some(FrontendIntegrationStatus {
+                phase: FrontendIntegrationPhase::Succeeded,
+                observed_spec_hash: Some("sha256:old".to_string()),
+                ..Default::default()
+            }),
+        );
+        let hash = spec_hash(&fi)?;
+        assert!(needs_new_build(&fi, &hash, None));
+
+        let during = "2024-01-01T02:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let after = "2024-01-01T03:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(
+            build_deferral(Some(&window), during),
+            Some(Duration::from_secs(3600))
+        );
+        assert_eq!(build_deferral(Some(&window), after), None);
+        assert_eq!(build_deferral(None, during), None);
+
+        let status = deferred_status(&fi, None, BUILD_DEFERRED_MESSAGE);
+        assert_eq!(status.phase, FrontendIntegrationPhase::Pending);
+        assert_eq!(status.message.as_deref(), Some(BUILD_DEFERRED_MESSAGE));
+        assert_eq!(status.observed_spec_hash.as_deref(), Some("sha256:old"));
+        assert!(needs_new_build(
+            &FrontendIntegration {
+                status: Some(status),
+                ..fi.clone()
+            },
+            &hash,
+            None
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn disabled_status_clears_last_build_and_uses_live_bundle_ref() {
+        let fi = fi(
+            "demo",
+            Some(FrontendIntegrationStatus {
+                observed_spec_hash: Some("sha256:demo".to_string()),
+                observed_manifest_hash: Some("sha256:manifest".to_string()),
+                last_build: Some(LastBuildStatus {
+                    job_ref: Some(ResourceRef {
+                        name: "old-job".to_string(),
+                        namespace: Some("default".to_string()),
+                        uid: Some("job-uid".to_string()),
+                    }),
+                    started_at: Some(Utc::now()),
+                }),
+                bundle_ref: Some(ResourceRef {
+                    name: "stale-bundle".to_string(),
+                    namespace: None,
+                    uid: Some("stale-uid".to_string()),
+                }),
+                ..Default::default()
+            }),
+        );
+        let bundle = bundle_for_hash("fi-demo", "sha256:demo");
+        let status = disabled_status(&fi, Some(&bundle));
+
+        assert!(status.last_build.is_none());
+        assert_eq!(
+            status.bundle_ref.map(|bundle_ref| bundle_ref.name),
+            Some("fi-demo".to_string())
+        );
+        assert_eq!(status.message.as_deref(), Some("Disabled"));
+        assert_eq!(status.effective_placements, vec![MenuPlacement::Global]);
+    }
+
+    #[test]
+    fn building_status_preserves_last_error_for_same_spec_hash() {
+        let fi = fi(
+            "demo",
+            Some(FrontendIntegrationStatus {
+                observed_spec_hash: Some("sha256:demo".to_string()),
+                last_error: Some(LastBuildError {
+                    source: "runner".to_string(),
+                    message: "duplicate page key".to_string(),
+                    reason: Some("RunnerFailed".to_string()),
+                    occurred_at: Some(Utc::now()),
+                    log_tail: None,
+                }),
+                ..Default::default()
+            }),
+        );
+        let job = job_with_status(Some(1), None, None);
+
+        let status = building_status(&fi, "sha256:demo", "fi-demo", &job, "Build in progress");
+
+        assert_eq!(
+            status
+                .last_error
+                .as_ref()
+                .map(|error| error.message.as_str()),
+            Some("duplicate page key")
+        );
+    }
+
+    #[test]
+    fn building_status_preserves_started_at_for_same_job() {
+        let started_at = Utc::now();
+        let fi = fi(
+            "demo",
+            Some(FrontendIntegrationStatus {
+                phase: FrontendIntegrationPhase::Building,
+                observed_spec_hash: Some("sha256:demo".to_string()),
+                last_build: Some(LastBuildStatus {
+                    job_ref: Some(ResourceRef {
+                        name: "build-job".to_string(),
+                        namespace: Some("default".to_string()),
+                        uid: Some("job-uid".to_string()),
+                    }),
+                    started_at: Some(started_at),
+                }),
+                ..Default::default()
+            }),
+        );
+        let mut job = job_with_status(Some(1), None, None);
+        job.metadata.name = Some("build-job".to_string());
+
+        let status = building_status(&fi, "sha256:demo", "fi-demo", &job, "Build in progress");
+
+        assert_eq!(
+            status.last_build.and_then(|build| build.started_at),
+            Some(started_at)
+        );
+    }
+
+    #[test]
+    fn status_patch_is_skipped_when_status_is_unchanged() {
+        let status = FrontendIntegrationStatus {
+            phase: FrontendIntegrationPhase::Building,
+            observed_spec_hash: Some("sha256:demo".to_string()),
+            observed_generation: Some(3),
+            last_build: Some(LastBuildStatus {
+                job_ref: Some(ResourceRef {
+                    name: "build-job".to_string(),
+                    namespace: Some("default".to_string()),
+                    uid: Some("job-uid".to_string()),
+                }),
+                started_at: Some(Utc::now()),
+            }),
+            bundle_ref: Some(ResourceRef {
+                name: "fi-demo".to_string(),
+                namespace: None,
+                uid: None,
+            }),
+            message: Some("Build in progress".to_string()),
+            ..Default::default()
+        };
+        let fi = fi("demo", Some(status.clone()));
+
+        assert!(!fi_status_needs_patch(&fi, &status));
+    }
+
+    #[test]
+    fn status_patch_ignores_refreshed_timestamps() {
+        let earlier = Utc::now() - chrono::Duration::hours(1);
+        let failed = |occurred_at, condition_time| FrontendIntegrationStatus {
+            phase: FrontendIntegrationPhase::Failed,
+            observed_spec_hash: Some("sha256:demo".to_string()),
+            observed_generation: Some(3),
+            last_error: Some(LastBuildError {
+                source: "controller".to_string(),
+                message: "runner service account is not allowed".to_string(),
+                reason: Some(REASON_SERVICE_ACCOUNT_NOT_ALLOWED.to_string()),
+                occurred_at: Some(occurred_at),
+                log_tail: None,
+            }),
+            conditions: vec![SimpleCondition {
+                type_: CONDITION_PAUSED.to_string(),
+                status: "True".to_string(),
+                reason: None,
+                message: None,
+                observed_generation: Some(3),
+                last_transition_time: Some(condition_time),
+            }],
+            ..Default::default()
+        };
+        let fresh = fi("demo", None);
+        let fi = fi("demo", Some(failed(earlier, earlier)));
+
+        assert!(!fi_status_needs_patch(&fi, &failed(Utc::now(), Utc::now())));
+
+        let mut changed = failed(Utc::now(), earlier);
+        changed.last_error.as_mut().unwrap().message = "quota exceeded".to_string();
+        assert!(fi_status_needs_patch(&fi, &changed));
+        assert!(fi_status_needs_patch(&fresh, &changed));
+    }
+
+    #[test]
+    fn failure_error_prefers_existing_runner_error_for_same_spec_hash() {
+        let fi = fi(
+            "demo",
+            Some(FrontendIntegrationStatus {
+                observed_spec_hash: Some("sha256:demo".to_string()),
+                last_error: Some(LastBuildError {
+                    source: "runner".to_string(),
+                    message: "duplicate page key".to_string(),
+                    reason: Some("RunnerFailed".to_string()),
+                    occurred_at: Some(Utc::now()),
+                    log_tail: None,
+                }),
+                ..Default::default()
+            }),
+        );
+        let job = job_with_failed_condition(
+            Some("Job has reached the specified backoff limit"),
+            Some("BackoffLimitExceeded"),
+        );
+
+        let failure = failure_error_for_status(&fi, "sha256:demo", &job);
+
+        assert_eq!(failure.source, "runner");
+        assert_eq!(failure.message, "duplicate page key");
+    }
+
+    #[test]
+    fn log_tail_keeps_the_end_of_long_logs_on_char_boundaries() {
+        assert_eq!(truncate_log_tail(" \n\n", 16), None);
+        assert_eq!(
+            truncate_log_tail("error: boom\n", 16).as_deref(),
+            Some("error: boom")
+        );
+
+        let tail = truncate_log_tail("ééééé\nfatal: out of memory", 22).unwrap_or_default();
+        assert_eq!(tail, "...\nfatal: out of memory");
+    }
+
+    fn pod_with_status(status: serde_json::Value) -> Pod {
+        serde_json::from_value(json!({
+            "metadata": {"name": "fi-demo-build-0"},
+            "status": status
+        }))
+        .unwrap()
+    }
+
+    fn waiting_pod(reason: &str, message: &str) -> Pod {
+        pod_with_status(json!({
+            "phase": "Pending",
+            "containerStatuses": [{
+                "name": "runner",
+                "image": "registry.test/runner:v1",
+                "imageID": "",
+                "ready": false,
+                "restartCount": 0,
+                "state": {"waiting": {"reason": reason, "message": message}}
+            }]
+        }))
+    }
+
+    fn terminated_pod(reason: &str, exit_code: i32, message: Option<&str>) -> Pod {
+        pod_with_status(json!({
+            "phase": "Failed",
+            "containerStatuses": [{
+                "name": "runner",
+                "image": "registry.test/runner:v1",
+                "imageID": "",
+                "ready": false,
+                "restartCount": 0,
+                "state": {"terminated": {"reason": reason, "exitCode": exit_code, "message": message}}
+            }]
+        }))
+    }
+
+    #[test]
+    fn stuck_pods_fail_fast_as_infrastructure_failures() {
+        let failure =
+            stuck_pod_failure(&waiting_pod("ImagePullBackOff", "Back-off pulling image")).unwrap();
+        assert_eq!(failure.reason, "RunnerImagePullFailed");
+        assert_eq!(
+            failure.message,
+            "Infrastructure failure: runner image registry.test/runner:v1 cannot be pulled (ImagePullBackOff: Back-off pulling image)"
+        );
+        assert_eq!(
+            stuck_pod_failure(&waiting_pod(
+                "CreateContainerConfigError",
+                "secret \"x\" not found"
+            ))
+            .map(|failure| failure.reason),
+            Some("RunnerContainerStartFailed")
+        );
+
+        let mut crash_looping = waiting_pod("CrashLoopBackOff", "back-off 10s");
+        crash_looping
+            .status
+            .as_mut()
+            .unwrap()
+            .container_statuses
+            .as_mut()
+            .unwrap()[0]
+            .last_state =
+            Some(serde_json::from_value(json!({"terminated": {"exitCode": 139}})).unwrap());
+        assert_eq!(
+            stuck_pod_failure(&crash_looping).map(|failure| failure.message),
+            Some(
+                "Infrastructure failure: runner container is crash-looping (last exit code 139)"
+                    .to_string()
+            )
+        );
 
-    if status.last_build.is_none() {
-        status_object.insert("last_build".to_string(), serde_json::Value::Null);
+        // The kubelet retries these on its own.
+        assert_eq!(
+            stuck_pod_failure(&waiting_pod("ErrImagePull", "timeout")),
+            None
+        );
+        assert_eq!(
+            stuck_pod_failure(&waiting_pod("ContainerCreating", "")),
+            None
+        );
+        assert_eq!(stuck_pod_failure(&terminated_pod("Error", 1, None)), None);
     }
-    if status.bundle_ref.is_none() {
-        status_object.insert("bundle_ref".to_string(), serde_json::Value::Null);
+
+    #[test]
+    fn container_start_errors_wait_for_the_grace_period() {
+        let mut pod = waiting_pod("CreateContainerConfigError", "secret \"x\" not found");
+        let created = "2026-01-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        pod.metadata.creation_timestamp = Some(Time(
+            k8s_openapi::jiff::Timestamp::from_second(created.timestamp()).unwrap(),
+        ));
+
+        assert_eq!(
+            settled_stuck_pod_failure(&pod, created + chrono::Duration::seconds(30)),
+            None
+        );
+        assert_eq!(
+            settled_stuck_pod_failure(
+                &pod,
+                created + chrono::Duration::seconds(CONTAINER_START_GRACE_SECONDS)
+            )
+            .map(|failure| failure.reason),
+            Some("RunnerContainerStartFailed")
+        );
+        // Image pull back-off already reflects repeated kubelet retries.
+        let mut pulling = waiting_pod("ImagePullBackOff", "Back-off pulling image");
+        pulling.metadata.creation_timestamp = pod.metadata.creation_timestamp.clone();
+        assert_eq!(
+            settled_stuck_pod_failure(&pulling, created).map(|failure| failure.reason),
+            Some("RunnerImagePullFailed")
+        );
     }
-    if status.last_error.is_none() {
-        status_object.insert("last_error".to_string(), serde_json::Value::Null);
+
+    #[test]
+    fn failed_pods_separate_infrastructure_from_build_failures() {
+        assert_eq!(
+            pod_failure(&terminated_pod("Error", 2, Some("render failed\n"))),
+            Some(PodFailure {
+                reason: "BuildFailed",
+                message: "Build failed: runner exited with code 2: render failed".to_string(),
+            })
+        );
+        assert_eq!(
+            pod_failure(&terminated_pod("OOMKilled", 137, None)).map(|failure| failure.reason),
+            Some("RunnerOOMKilled")
+        );
+        assert_eq!(
+            pod_failure(&pod_with_status(json!({
+                "phase": "Failed",
+                "reason": "Evicted",
+                "message": "The node was low on resource: ephemeral-storage."
+            })))
+            .map(|failure| failure.message),
+            Some("Infrastructure failure: runner pod was evicted: The node was low on resource: ephemeral-storage.".to_string())
+        );
+        assert_eq!(
+            pod_failure(&waiting_pod("InvalidImageName", "bad ref")).map(|failure| failure.reason),
+            Some("RunnerImagePullFailed")
+        );
+        assert_eq!(pod_failure(&terminated_pod("Completed", 0, None)), None);
+
+        let error = pod_failure(&terminated_pod("Error", 1, None))
+            .unwrap()
+            .into_last_error(None);
+        assert_eq!(error.source, "pod");
+        assert_eq!(error.reason.as_deref(), Some("BuildFailed"));
     }
 
-    Ok(json!({
-        "status": status_value,
-    }))
-}
+    #[test]
+    fn runner_service_account_respects_allowlist() {
+        let mut config = ControllerConfig::for_tests();
+        config.runner_service_account = Some("frontend-forge-runner".to_string());
+        let mut fi = fi("demo", None);
+        assert_eq!(
+            runner_service_account(&fi, &config),
+            Ok(Some("frontend-forge-runner".to_string()))
+        );
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use frontend_forge_api::{
-        FrontendIntegrationSpec, IframePageSpec, LastBuildError, MenuNodeType, MenuPlacement,
-        PageSpec, PageType, PrimaryMenuSpec,
-    };
-    use k8s_openapi::api::batch::v1::JobStatus;
-    use kube::core::ObjectMeta;
+        fi.spec.builder = Some(BuilderSpec {
+            service_account_name: Some("team-a-runner".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(
+            runner_service_account(&fi, &config),
+            Ok(Some("team-a-runner".to_string()))
+        );
 
-    fn fi(name: &str, status: Option<FrontendIntegrationStatus>) -> FrontendIntegration {
-        FrontendIntegration {
-            metadata: ObjectMeta {
-                name: Some(name.to_string()),
-                namespace: Some("default".to_string()),
-                generation: Some(3),
-                ..Default::default()
-            },
-            spec: FrontendIntegrationSpec {
-                display_name: None,
-                locales: BTreeMap::new(),
-                enabled: Some(true),
-                menus: vec![PrimaryMenuSpec {
-                    display_name: "demo".to_string(),
-                    key: "demo".to_string(),
-                    icon: None,
-                    placement: MenuPlacement::Global,
-                    type_: MenuNodeType::Page,
-                    children: vec![],
-                }],
-                pages: vec![PageSpec {
-                    key: "demo".to_string(),
-                    type_: PageType::Iframe,
-                    crd_table: None,
-                    iframe: Some(IframePageSpec {
-                        src: "http://example.test".to_string(),
-                    }),
-                }],
-                builder: None,
-            },
-            status,
-        }
-    }
+        config.allowed_runner_service_accounts =
+            vec!["team-a-runner".to_string(), "team-b-runner".to_string()];
+        let service_account = runner_service_account(&fi, &config).unwrap();
+        let job = make_build_job(
+            &fi,
+            &config,
+            service_account,
+            None,
+            "fi-demo-build",
+            "fi-demo",
+            "sha256:abc",
+        );
+        let pod = job.spec.and_then(|spec| spec.template.spec).unwrap();
+        assert_eq!(pod.service_account_name.as_deref(), Some("team-a-runner"));
+        assert_eq!(
+            pod.security_context,
+            Some(RunnerSecurityContext::default().pod)
+        );
+        assert_eq!(
+            pod.containers[0].security_context,
+            Some(RunnerSecurityContext::default().container)
+        );
 
-    fn spec_hash(fi: &FrontendIntegration) -> Result<String, CommonError> {
-        build_spec_hash(fi)
+        fi.spec.builder = Some(BuilderSpec {
+            service_account_name: Some("cluster-admin-sa".to_string()),
+            ..Default::default()
+        });
+        let err = runner_service_account(&fi, &config).unwrap_err();
+        assert!(err.contains("'cluster-admin-sa'"));
     }
 
-    fn bundle_for_hash(name: &str, spec_hash: &str) -> JSBundle {
-        JSBundle {
-            metadata: ObjectMeta {
-                name: Some(name.to_string()),
-                labels: Some(BTreeMap::from([(
-                    LABEL_SPEC_HASH.to_string(),
-                    hash_label_value(spec_hash),
-                )])),
-                ..Default::default()
-            },
-            spec: frontend_forge_api::JsBundleSpec {
-                raw: None,
-                raw_from: None,
+    #[test]
+    fn builds_are_queued_once_the_namespace_limit_is_reached() -> Result<(), CommonError> {
+        assert_eq!(build_queue_delay(5, 0, 5), None);
+        assert_eq!(build_queue_delay(1, 2, 5), None);
+        assert_eq!(build_queue_delay(2, 2, 5), Some(Duration::from_secs(5)));
+        assert_eq!(build_queue_delay(4, 2, 5), Some(Duration::from_secs(20)));
+        assert_eq!(
+            build_queue_delay(40, 2, 5),
+            Some(Duration::from_secs(MAX_BUILD_QUEUE_BACKOFF_SECONDS))
+        );
+
+        let fi = fi("demo", None);
+        let hash = spec_hash(&fi)?;
+        let status = deferred_status(&fi, None, "Build queued: 2/2 build Jobs running in ns");
+        assert_eq!(status.phase, FrontendIntegrationPhase::Pending);
+        assert!(
+            status
+                .message
+                .as_deref()
+                .is_some_and(|message| message.starts_with(BUILD_QUEUED_MESSAGE))
+        );
+        // Queued FIs keep needing a build, so the requeue retries Job creation.
+        assert!(needs_new_build(
+            &FrontendIntegration {
+                status: Some(status),
+                ..fi.clone()
             },
-            status: None,
-        }
+            &hash,
+            None
+        ));
+        Ok(())
     }
 
     #[test]
-    fn build_hash_ignores_enabled() -> Result<(), CommonError> {
-        let mut enabled_fi = fi("demo", None);
-        enabled_fi.spec.enabled = Some(true);
-
-        let mut disabled_fi = fi("demo", None);
-        disabled_fi.spec.enabled = Some(false);
+    fn build_job_is_labeled_with_the_observed_generation() {
+        let job = make_build_job(
+            &fi("demo", None),
+            &ControllerConfig::for_tests(),
+            None,
+            None,
+            "fi-demo-build",
+            "fi-demo",
+            "sha256:abc",
+        );
+        let labels = job.metadata.labels.unwrap();
+        assert_eq!(
+            labels.get(LABEL_OBSERVED_GENERATION).map(String::as_str),
+            Some("3")
+        );
+        assert_eq!(labels.get(LABEL_SPEC_HASH).map(String::as_str), Some("abc"));
+        assert_eq!(
+            job.metadata
+                .annotations
+                .unwrap()
+                .get(ANNO_OBSERVED_GENERATION)
+                .map(String::as_str),
+            Some("3")
+        );
 
-        assert_eq!(spec_hash(&enabled_fi)?, spec_hash(&disabled_fi)?);
-        Ok(())
+        let mut ungenerated = fi("demo", None);
+        ungenerated.metadata.generation = None;
+        let job = make_build_job(
+            &ungenerated,
+            &ControllerConfig::for_tests(),
+            None,
+            None,
+            "fi-demo-build",
+            "fi-demo",
+            "sha256:abc",
+        );
+        assert!(
+            !job.metadata
+                .labels
+                .unwrap()
+                .contains_key(LABEL_OBSERVED_GENERATION)
+        );
     }
 
     #[test]
-    fn needs_build_when_hash_changes() {
-        let mut fi = fi(
-            "demo",
-            Some(FrontendIntegrationStatus {
-                observed_spec_hash: Some("sha256:old".to_string()),
-                phase: FrontendIntegrationPhase::Succeeded,
-                ..Default::default()
-            }),
+    fn build_job_carries_runner_scheduling_hints() {
+        let mut config = ControllerConfig::for_tests();
+        config.runner_priority_class = Some("frontend-forge-build".to_string());
+        config.runner_node_selector =
+            parse_json_env(Some(r#"{"node-role.kubernetes.io/build":"true"}"#)).unwrap();
+        config.runner_tolerations = parse_json_env(Some(
+            r#"[{"key":"dedicated","operator":"Equal","value":"build","effect":"NoSchedule"}]"#,
+        ))
+        .unwrap();
+
+        let job = make_build_job(
+            &fi("demo", None),
+            &config,
+            None,
+            None,
+            "fi-demo-build",
+            "fi-demo",
+            "sha256:abc",
         );
-        fi.spec.enabled = Some(true);
-        assert!(needs_new_build(&fi, "sha256:new", None));
+
+        assert_eq!(
+            job.metadata
+                .annotations
+                .as_ref()
+                .and_then(|annotations| annotations.get(ANNO_PRIORITY_CLASS))
+                .map(String::as_str),
+            Some("frontend-forge-build")
+        );
+        let pod = job.spec.and_then(|spec| spec.template.spec).unwrap();
+        assert_eq!(
+            pod.priority_class_name.as_deref(),
+            Some("frontend-forge-build")
+        );
+        assert_eq!(
+            pod.node_selector
+                .unwrap()
+                .get("node-role.kubernetes.io/build"),
+            Some(&"true".to_string())
+        );
+        assert_eq!(
+            pod.tolerations.unwrap()[0].key.as_deref(),
+            Some("dedicated")
+        );
+
+        let job = make_build_job(
+            &fi("demo", None),
+            &ControllerConfig::for_tests(),
+            None,
+            None,
+            "fi-demo-build",
+            "fi-demo",
+            "sha256:abc",
+        );
+        let pod = job.spec.and_then(|spec| spec.template.spec).unwrap();
+        assert_eq!(pod.priority_class_name, None);
+        assert_eq!(pod.node_selector, None);
+        assert_eq!(pod.tolerations, None);
     }
 
     #[test]
-    fn does_not_build_when_observed_hash_matches() -> Result<(), CommonError> {
+    fn builder_runner_image_overrides_the_default() {
+        let job_image = |fi: &FrontendIntegration| {
+            make_build_job(
+                fi,
+                &ControllerConfig::for_tests(),
+                None,
+                None,
+                "fi-demo-build",
+                "fi-demo",
+                "sha256:abc",
+            )
+            .spec
+            .and_then(|spec| spec.template.spec)
+            .and_then(|pod| pod.containers[0].image.clone())
+            .unwrap()
+        };
+
         let mut fi = fi("demo", None);
-        fi.spec.enabled = Some(true);
-        let hash = spec_hash(&fi)?;
-        let bundle = bundle_for_hash("fi-demo", &hash);
-        fi.status = Some(FrontendIntegrationStatus {
-            observed_spec_hash: Some(hash.clone()),
-            phase: FrontendIntegrationPhase::Succeeded,
+        assert_eq!(job_image(&fi), "runner:env");
+
+        fi.spec.builder = Some(BuilderSpec {
+            runner_image: Some("registry.example.com/runner:canary".to_string()),
             ..Default::default()
         });
+        assert_eq!(job_image(&fi), "registry.example.com/runner:canary");
 
-        assert!(!needs_new_build(&fi, &hash, Some(&bundle)));
-        Ok(())
+        // Invalid overrides keep the default image so the runner can report the error.
+        fi.spec.builder.as_mut().unwrap().runner_image = Some("not an image".to_string());
+        assert_eq!(job_image(&fi), "runner:env");
     }
 
     #[test]
-    fn does_not_auto_retry_failed_build_when_hash_is_unchanged() -> Result<(), CommonError> {
+    fn builder_resources_override_controller_defaults_in_the_job() {
+        let mut config = ControllerConfig::for_tests();
+        config.runner_resources =
+            parse_json_env(Some(r#"{"requests":{"cpu":"100m","memory":"256Mi"}}"#)).unwrap();
         let mut fi = fi("demo", None);
-        fi.spec.enabled = Some(true);
-        let hash = spec_hash(&fi)?;
-        fi.status = Some(FrontendIntegrationStatus {
-            observed_spec_hash: Some(hash.clone()),
-            phase: FrontendIntegrationPhase::Failed,
+        fi.spec.builder = Some(BuilderSpec {
+            resources: Some(BuilderResourcesSpec {
+                requests: BTreeMap::from([("memory".to_string(), "2Gi".to_string())]),
+                limits: BTreeMap::from([("memory".to_string(), "4Gi".to_string())]),
+            }),
             ..Default::default()
         });
 
-        assert!(!needs_new_build(&fi, &hash, None));
-        Ok(())
+        let resources = runner_resources(&fi, &config).unwrap();
+        let job = make_build_job(
+            &fi,
+            &config,
+            None,
+            resources,
+            "fi-demo-build",
+            "fi-demo",
+            "sha256:abc",
+        );
+        let container = job
+            .spec
+            .and_then(|spec| spec.template.spec)
+            .map(|pod| pod.containers[0].clone())
+            .unwrap();
+        let resources = container.resources.unwrap();
+        let requests = resources.requests.unwrap();
+        assert_eq!(requests["memory"].0, "2Gi");
+        assert_eq!(requests["cpu"].0, "100m");
+        assert_eq!(resources.limits.unwrap()["memory"].0, "4Gi");
+
+        fi.spec.builder.as_mut().unwrap().resources = Some(BuilderResourcesSpec {
+            limits: BTreeMap::from([("memory".to_string(), "4 GB".to_string())]),
+            ..Default::default()
+        });
+        assert_eq!(
+            runner_resources(&fi, &config),
+            Err("builder.resources: limits.memory '4 GB' is not a valid quantity".to_string())
+        );
     }
 
     #[test]
-    fn builds_when_matching_bundle_is_missing_after_reenable() -> Result<(), CommonError> {
+    fn pause_edit_unpause_resumes_with_the_edited_spec() -> Result<(), CommonError> {
         let mut fi = fi("demo", None);
-        fi.spec.enabled = Some(true);
-        let hash = spec_hash(&fi)?;
+        let built = spec_hash(&fi)?;
+        let bundle = bundle_for_hash("fi-demo", &built);
         fi.status = Some(FrontendIntegrationStatus {
-            observed_spec_hash: Some(hash.clone()),
-            phase: FrontendIntegrationPhase::Pending,
-            message: Some("Disabled".to_string()),
+            observed_spec_hash: Some(built.clone()),
+            observed_manifest_hash: Some("sha256:manifest".to_string()),
+            phase: FrontendIntegrationPhase::Succeeded,
             ..Default::default()
         });
+        let paused_at = Utc::now();
 
-        assert!(needs_new_build(&fi, &hash, None));
+        // Pause: nothing to build, but the condition is raised.
+        fi.spec.paused = Some(true);
+        assert_eq!(spec_hash(&fi)?, built);
+        assert!(!needs_new_build(&fi, &built, Some(&bundle)));
+        let condition = paused_condition(&fi, paused_at).unwrap();
+        assert_eq!(
+            (condition.status.as_str(), condition.last_transition_time),
+            ("True", Some(paused_at))
+        );
+        fi.status.as_mut().unwrap().conditions = vec![condition];
+
+        // Edit while paused: a build is due, but it is held and the old hashes are kept.
+        fi.spec.display_name = Some("Renamed".to_string());
+        let edited = spec_hash(&fi)?;
+        assert_ne!(edited, built);
+        assert!(needs_new_build(&fi, &edited, Some(&bundle)));
+        let held = paused_hold_status(&fi, Some(&bundle)).unwrap();
+        assert_eq!(held.observed_spec_hash.as_deref(), Some(built.as_str()));
+        assert_eq!(
+            held.observed_manifest_hash.as_deref(),
+            Some("sha256:manifest")
+        );
+        assert_eq!(held.message.as_deref(), Some(BUILD_PAUSED_MESSAGE));
+        assert_eq!(held.conditions, fi.status.as_ref().unwrap().conditions);
+        let later = paused_at + chrono::Duration::minutes(5);
+        assert_eq!(
+            paused_condition(&fi, later).unwrap().last_transition_time,
+            Some(paused_at)
+        );
+        fi.status = Some(held);
+
+        // Unpause: the hold lifts, the condition goes away and the edit gets built.
+        fi.spec.paused = Some(false);
+        assert_eq!(spec_hash(&fi)?, edited);
+        assert!(paused_hold_status(&fi, Some(&bundle)).is_none());
+        assert!(paused_condition(&fi, later).is_none());
+        assert!(needs_new_build(&fi, &edited, Some(&bundle)));
         Ok(())
     }
 
     #[test]
-    fn hash_label_value_is_dns_safe() {
-        assert_eq!(hash_label_value("sha256:abcd"), "abcd");
-        assert_eq!(hash_label_value("abcd"), "abcd");
-    }
-
-    fn job_with_status(active: Option<i32>, succeeded: Option<i32>, failed: Option<i32>) -> Job {
-        Job {
-            status: Some(JobStatus {
-                active,
-                succeeded,
-                failed,
-                ..Default::default()
-            }),
-            ..Default::default()
-        }
-    }
-
-    fn job_with_failed_condition(message: Option<&str>, reason: Option<&str>) -> Job {
-        Job {
-            status: Some(JobStatus {
-                conditions: Some(vec![k8s_openapi::api::batch::v1::JobCondition {
-                    message: message.map(str::to_string),
-                    reason: reason.map(str::to_string),
-                    status: "True".to_string(),
-                    type_: "Failed".to_string(),
-                    ..Default::default()
-                }]),
-                failed: Some(1),
-                ..Default::default()
-            }),
+    fn spec_change_supersedes_unfinished_builds_of_older_hashes() -> Result<(), CommonError> {
+        let mut fi = fi("demo", None);
+        let old_hash = spec_hash(&fi)?;
+        let job_for = |fi: &FrontendIntegration, name: &str, hash: &str, status: Job| {
+            let mut job = make_build_job(
+                fi,
+                &ControllerConfig::for_tests(),
+                None,
+                None,
+                name,
+                "fi-demo",
+                hash,
+            );
+            job.status = status.status;
+            job
+        };
+        fi.metadata.generation = Some(3);
+        fi.status = Some(FrontendIntegrationStatus {
+            phase: FrontendIntegrationPhase::Building,
+            observed_spec_hash: Some(old_hash.clone()),
+            observed_manifest_hash: Some("sha256:manifest".to_string()),
+            observed_generation: Some(2),
+            message: Some("Build in progress".to_string()),
             ..Default::default()
-        }
-    }
+        });
+        fi.spec.display_name = Some("Renamed".to_string());
+        let new_hash = spec_hash(&fi)?;
 
-    #[test]
-    fn does_not_reuse_failed_job_when_retrying_failed_phase() {
-        let fi = fi(
-            "demo",
-            Some(FrontendIntegrationStatus {
-                phase: FrontendIntegrationPhase::Failed,
-                ..Default::default()
-            }),
+        let jobs = vec![
+            job_for(
+                &fi,
+                "old-running",
+                &old_hash,
+                job_with_status(Some(1), None, None),
+            ),
+            job_for(
+                &fi,
+                "old-done",
+                &old_hash,
+                job_with_status(None, Some(1), None),
+            ),
+            job_for(
+                &fi,
+                "new-running",
+                &new_hash,
+                job_with_status(Some(1), None, None),
+            ),
+        ];
+        // A Job cancelled by an earlier reconcile that is still terminating is not cancelled again.
+        let mut terminating = job_for(
+            &fi,
+            "old-terminating",
+            &old_hash,
+            job_with_status(Some(1), None, None),
+        );
+        terminating.metadata.deletion_timestamp =
+            Some(Time(k8s_openapi::jiff::Timestamp::UNIX_EPOCH));
+        let jobs = [jobs, vec![terminating]].concat();
+        let superseded = superseded_jobs(&jobs, &new_hash)
+            .into_iter()
+            .map(|job| job.name_any())
+            .collect::<Vec<_>>();
+        assert_eq!(superseded, vec!["old-running".to_string()]);
+
+        let status = superseded_status(&fi, &new_hash, &superseded);
+        assert_eq!(status.phase, FrontendIntegrationPhase::Superseded);
+        assert_eq!(
+            status.observed_spec_hash.as_deref(),
+            Some(old_hash.as_str())
+        );
+        assert_eq!(status.observed_generation, Some(3));
+        assert_eq!(
+            status.message.unwrap(),
+            format!(
+                "Superseded by generation 3 (spec hash {new_hash}); cancelled Job old-running; observed spec hash {old_hash} is the cancelled build's"
+            )
         );
-        let failed_job = job_with_status(None, None, Some(1));
 
-        assert!(!should_reuse_build_job(
-            &fi,
-            &failed_job,
-            None,
-            "sha256:demo"
-        ));
+        // The next reconcile still builds the new hash once the old Job is gone.
+        fi.status.as_mut().unwrap().phase = FrontendIntegrationPhase::Superseded;
+        assert!(needs_new_build(&fi, &new_hash, None));
+        Ok(())
     }
 
     #[test]
-    fn reuses_running_job_when_retrying_failed_phase() {
-        let fi = fi(
-            "demo",
-            Some(FrontendIntegrationStatus {
-                phase: FrontendIntegrationPhase::Failed,
-                ..Default::default()
-            }),
+    fn paused_fis_suspend_and_resume_unfinished_build_jobs() {
+        let mut running = make_build_job(
+            &fi("demo", None),
+            &ControllerConfig::for_tests(),
+            None,
+            None,
+            "fi-demo-build",
+            "fi-demo",
+            "sha256:abc",
         );
-        let running_job = job_with_status(Some(1), None, None);
+        running.status = job_with_status(Some(1), None, None).status;
+        assert_eq!(running.spec.as_ref().unwrap().suspend, None);
+
+        // Pausing suspends the running Job; repeated reconciles are no-ops.
+        assert_eq!(job_suspend_patch(&running, false), None);
+        assert_eq!(job_suspend_patch(&running, true), Some(true));
+        running.spec.as_mut().unwrap().suspend = Some(true);
+        assert_eq!(job_suspend_patch(&running, true), None);
+
+        // Unpausing resumes it.
+        assert_eq!(job_suspend_patch(&running, false), Some(false));
+
+        // Finished Jobs are never touched.
+        running.status = job_with_status(None, Some(1), None).status;
+        assert_eq!(job_suspend_patch(&running, false), None);
+        let failed = job_with_status(None, None, Some(1));
+        assert_eq!(job_suspend_patch(&failed, true), None);
+    }
 
-        assert!(should_reuse_build_job(
-            &fi,
-            &running_job,
-            None,
-            "sha256:demo"
-        ));
+    #[test]
+    fn skip_stale_check_annotation_is_passed_to_the_runner() {
+        let job_env = |fi: &FrontendIntegration| {
+            make_build_job(
+                fi,
+                &ControllerConfig::for_tests(),
+                None,
+                None,
+                "fi-demo-build",
+                "fi-demo",
+                "sha256:abc",
+            )
+            .spec
+            .and_then(|spec| spec.template.spec)
+            .map(|pod| pod.containers[0].env.clone().unwrap_or_default())
+            .unwrap()
+        };
+        let skips = |env: &[EnvVar]| {
+            env.iter()
+                .any(|var| var.name == "SKIP_STALE_CHECK" && var.value.as_deref() == Some("true"))
+        };
+
+        let mut fi = fi("demo", None);
+        assert!(!skips(&job_env(&fi)));
+
+        fi.annotations_mut()
+            .insert(ANNO_SKIP_STALE_CHECK.to_string(), "true".to_string());
+        assert!(skips(&job_env(&fi)));
+
+        fi.annotations_mut()
+            .insert(ANNO_SKIP_STALE_CHECK.to_string(), "no".to_string());
+        assert!(!skips(&job_env(&fi)));
     }
 
     #[test]
-    fn does_not_reuse_succeeded_job_when_matching_bundle_is_missing() {
-        let fi = fi(
-            "demo",
-            Some(FrontendIntegrationStatus {
-                phase: FrontendIntegrationPhase::Succeeded,
-                ..Default::default()
-            }),
+    fn bundle_overrides_reach_the_job_and_the_bundle_lookup() {
+        let mut config = ControllerConfig::for_tests();
+        config.allowed_bundle_namespaces = vec!["team-a".to_string()];
+        let env_value = |fi: &FrontendIntegration, name: &str| {
+            make_build_job(
+                fi,
+                &config,
+                None,
+                None,
+                "fi-demo-build",
+                &bundle_name_for(fi),
+                "sha256:abc",
+            )
+            .spec
+            .and_then(|spec| spec.template.spec)
+            .and_then(|pod| pod.containers[0].env.clone())
+            .unwrap()
+            .into_iter()
+            .find(|var| var.name == name)
+            .and_then(|var| var.value)
+            .unwrap()
+        };
+
+        let mut fi = fi("demo", None);
+        assert_eq!(bundle_name_for(&fi), "fi-demo");
+        assert_eq!(env_value(&fi, "JSBUNDLE_NAME"), "fi-demo");
+        assert_eq!(
+            env_value(&fi, "JSBUNDLE_CONFIGMAP_NAMESPACE"),
+            "extension-frontend-forge"
         );
-        let succeeded_job = job_with_status(None, Some(1), None);
 
-        assert!(!should_reuse_build_job(
-            &fi,
-            &succeeded_job,
-            None,
-            "sha256:demo",
-        ));
+        fi.spec.bundle_name = Some("team-a-portal".to_string());
+        fi.spec.bundle_config_map_namespace = Some("team-a".to_string());
+        assert_eq!(bundle_name_for(&fi), "team-a-portal");
+        assert_eq!(env_value(&fi, "JSBUNDLE_NAME"), "team-a-portal");
+        assert_eq!(env_value(&fi, "JSBUNDLE_CONFIGMAP_NAMESPACE"), "team-a");
+
+        // Invalid overrides fall back; rendering in the runner reports them.
+        fi.spec.bundle_name = Some("Team A".to_string());
+        fi.spec.bundle_config_map_namespace = Some("team.a".to_string());
+        assert_eq!(bundle_name_for(&fi), "fi-demo");
+        assert_eq!(
+            env_value(&fi, "JSBUNDLE_CONFIGMAP_NAMESPACE"),
+            "extension-frontend-forge"
+        );
     }
 
     #[test]
-    fn bundle_hash_match_uses_build_hash_label() -> Result<(), CommonError> {
+    fn bundle_namespace_overrides_must_be_allowlisted() {
+        let mut config = ControllerConfig::for_tests();
         let mut fi = fi("demo", None);
-        fi.spec.enabled = Some(true);
-        let hash = spec_hash(&fi)?;
-        let bundle = bundle_for_hash("fi-demo", &hash);
+        assert_eq!(
+            bundle_configmap_namespace(&fi, &config),
+            Ok("extension-frontend-forge")
+        );
 
-        assert!(bundle_matches_spec_hash(&bundle, &hash));
-        Ok(())
+        fi.spec.bundle_config_map_namespace = Some("extension-frontend-forge".to_string());
+        assert_eq!(
+            bundle_configmap_namespace(&fi, &config),
+            Ok("extension-frontend-forge")
+        );
+
+        fi.spec.bundle_config_map_namespace = Some("team-a".to_string());
+        assert_eq!(
+            bundle_configmap_namespace(&fi, &config),
+            Err(
+                "bundleConfigMapNamespace 'team-a' is not in ALLOWED_BUNDLE_NAMESPACES".to_string()
+            )
+        );
+        assert_eq!(
+            bundle_configmap_namespace_for(&fi, &config),
+            "extension-frontend-forge"
+        );
+
+        config.allowed_bundle_namespaces = vec!["team-a".to_string()];
+        assert_eq!(bundle_configmap_namespace(&fi, &config), Ok("team-a"));
     }
 
     #[test]
-    fn disabled_status_clears_last_build_and_uses_live_bundle_ref() {
-        let fi = fi(
-            "demo",
-            Some(FrontendIntegrationStatus {
-                observed_spec_hash: Some("sha256:demo".to_string()),
-                observed_manifest_hash: Some("sha256:manifest".to_string()),
-                last_build: Some(LastBuildStatus {
-                    job_ref: Some(ResourceRef {
-                        name: "old-job".to_string(),
-                        namespace: Some("default".to_string()),
-                        uid: Some("job-uid".to_string()),
-                    }),
-                    started_at: Some(Utc::now()),
-                }),
-                bundle_ref: Some(ResourceRef {
-                    name: "stale-bundle".to_string(),
-                    namespace: None,
-                    uid: Some("stale-uid".to_string()),
-                }),
-                ..Default::default()
-            }),
+    fn propagated_metadata_is_merged_onto_the_build_job() {
+        let mut fi = fi("demo", None);
+        fi.spec.propagate_labels = Some(BTreeMap::from([
+            ("team".to_string(), "portal".to_string()),
+            (LABEL_SPEC_HASH.to_string(), "spoofed".to_string()),
+        ]));
+        fi.spec.propagate_annotations = Some(BTreeMap::from([(
+            "example.com/cost-center".to_string(),
+            "1234".to_string(),
+        )]));
+        let job = make_build_job(
+            &fi,
+            &ControllerConfig::for_tests(),
+            None,
+            None,
+            "fi-demo-build",
+            "fi-demo",
+            "sha256:abc",
         );
-        let bundle = bundle_for_hash("fi-demo", "sha256:demo");
-        let status = disabled_status(&fi, Some(&bundle));
 
-        assert!(status.last_build.is_none());
+        let labels = job.metadata.labels.unwrap();
+        assert_eq!(labels.get("team").map(String::as_str), Some("portal"));
+        assert_eq!(labels.get(LABEL_SPEC_HASH).map(String::as_str), Some("abc"));
         assert_eq!(
-            status.bundle_ref.map(|bundle_ref| bundle_ref.name),
-            Some("fi-demo".to_string())
+            job.metadata
+                .annotations
+                .unwrap()
+                .get("example.com/cost-center")
+                .map(String::as_str),
+            Some("1234")
         );
-        assert_eq!(status.message.as_deref(), Some("Disabled"));
+
+        // An invalid map never reaches the Job; rendering in the runner reports it.
+        fi.spec
+            .propagate_labels
+            .as_mut()
+            .unwrap()
+            .insert("team".to_string(), "Portal Team".to_string());
+        let job = make_build_job(
+            &fi,
+            &ControllerConfig::for_tests(),
+            None,
+            None,
+            "fi-demo-build",
+            "fi-demo",
+            "sha256:abc",
+        );
+        assert!(!job.metadata.labels.unwrap().contains_key("team"));
     }
 
     #[test]
-    fn building_status_preserves_last_error_for_same_spec_hash() {
-        let fi = fi(
-            "demo",
-            Some(FrontendIntegrationStatus {
-                observed_spec_hash: Some("sha256:demo".to_string()),
-                last_error: Some(LastBuildError {
-                    source: "runner".to_string(),
-                    message: "duplicate page key".to_string(),
-                    reason: Some("RunnerFailed".to_string()),
-                    occurred_at: Some(Utc::now()),
-                }),
-                ..Default::default()
-            }),
+    fn bundles_labeled_for_another_fi_are_conflicts() {
+        let mut bundle = bundle_for_hash("team-a-portal", "sha256:abc");
+        assert_eq!(foreign_bundle_owner(&bundle, "demo"), None);
+
+        bundle
+            .labels_mut()
+            .insert(LABEL_FI_NAME.to_string(), "demo".to_string());
+        assert_eq!(foreign_bundle_owner(&bundle, "demo"), None);
+
+        bundle
+            .labels_mut()
+            .insert(LABEL_FI_NAME.to_string(), "other".to_string());
+        assert_eq!(
+            foreign_bundle_owner(&bundle, "demo").as_deref(),
+            Some("other")
         );
-        let job = job_with_status(Some(1), None, None);
+    }
 
-        let status = building_status(&fi, "sha256:demo", "fi-demo", &job, "Build in progress");
+    #[test]
+    fn otlp_endpoint_is_forwarded_to_the_runner_only_when_set() {
+        let job_env = |config: &ControllerConfig| {
+            make_build_job(
+                &fi("demo", None),
+                config,
+                None,
+                None,
+                "fi-demo-build",
+                "fi-demo",
+                "sha256:abc",
+            )
+            .spec
+            .and_then(|spec| spec.template.spec)
+            .map(|pod| pod.containers[0].env.clone().unwrap_or_default())
+            .unwrap()
+        };
+        let mut config = ControllerConfig::for_tests();
+        assert!(
+            !job_env(&config)
+                .iter()
+                .any(|var| var.name == OTEL_ENDPOINT_ENV || var.name == TRACEPARENT_ENV)
+        );
+
+        config.otel_exporter_endpoint = Some("http://otel-collector:4318".to_string());
+        let env = job_env(&config);
+        assert!(env.iter().any(|var| var.name == OTEL_ENDPOINT_ENV
+            && var.value.as_deref() == Some("http://otel-collector:4318")));
+        // Outside an exported span there is no trace to continue.
+        assert!(!env.iter().any(|var| var.name == TRACEPARENT_ENV));
+    }
+
+    #[test]
+    fn passthrough_env_is_copied_into_the_runner_job() {
+        let mut config = ControllerConfig::for_tests();
+        config.runner_passthrough_env = vec![
+            ("HTTPS_PROXY".to_string(), "http://egress:3128".to_string()),
+            ("NO_PROXY".to_string(), ".svc,.cluster.local".to_string()),
+        ];
+        let job = make_build_job(
+            &fi("demo", None),
+            &config,
+            None,
+            None,
+            "fi-demo-build",
+            "fi-demo",
+            "sha256:abc",
+        );
+        let env = job
+            .spec
+            .and_then(|spec| spec.template.spec)
+            .map(|pod| pod.containers[0].env.clone().unwrap_or_default())
+            .unwrap();
+        let value = |name: &str| {
+            env.iter()
+                .find(|var| var.name == name)
+                .and_then(|var| var.value.clone())
+        };
+        assert_eq!(value("HTTPS_PROXY").as_deref(), Some("http://egress:3128"));
+        assert_eq!(value("NO_PROXY").as_deref(), Some(".svc,.cluster.local"));
+        assert_eq!(value("HTTP_PROXY"), None);
+    }
 
+    #[test]
+    fn build_service_tls_secret_is_mounted_into_the_runner() {
+        let pod = |config: &ControllerConfig| {
+            make_build_job(
+                &fi("demo", None),
+                config,
+                None,
+                None,
+                "fi-demo-build",
+                "fi-demo",
+                "sha256:abc",
+            )
+            .spec
+            .and_then(|spec| spec.template.spec)
+            .unwrap()
+        };
+        let mut config = ControllerConfig::for_tests();
+        let unmounted = pod(&config);
+        assert_eq!(unmounted.volumes, None);
+        assert_eq!(unmounted.containers[0].volume_mounts, None);
+
+        config.build_service_tls_secret = Some("build-service-ca".to_string());
+        let mounted = pod(&config);
+        let volumes = mounted.volumes.unwrap();
         assert_eq!(
-            status
-                .last_error
+            volumes[0]
+                .secret
                 .as_ref()
-                .map(|error| error.message.as_str()),
-            Some("duplicate page key")
+                .and_then(|secret| secret.secret_name.as_deref()),
+            Some("build-service-ca")
         );
+        let mounts = mounted.containers[0].volume_mounts.clone().unwrap();
+        assert_eq!(mounts[0].name, volumes[0].name);
+        assert_eq!(mounts[0].mount_path, BUILD_SERVICE_TLS_MOUNT_PATH);
+        assert_eq!(mounts[0].read_only, Some(true));
     }
 
     #[test]
-    fn building_status_preserves_started_at_for_same_job() {
-        let started_at = Utc::now();
-        let fi = fi(
-            "demo",
-            Some(FrontendIntegrationStatus {
-                phase: FrontendIntegrationPhase::Building,
-                observed_spec_hash: Some("sha256:demo".to_string()),
-                last_build: Some(LastBuildStatus {
-                    job_ref: Some(ResourceRef {
-                        name: "build-job".to_string(),
-                        namespace: Some("default".to_string()),
-                        uid: Some("job-uid".to_string()),
-                    }),
-                    started_at: Some(started_at),
-                }),
-                ..Default::default()
-            }),
+    fn scheduling_env_values_must_parse() {
+        assert_eq!(
+            parse_json_env::<BTreeMap<String, String>>(Some("  ")).unwrap(),
+            BTreeMap::new()
         );
-        let mut job = job_with_status(Some(1), None, None);
-        job.metadata.name = Some("build-job".to_string());
-
-        let status = building_status(&fi, "sha256:demo", "fi-demo", &job, "Build in progress");
+        assert!(parse_json_env::<BTreeMap<String, String>>(Some(r#"{"zone":1}"#)).is_err());
+        assert!(parse_json_env::<Vec<Toleration>>(Some(r#"{"key":"dedicated"}"#)).is_err());
+    }
 
+    #[test]
+    fn reconcile_concurrency_is_parsed_and_bounded() {
         assert_eq!(
-            status.last_build.and_then(|build| build.started_at),
-            Some(started_at)
+            parse_reconcile_concurrency(None),
+            DEFAULT_RECONCILE_CONCURRENCY
+        );
+        assert_eq!(parse_reconcile_concurrency(Some(" 8 ")), 8);
+        assert_eq!(parse_reconcile_concurrency(Some("0")), 1);
+        assert_eq!(
+            parse_reconcile_concurrency(Some("1000")),
+            MAX_RECONCILE_CONCURRENCY
+        );
+        assert_eq!(
+            parse_reconcile_concurrency(Some("many")),
+            DEFAULT_RECONCILE_CONCURRENCY
         );
     }
 
     #[test]
-    fn status_patch_is_skipped_when_status_is_unchanged() {
+    fn only_log_format_json_switches_to_json() {
+        assert!(log_format_is_json(Some("json")));
+        assert!(log_format_is_json(Some(" JSON ")));
+        assert!(!log_format_is_json(None));
+        assert!(!log_format_is_json(Some("text")));
+        assert!(!log_format_is_json(Some("")));
+    }
+
+    #[test]
+    fn status_apply_leaves_fields_owned_by_other_writers_alone() -> Result<(), Error> {
         let status = FrontendIntegrationStatus {
-            phase: FrontendIntegrationPhase::Building,
-            observed_spec_hash: Some("sha256:demo".to_string()),
-            observed_generation: Some(3),
-            last_build: Some(LastBuildStatus {
-                job_ref: Some(ResourceRef {
-                    name: "build-job".to_string(),
-                    namespace: Some("default".to_string()),
-                    uid: Some("job-uid".to_string()),
-                }),
-                started_at: Some(Utc::now()),
-            }),
-            bundle_ref: Some(ResourceRef {
-                name: "fi-demo".to_string(),
-                namespace: None,
-                uid: None,
-            }),
-            message: Some("Build in progress".to_string()),
+            phase: FrontendIntegrationPhase::Pending,
+            conditions: vec![SimpleCondition {
+                type_: "WebhookReady".to_string(),
+                status: "True".to_string(),
+                ..Default::default()
+            }],
             ..Default::default()
         };
-        let fi = fi("demo", Some(status.clone()));
+        let patch = frontend_integration_status_patch(&status, "default", "demo")?;
 
-        assert!(!fi_status_needs_patch(&fi, &status));
+        assert_eq!(patch["apiVersion"], "frontend-forge.kubesphere.io/v1alpha1");
+        assert_eq!(patch["kind"], "FrontendIntegration");
+        assert_eq!(patch["status"]["phase"], "Pending");
+        let applied = patch["status"].as_object().unwrap();
+        for field in [
+            "url",
+            "bundle_ref",
+            "last_build",
+            "last_error",
+            "conditions",
+        ] {
+            assert!(!applied.contains_key(field), "{field}");
+        }
+        Ok(())
     }
 
     #[test]
-    fn failure_error_prefers_existing_runner_error_for_same_spec_hash() {
-        let fi = fi(
-            "demo",
-            Some(FrontendIntegrationStatus {
-                observed_spec_hash: Some("sha256:demo".to_string()),
-                last_error: Some(LastBuildError {
-                    source: "runner".to_string(),
-                    message: "duplicate page key".to_string(),
-                    reason: Some("RunnerFailed".to_string()),
-                    occurred_at: Some(Utc::now()),
-                }),
+    fn status_patch_sets_null_for_cleared_optional_refs() {
+        let bundle = bundle_for_hash("fi-demo", "sha256:abc");
+        let current = FrontendIntegrationStatus {
+            phase: FrontendIntegrationPhase::Succeeded,
+            bundle_ref: Some(resource_ref(&bundle)),
+            url: Some("/dist/fi-demo/index.js".to_string()),
+            last_error: Some(LastBuildError {
+                source: "runner".to_string(),
+                message: "boom".to_string(),
                 ..Default::default()
             }),
-        );
-        let job = job_with_failed_condition(
-            Some("Job has reached the specified backoff limit"),
-            Some("BackoffLimitExceeded"),
-        );
+            ..Default::default()
+        };
+        let live = fi("demo", Some(current.clone()));
+        let desired = FrontendIntegrationStatus {
+            phase: FrontendIntegrationPhase::Pending,
+            ..Default::default()
+        };
 
-        let failure = failure_error_for_status(&fi, "sha256:demo", &job);
+        assert_eq!(
+            cleared_status_refs_patch(&live, &desired),
+            Some(json!({
+                "status": {"bundle_ref": null, "url": null, "last_error": null}
+            }))
+        );
+        // Refs that are kept, or were never set, are left to the apply.
+        assert_eq!(cleared_status_refs_patch(&live, &current), None);
+        assert_eq!(cleared_status_refs_patch(&fi("demo", None), &desired), None);
+    }
 
-        assert_eq!(failure.source, "runner");
-        assert_eq!(failure.message, "duplicate page key");
+    // The stored object and the field manager owning each top-level status field.
+    type LiveObject = Arc<std::sync::Mutex<(serde_json::Value, BTreeMap<String, String>)>>;
+
+    // A minimal status endpoint that tracks one owner per top-level status field: an apply drops
+    // the fields its manager owned but no longer sends, and merge patch nulls remove a field.
+    async fn status_owning_api_server(
+        fi: &FrontendIntegration,
+        owners: &[(&str, &str)],
+    ) -> (Api<FrontendIntegration>, LiveObject) {
+        use axum::extract::{Query, State};
+        use axum::http::HeaderMap;
+        use std::collections::HashMap;
+
+        let live: LiveObject = Arc::new(std::sync::Mutex::new((
+            serde_json::to_value(fi).unwrap(),
+            owners
+                .iter()
+                .map(|(field, owner)| (field.to_string(), owner.to_string()))
+                .collect(),
+        )));
+        let app = axum::Router::new()
+            .route(
+                "/apis/frontend-forge.kubesphere.io/v1alpha1/frontendintegrations/{name}/status",
+                axum::routing::patch(
+                    |State(live): State<LiveObject>,
+                     Query(query): Query<HashMap<String, String>>,
+                     headers: HeaderMap,
+                     body: String| async move {
+                        let manager = query
+                            .get("fieldManager")
+                            .cloned()
+                            .unwrap_or_else(|| "kube-rs".to_string());
+                        let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+                        let sent = body["status"].as_object().cloned().unwrap_or_default();
+                        let apply = headers["content-type"] == "application/apply-patch+yaml";
+                        let mut live = live.lock().unwrap();
+                        let (object, owners) = &mut *live;
+                        let status = object["status"].as_object_mut().unwrap();
+                        if apply {
+                            owners.retain(|field, owner| {
+                                let dropped = *owner == manager && !sent.contains_key(field);
+                                if dropped {
+                                    status.remove(field);
+                                }
+                                !dropped
+                            });
+                        }
+                        for (field, value) in sent {
+                            if value.is_null() {
+                                status.remove(&field);
+                                owners.remove(&field);
+                            } else {
+                                status.insert(field.clone(), value);
+                                owners.insert(field, manager.clone());
+                            }
+                        }
+                        axum::Json(object.clone())
+                    },
+                ),
+            )
+            .with_state(live.clone());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        let client =
+            Client::try_from(kube::Config::new(format!("http://{addr}").parse().unwrap())).unwrap();
+        (Api::all(client), live)
     }
 
-    #[test]
-    fn status_patch_sets_null_for_cleared_optional_refs() -> Result<(), Error> {
-        let status = FrontendIntegrationStatus {
+    #[tokio::test]
+    async fn co_owned_status_fields_survive_a_controller_patch() {
+        let bundle = bundle_for_hash("fi-demo", "sha256:abc");
+        let live = fi(
+            "demo",
+            Some(FrontendIntegrationStatus {
+                phase: FrontendIntegrationPhase::Succeeded,
+                bundle_ref: Some(resource_ref(&bundle)),
+                url: Some("/dist/fi-demo/index.js".to_string()),
+                conditions: vec![SimpleCondition {
+                    type_: "WebhookReady".to_string(),
+                    status: "True".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+        );
+        // The refs were written by merge patches before the switch to server-side apply.
+        let (api, live_object) = status_owning_api_server(
+            &live,
+            &[
+                ("phase", "kube-rs"),
+                ("bundle_ref", "kube-rs"),
+                ("url", "kube-rs"),
+                ("conditions", "frontend-forge-webhook"),
+            ],
+        )
+        .await;
+
+        let disabled = FrontendIntegrationStatus {
             phase: FrontendIntegrationPhase::Pending,
-            last_build: None,
-            bundle_ref: None,
-            last_error: None,
+            message: Some(DISABLED_MESSAGE.to_string()),
             ..Default::default()
         };
-        let patch = frontend_integration_status_patch(&status, "default", "demo")?;
+        patch_fi_status(&api, &live, disabled).await.unwrap();
+
+        let status = live_object.lock().unwrap().0["status"].clone();
+        assert_eq!(status["phase"], "Pending");
+        assert_eq!(status["message"], DISABLED_MESSAGE);
+        assert!(status.get("bundle_ref").is_none(), "{status}");
+        assert!(status.get("url").is_none(), "{status}");
+        assert_eq!(status["conditions"][0]["type"], "WebhookReady");
+    }
 
-        assert_eq!(patch["status"]["last_build"], serde_json::Value::Null);
-        assert_eq!(patch["status"]["bundle_ref"], serde_json::Value::Null);
-        assert_eq!(patch["status"]["last_error"], serde_json::Value::Null);
-        Ok(())
+    #[test]
+    fn bundle_ready_condition_gates_success_and_url() {
+        let mut bundle = bundle_for_hash("fi-demo", "sha256:abc");
+        assert_eq!(bundle_not_ready_reason(&bundle), None);
+        assert_eq!(bundle_url(&bundle), None);
+
+        bundle.status = Some(
+            serde_json::from_value(json!({
+                "state": "Available",
+                "link": "/dist/fi-demo/index.js",
+                "conditions": [{
+                    "type": "Ready",
+                    "status": "False",
+                    "reason": "Rejected",
+                    "message": "bundle failed to load",
+                    "lastTransitionTime": "2024-01-01T00:00:00Z"
+                }]
+            }))
+            .unwrap(),
+        );
+        assert_eq!(
+            bundle_not_ready_reason(&bundle).as_deref(),
+            Some("bundle failed to load")
+        );
+
+        let status = bundle.status.as_mut().unwrap();
+        status.conditions[0].status = "True".to_string();
+        assert_eq!(bundle_not_ready_reason(&bundle), None);
+        assert_eq!(
+            bundle_url(&bundle).as_deref(),
+            Some("/dist/fi-demo/index.js")
+        );
+
+        bundle.status.as_mut().unwrap().state = Some(JSBUNDLE_STATE_DISABLED.to_string());
+        assert_eq!(bundle_url(&bundle), None);
     }
 }
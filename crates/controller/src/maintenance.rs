@@ -0,0 +1,131 @@
+use chrono::{DateTime, Timelike, Utc};
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+const SECONDS_PER_DAY: u32 = 24 * 60 * 60;
+
+// Daily UTC window written as "HH:MM-HH:MM"; an end before the start wraps past midnight.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct MaintenanceWindow {
+    start: u32,
+    end: u32,
+}
+
+impl MaintenanceWindow {
+    pub(crate) fn remaining(&self, now: DateTime<Utc>) -> Option<Duration> {
+        let now = now.num_seconds_from_midnight();
+        let inside = if self.start <= self.end {
+            self.start <= now && now < self.end
+        } else {
+            now >= self.start || now < self.end
+        };
+        if !inside {
+            return None;
+        }
+
+        let remaining = (self.end + SECONDS_PER_DAY - now) % SECONDS_PER_DAY;
+        Some(Duration::from_secs(u64::from(remaining)))
+    }
+}
+
+impl FromStr for MaintenanceWindow {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (start, end) = value
+            .split_once('-')
+            .ok_or_else(|| "expected HH:MM-HH:MM".to_string())?;
+        let start = parse_time_of_day(start)?;
+        let end = parse_time_of_day(end)?;
+        if start == end {
+            return Err("start and end must differ".to_string());
+        }
+        Ok(Self { start, end })
+    }
+}
+
+impl fmt::Display for MaintenanceWindow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hhmm = |seconds: u32| format!("{:02}:{:02}", seconds / 3600, seconds % 3600 / 60);
+        write!(f, "{}-{}", hhmm(self.start), hhmm(self.end))
+    }
+}
+
+fn parse_time_of_day(value: &str) -> Result<u32, String> {
+    let value = value.trim();
+    let (hours, minutes) = value
+        .split_once(':')
+        .ok_or_else(|| format!("invalid time '{value}', expected HH:MM"))?;
+    let hours = hours
+        .parse::<u32>()
+        .ok()
+        .filter(|hours| *hours < 24)
+        .ok_or_else(|| format!("invalid hour in '{value}'"))?;
+    let minutes = minutes
+        .parse::<u32>()
+        .ok()
+        .filter(|minutes| *minutes < 60)
+        .ok_or_else(|| format!("invalid minute in '{value}'"))?;
+    Ok(hours * 3600 + minutes * 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn parses_window_and_rejects_invalid_values() {
+        assert_eq!(
+            "01:30-03:00".parse::<MaintenanceWindow>(),
+            Ok(MaintenanceWindow {
+                start: 5400,
+                end: 10800
+            })
+        );
+        assert!("01:30".parse::<MaintenanceWindow>().is_err());
+        assert!("24:00-01:00".parse::<MaintenanceWindow>().is_err());
+        assert!("01:60-02:00".parse::<MaintenanceWindow>().is_err());
+        assert!("02:00-02:00".parse::<MaintenanceWindow>().is_err());
+        assert_eq!(
+            " 22:05 - 01:00 "
+                .trim()
+                .parse::<MaintenanceWindow>()
+                .unwrap()
+                .to_string(),
+            "22:05-01:00"
+        );
+    }
+
+    #[test]
+    fn remaining_covers_same_day_window() {
+        let window: MaintenanceWindow = "01:00-03:00".parse().unwrap();
+
+        assert_eq!(window.remaining(at(0, 59)), None);
+        assert_eq!(
+            window.remaining(at(2, 30)),
+            Some(Duration::from_secs(30 * 60))
+        );
+        assert_eq!(window.remaining(at(3, 0)), None);
+    }
+
+    #[test]
+    fn remaining_covers_window_wrapping_midnight() {
+        let window: MaintenanceWindow = "23:00-01:00".parse().unwrap();
+
+        assert_eq!(
+            window.remaining(at(23, 30)),
+            Some(Duration::from_secs(90 * 60))
+        );
+        assert_eq!(
+            window.remaining(at(0, 30)),
+            Some(Duration::from_secs(30 * 60))
+        );
+        assert_eq!(window.remaining(at(12, 0)), None);
+    }
+}
@@ -0,0 +1,274 @@
+use axum::{Router, http::header, response::IntoResponse, routing::get};
+use chrono::{DateTime, Utc};
+use frontend_forge_api::{
+    FrontendIntegration, FrontendIntegrationPhase, FrontendIntegrationStatus,
+};
+use snafu::ResultExt;
+use std::{
+    collections::BTreeMap,
+    env,
+    fmt::Write,
+    net::SocketAddr,
+    str::FromStr,
+    sync::{LazyLock, Mutex},
+};
+use tracing::info;
+
+use crate::{Error, InvalidMetricsBindAddrSnafu, MetricsServerSnafu, webhook::shutdown_signal};
+
+const DEFAULT_METRICS_BIND_ADDR: &str = "0.0.0.0:8080";
+const TIME_TO_READY_BUCKETS: &[f64] = &[
+    5.0, 10.0, 30.0, 60.0, 120.0, 300.0, 600.0, 1200.0, 1800.0, 3600.0,
+];
+
+static METRICS: LazyLock<Metrics> = LazyLock::new(Metrics::default);
+
+pub(crate) fn metrics() -> &'static Metrics {
+    &METRICS
+}
+
+pub(crate) struct Metrics {
+    time_to_ready: Histogram,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self {
+            time_to_ready: Histogram::new(
+                "frontend_forge_time_to_ready_seconds",
+                "Seconds from build start until a FrontendIntegration reaches Succeeded.",
+                "integration_type",
+                TIME_TO_READY_BUCKETS,
+            ),
+        }
+    }
+}
+
+impl Metrics {
+    // Only the transition into Succeeded counts, so repeated Succeeded patches are not observed.
+    pub(crate) fn observe_status_transition(
+        &self,
+        fi: &FrontendIntegration,
+        next: &FrontendIntegrationStatus,
+        now: DateTime<Utc>,
+    ) {
+        if next.phase != FrontendIntegrationPhase::Succeeded
+            || fi.status.as_ref().map(|status| &status.phase)
+                == Some(&FrontendIntegrationPhase::Succeeded)
+        {
+            return;
+        }
+        let Some(started_at) = next.last_build.as_ref().and_then(|build| build.started_at) else {
+            return;
+        };
+        let seconds = (now - started_at).num_milliseconds().max(0) as f64 / 1000.0;
+        self.time_to_ready.observe(&integration_type(fi), seconds);
+    }
+
+    pub(crate) fn render(&self) -> String {
+        let mut out = String::new();
+        self.time_to_ready.render(&mut out);
+        out
+    }
+}
+
+// FIs mixing page types are reported as `mixed` to keep label cardinality fixed.
+fn integration_type(fi: &FrontendIntegration) -> String {
+    let mut types = fi
+        .spec
+        .pages
+        .iter()
+        .map(|page| page.type_.as_str())
+        .collect::<Vec<_>>();
+    types.sort_unstable();
+    types.dedup();
+    match types.as_slice() {
+        [] => "none".to_string(),
+        [only] => only.to_string(),
+        _ => "mixed".to_string(),
+    }
+}
+
+struct Histogram {
+    name: &'static str,
+    help: &'static str,
+    label: &'static str,
+    buckets: &'static [f64],
+    series: Mutex<BTreeMap<String, HistogramSeries>>,
+}
+
+#[derive(Default)]
+struct HistogramSeries {
+    bucket_counts: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn new(
+        name: &'static str,
+        help: &'static str,
+        label: &'static str,
+        buckets: &'static [f64],
+    ) -> Self {
+        Self {
+            name,
+            help,
+            label,
+            buckets,
+            series: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    fn observe(&self, label_value: &str, value: f64) {
+        let mut series = self.series.lock().expect("histogram lock poisoned");
+        let series = series
+            .entry(label_value.to_string())
+            .or_insert_with(|| HistogramSeries {
+                bucket_counts: vec![0; self.buckets.len()],
+                ..Default::default()
+            });
+        for (bound, count) in self.buckets.iter().zip(series.bucket_counts.iter_mut()) {
+            if value <= *bound {
+                *count += 1;
+            }
+        }
+        series.sum += value;
+        series.count += 1;
+    }
+
+    fn render(&self, out: &mut String) {
+        let (name, label) = (self.name, self.label);
+        let _ = writeln!(out, "# HELP {name} {}", self.help);
+        let _ = writeln!(out, "# TYPE {name} histogram");
+        let series = self.series.lock().expect("histogram lock poisoned");
+        for (value, series) in series.iter() {
+            for (bound, count) in self.buckets.iter().zip(&series.bucket_counts) {
+                let _ = writeln!(
+                    out,
+                    "{name}_bucket{{{label}=\"{value}\",le=\"{bound}\"}} {count}"
+                );
+            }
+            let _ = writeln!(
+                out,
+                "{name}_bucket{{{label}=\"{value}\",le=\"+Inf\"}} {}",
+                series.count
+            );
+            let _ = writeln!(out, "{name}_sum{{{label}=\"{value}\"}} {}", series.sum);
+            let _ = writeln!(out, "{name}_count{{{label}=\"{value}\"}} {}", series.count);
+        }
+    }
+}
+
+// An empty METRICS_BIND_ADDR disables the endpoint.
+pub(crate) fn metrics_bind_addr_from_env() -> Result<Option<SocketAddr>, Error> {
+    let raw =
+        env::var("METRICS_BIND_ADDR").unwrap_or_else(|_| DEFAULT_METRICS_BIND_ADDR.to_string());
+    if raw.trim().is_empty() {
+        return Ok(None);
+    }
+    SocketAddr::from_str(raw.trim())
+        .map(Some)
+        .with_context(|_| InvalidMetricsBindAddrSnafu { value: raw.clone() })
+}
+
+pub(crate) async fn run_metrics_server(bind_addr: SocketAddr) -> Result<(), Error> {
+    let app = Router::new().route("/metrics", get(serve_metrics));
+    let listener = tokio::net::TcpListener::bind(bind_addr)
+        .await
+        .with_context(|_| MetricsServerSnafu { bind_addr })?;
+
+    info!(%bind_addr, "metrics server listening");
+    axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown_signal())
+        .await
+        .with_context(|_| MetricsServerSnafu { bind_addr })
+}
+
+async fn serve_metrics() -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        metrics().render(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use frontend_forge_api::LastBuildStatus;
+
+    fn iframe_fi(phase: FrontendIntegrationPhase) -> FrontendIntegration {
+        let mut fi: FrontendIntegration = serde_yaml::from_str(
+            r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: demo
+spec:
+  menus:
+    - displayName: Overview
+      key: overview
+      placement: cluster
+      type: page
+  pages:
+    - key: overview
+      type: iframe
+      iframe:
+        src: http://example.test
+"#,
+        )
+        .unwrap();
+        fi.status = Some(FrontendIntegrationStatus {
+            phase,
+            ..Default::default()
+        });
+        fi
+    }
+
+    #[test]
+    fn observes_time_to_ready_on_the_transition_to_succeeded() {
+        let metrics = Metrics::default();
+        let started_at = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let now = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 45).unwrap();
+        let succeeded = FrontendIntegrationStatus {
+            phase: FrontendIntegrationPhase::Succeeded,
+            last_build: Some(LastBuildStatus {
+                job_ref: None,
+                started_at: Some(started_at),
+            }),
+            ..Default::default()
+        };
+
+        metrics.observe_status_transition(
+            &iframe_fi(FrontendIntegrationPhase::Building),
+            &succeeded,
+            now,
+        );
+        // Re-patching an already Succeeded FI must not count twice.
+        metrics.observe_status_transition(
+            &iframe_fi(FrontendIntegrationPhase::Succeeded),
+            &succeeded,
+            now,
+        );
+
+        let rendered = metrics.render();
+        assert!(rendered.contains("# TYPE frontend_forge_time_to_ready_seconds histogram"));
+        assert!(rendered.contains(
+            "frontend_forge_time_to_ready_seconds_bucket{integration_type=\"iframe\",le=\"30\"} 0"
+        ));
+        assert!(rendered.contains(
+            "frontend_forge_time_to_ready_seconds_bucket{integration_type=\"iframe\",le=\"60\"} 1"
+        ));
+        assert!(
+            rendered.contains(
+                "frontend_forge_time_to_ready_seconds_sum{integration_type=\"iframe\"} 45"
+            )
+        );
+        assert!(
+            rendered.contains(
+                "frontend_forge_time_to_ready_seconds_count{integration_type=\"iframe\"} 1"
+            )
+        );
+    }
+}
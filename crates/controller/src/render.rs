@@ -0,0 +1,98 @@
+use frontend_forge_api::FrontendIntegration;
+use frontend_forge_common::{canonical_json_string, manifest_content_and_hash};
+use frontend_forge_manifest::render_extension_manifest;
+use serde_json::json;
+use snafu::ResultExt;
+use std::io::{Read, Write};
+
+use crate::{
+    CommonSnafu, Error, ParseRenderInputSnafu, ReadRenderInputSnafu, RenderManifestSnafu,
+    WriteRenderOutputSnafu,
+};
+
+pub(crate) const RENDER_MANIFEST_COMMAND: &str = "render-manifest";
+
+// Shares the runner render path so pipelines can validate an FI without a cluster.
+pub(crate) fn render_manifest(mut input: impl Read, mut output: impl Write) -> Result<(), Error> {
+    let mut raw = String::new();
+    input
+        .read_to_string(&mut raw)
+        .context(ReadRenderInputSnafu)?;
+    let fi: FrontendIntegration = serde_yaml::from_str(&raw).context(ParseRenderInputSnafu)?;
+    let manifest = render_extension_manifest(&fi).context(RenderManifestSnafu)?;
+    let (_, manifest_hash) = manifest_content_and_hash(&manifest).context(CommonSnafu)?;
+    let document = canonical_json_string(&json!({
+        "manifest": manifest,
+        "manifestHash": manifest_hash,
+    }))
+    .context(CommonSnafu)?;
+
+    writeln!(output, "{document}").context(WriteRenderOutputSnafu)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    const FI_YAML: &str = r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: demo
+spec:
+  menus:
+    - displayName: Demo
+      key: demo
+      placement: global
+      type: page
+  pages:
+    - key: demo
+      type: iframe
+      iframe:
+        src: http://example.test
+"#;
+
+    #[test]
+    fn renders_canonical_manifest_and_hash() {
+        let mut output = Vec::new();
+        render_manifest(FI_YAML.as_bytes(), &mut output).unwrap();
+
+        let fi: FrontendIntegration = serde_yaml::from_str(FI_YAML).unwrap();
+        let (expected_content, expected_hash) =
+            manifest_content_and_hash(&render_extension_manifest(&fi).unwrap()).unwrap();
+        let document: Value = serde_json::from_slice(&output).unwrap();
+
+        assert_eq!(document["manifestHash"], expected_hash);
+        assert_eq!(
+            canonical_json_string(&document["manifest"]).unwrap(),
+            expected_content
+        );
+        assert!(output.ends_with(b"\n"));
+    }
+
+    #[test]
+    fn accepts_json_input() {
+        let fi: Value = serde_yaml::from_str(FI_YAML).unwrap();
+        let mut output = Vec::new();
+        render_manifest(fi.to_string().as_bytes(), &mut output).unwrap();
+
+        let document: Value = serde_json::from_slice(&output).unwrap();
+        assert_eq!(document["manifest"]["name"], "demo");
+    }
+
+    #[test]
+    fn render_errors_are_reported() {
+        let invalid = FI_YAML.replace(
+            "key: demo\n      type: iframe",
+            "key: other\n      type: iframe",
+        );
+        let mut output = Vec::new();
+
+        assert!(matches!(
+            render_manifest(invalid.as_bytes(), &mut output),
+            Err(Error::RenderManifest { .. })
+        ));
+        assert!(output.is_empty());
+    }
+}
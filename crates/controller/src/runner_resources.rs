@@ -0,0 +1,179 @@
+use frontend_forge_api::BuilderResourcesSpec;
+use k8s_openapi::api::core::v1::ResourceRequirements;
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+use std::collections::BTreeMap;
+
+// FI entries win per resource name. The merged result is checked here because the API server
+// would otherwise reject the Job on every reconcile.
+pub(crate) fn merge_runner_resources(
+    defaults: &ResourceRequirements,
+    overrides: Option<&BuilderResourcesSpec>,
+) -> Result<Option<ResourceRequirements>, String> {
+    let mut requests = defaults.requests.clone().unwrap_or_default();
+    let mut limits = defaults.limits.clone().unwrap_or_default();
+    if let Some(overrides) = overrides {
+        for (target, entries) in [
+            (&mut requests, &overrides.requests),
+            (&mut limits, &overrides.limits),
+        ] {
+            for (name, value) in entries {
+                target.insert(name.trim().to_string(), Quantity(value.trim().to_string()));
+            }
+        }
+    }
+    validate_runner_resources(&requests, &limits)?;
+    if requests.is_empty() && limits.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(ResourceRequirements {
+        requests: Some(requests).filter(|requests| !requests.is_empty()),
+        limits: Some(limits).filter(|limits| !limits.is_empty()),
+        ..Default::default()
+    }))
+}
+
+pub(crate) fn validate_runner_resources(
+    requests: &BTreeMap<String, Quantity>,
+    limits: &BTreeMap<String, Quantity>,
+) -> Result<(), String> {
+    for (kind, entries) in [("requests", requests), ("limits", limits)] {
+        for (name, quantity) in entries {
+            if name.is_empty() {
+                return Err(format!("{kind} contains an empty resource name"));
+            }
+            if parse_quantity(&quantity.0).is_none() {
+                return Err(format!(
+                    "{kind}.{name} '{}' is not a valid quantity",
+                    quantity.0
+                ));
+            }
+        }
+    }
+    for (name, limit) in limits {
+        if let Some(request) = requests.get(name)
+            && parse_quantity(&limit.0) < parse_quantity(&request.0)
+        {
+            return Err(format!(
+                "limits.{name} '{}' is lower than requests.{name} '{}'",
+                limit.0, request.0
+            ));
+        }
+    }
+    Ok(())
+}
+
+// Non-negative decimal with an optional binary (Ki..Ei), decimal (m..E) or exponent suffix.
+fn parse_quantity(value: &str) -> Option<f64> {
+    let number_end = value
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '+'))
+        .unwrap_or(value.len());
+    let (number, suffix) = value.split_at(number_end);
+    let number = number.strip_prefix('+').unwrap_or(number);
+    if number.is_empty() || number == "." || number.contains('+') || number.matches('.').count() > 1
+    {
+        return None;
+    }
+    let base = number.parse::<f64>().ok()?;
+    let multiplier = match suffix {
+        "" => 1.0,
+        "Ki" => 1024f64,
+        "Mi" => 1024f64.powi(2),
+        "Gi" => 1024f64.powi(3),
+        "Ti" => 1024f64.powi(4),
+        "Pi" => 1024f64.powi(5),
+        "Ei" => 1024f64.powi(6),
+        "n" => 1e-9,
+        "u" => 1e-6,
+        "m" => 1e-3,
+        "k" => 1e3,
+        "M" => 1e6,
+        "G" => 1e9,
+        "T" => 1e12,
+        "P" => 1e15,
+        "E" => 1e18,
+        exponent => {
+            let exponent = exponent
+                .strip_prefix(['e', 'E'])?
+                .trim_start_matches('+')
+                .parse::<i32>()
+                .ok()?;
+            10f64.powi(exponent)
+        }
+    };
+    Some(base * multiplier)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quantities(pairs: &[(&str, &str)]) -> BTreeMap<String, Quantity> {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), Quantity(value.to_string())))
+            .collect()
+    }
+
+    #[test]
+    fn parses_kubernetes_quantities() {
+        assert_eq!(parse_quantity("500m"), Some(0.5));
+        assert_eq!(parse_quantity("2Gi"), Some(2.0 * 1024f64.powi(3)));
+        assert_eq!(parse_quantity("1.5"), Some(1.5));
+        assert_eq!(parse_quantity("1e3"), Some(1000.0));
+        for invalid in ["", "-1", "1.2.3", "12XB", "Gi", "1 Gi", "."] {
+            assert_eq!(parse_quantity(invalid), None, "{invalid}");
+        }
+    }
+
+    #[test]
+    fn fi_overrides_take_precedence_over_defaults() {
+        let defaults = ResourceRequirements {
+            requests: Some(quantities(&[("cpu", "100m"), ("memory", "256Mi")])),
+            limits: Some(quantities(&[("memory", "512Mi")])),
+            ..Default::default()
+        };
+        let overrides = BuilderResourcesSpec {
+            requests: BTreeMap::from([("memory".to_string(), "1Gi".to_string())]),
+            limits: BTreeMap::from([("memory".to_string(), "2Gi".to_string())]),
+        };
+
+        let merged = merge_runner_resources(&defaults, Some(&overrides))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            merged.requests,
+            Some(quantities(&[("cpu", "100m"), ("memory", "1Gi")]))
+        );
+        assert_eq!(merged.limits, Some(quantities(&[("memory", "2Gi")])));
+        assert_eq!(
+            merge_runner_resources(&ResourceRequirements::default(), None),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_or_inverted_quantities() {
+        let defaults = ResourceRequirements {
+            limits: Some(quantities(&[("memory", "512Mi")])),
+            ..Default::default()
+        };
+        let invalid = BuilderResourcesSpec {
+            requests: BTreeMap::from([("memory".to_string(), "lots".to_string())]),
+            ..Default::default()
+        };
+        assert_eq!(
+            merge_runner_resources(&defaults, Some(&invalid)),
+            Err("requests.memory 'lots' is not a valid quantity".to_string())
+        );
+
+        let inverted = BuilderResourcesSpec {
+            requests: BTreeMap::from([("memory".to_string(), "1Gi".to_string())]),
+            ..Default::default()
+        };
+        assert_eq!(
+            merge_runner_resources(&defaults, Some(&inverted)),
+            Err("limits.memory '512Mi' is lower than requests.memory '1Gi'".to_string())
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use k8s_openapi::api::core::v1::{
+    Capabilities, PodSecurityContext, SeccompProfile, SecurityContext,
+};
+use serde::Deserialize;
+
+pub(crate) const RUNNER_SECURITY_CONTEXT_ENV: &str = "RUNNER_SECURITY_CONTEXT_JSON";
+// Matches the `nonroot` user of the distroless runner image.
+const RUNNER_UID: i64 = 65532;
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct RunnerSecurityContext {
+    pub(crate) pod: PodSecurityContext,
+    pub(crate) container: SecurityContext,
+}
+
+// Either half may be omitted; a provided half replaces the matching default wholesale.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RunnerSecurityContextOverride {
+    #[serde(default)]
+    pod: Option<PodSecurityContext>,
+    #[serde(default)]
+    container: Option<SecurityContext>,
+}
+
+impl Default for RunnerSecurityContext {
+    // Satisfies the `restricted` Pod Security Standard.
+    fn default() -> Self {
+        Self {
+            pod: PodSecurityContext {
+                run_as_non_root: Some(true),
+                run_as_user: Some(RUNNER_UID),
+                run_as_group: Some(RUNNER_UID),
+                seccomp_profile: Some(runtime_default_seccomp()),
+                ..Default::default()
+            },
+            container: SecurityContext {
+                allow_privilege_escalation: Some(false),
+                run_as_non_root: Some(true),
+                capabilities: Some(Capabilities {
+                    drop: Some(vec!["ALL".to_string()]),
+                    ..Default::default()
+                }),
+                seccomp_profile: Some(runtime_default_seccomp()),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl RunnerSecurityContext {
+    pub(crate) fn from_json(value: Option<&str>) -> Result<Self, serde_json::Error> {
+        let mut context = Self::default();
+        let Some(value) = value.map(str::trim).filter(|value| !value.is_empty()) else {
+            return Ok(context);
+        };
+        let overrides: RunnerSecurityContextOverride = serde_json::from_str(value)?;
+        if let Some(pod) = overrides.pod {
+            context.pod = pod;
+        }
+        if let Some(container) = overrides.container {
+            context.container = container;
+        }
+        Ok(context)
+    }
+}
+
+fn runtime_default_seccomp() -> SeccompProfile {
+    SeccompProfile {
+        type_: "RuntimeDefault".to_string(),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_follow_the_restricted_profile() {
+        let context = RunnerSecurityContext::from_json(None).unwrap();
+
+        assert_eq!(context.pod.run_as_non_root, Some(true));
+        assert_eq!(
+            context.pod.seccomp_profile.map(|profile| profile.type_),
+            Some("RuntimeDefault".to_string())
+        );
+        assert_eq!(context.container.allow_privilege_escalation, Some(false));
+        assert_eq!(
+            context.container.capabilities.and_then(|caps| caps.drop),
+            Some(vec!["ALL".to_string()])
+        );
+    }
+
+    #[test]
+    fn json_overrides_replace_only_the_given_half() {
+        let context = RunnerSecurityContext::from_json(Some(
+            r#"{"container":{"readOnlyRootFilesystem":true,"allowPrivilegeEscalation":false}}"#,
+        ))
+        .unwrap();
+
+        assert_eq!(context.pod, RunnerSecurityContext::default().pod);
+        assert_eq!(context.container.read_only_root_filesystem, Some(true));
+        assert_eq!(context.container.capabilities, None);
+    }
+
+    #[test]
+    fn rejects_malformed_json() {
+        assert!(RunnerSecurityContext::from_json(Some("{not json")).is_err());
+        assert!(RunnerSecurityContext::from_json(Some(r#"{"pods":{}}"#)).is_err());
+        assert!(RunnerSecurityContext::from_json(Some(r#"{"pod":{"runAsUser":"root"}}"#)).is_err());
+    }
+}
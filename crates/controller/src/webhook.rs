@@ -1,23 +1,28 @@
 use axum::{
     Json, Router,
     body::Bytes,
+    extract::State,
     routing::{get, post},
 };
 use axum_server::{Handle, tls_rustls::RustlsConfig};
 use frontend_forge_api::FrontendIntegration;
-use frontend_forge_manifest::validate_frontend_integration;
+use frontend_forge_manifest::{
+    RenderOptions, ValidationSeverity, classify_frontend_integration_with_options,
+};
 use kube::Resource;
 use kube::core::{
     DynamicObject, Status,
     admission::{AdmissionRequest, AdmissionResponse, AdmissionReview, Operation},
 };
 use snafu::ResultExt;
-use std::{env, future::pending, net::SocketAddr, path::PathBuf, str::FromStr, time::Duration};
+use std::{
+    env, future::pending, net::SocketAddr, path::PathBuf, str::FromStr, sync::Arc, time::Duration,
+};
 use tracing::{info, warn};
 
 use crate::{
-    Error, InvalidWebhookBindAddrSnafu, InvalidWebhookEnabledSnafu, WebhookServerSnafu,
-    WebhookTlsConfigSnafu,
+    ContextData, Error, InvalidWebhookBindAddrSnafu, InvalidWebhookEnabledSnafu,
+    WebhookServerSnafu, WebhookTlsConfigSnafu,
 };
 
 const DEFAULT_WEBHOOK_BIND_ADDR: &str = "0.0.0.0:9443";
@@ -68,11 +73,14 @@ impl WebhookConfig {
     }
 }
 
-pub(crate) async fn run_webhook_server(config: WebhookConfig) -> Result<(), Error> {
+pub(crate) async fn run_webhook_server(
+    config: WebhookConfig,
+    ctx: Arc<ContextData>,
+) -> Result<(), Error> {
     let tls_config = load_tls_config(&config)
         .await?
         .expect("enabled webhook must load TLS config");
-    let app = router();
+    let app = router(ctx);
     let handle = Handle::new();
 
     tokio::spawn(shutdown_webhook_on_signal(handle.clone()));
@@ -94,7 +102,7 @@ async fn shutdown_webhook_on_signal(handle: Handle<SocketAddr>) {
     )));
 }
 
-async fn shutdown_signal() {
+pub(crate) async fn shutdown_signal() {
     let ctrl_c = async {
         let _ = tokio::signal::ctrl_c().await;
     };
@@ -135,24 +143,37 @@ async fn load_tls_config(config: &WebhookConfig) -> Result<Option<RustlsConfig>,
     ))
 }
 
-fn router() -> Router {
-    Router::new().route("/healthz", get(healthz)).route(
-        "/validate/frontendintegrations",
-        post(validate_frontend_integrations),
-    )
+fn router(ctx: Arc<ContextData>) -> Router {
+    Router::new()
+        .route("/healthz", get(healthz))
+        .route(
+            "/validate/frontendintegrations",
+            post(validate_frontend_integrations),
+        )
+        .with_state(ctx)
 }
 
 async fn healthz() -> &'static str {
     "ok"
 }
 
-async fn validate_frontend_integrations(body: Bytes) -> Json<AdmissionReview<DynamicObject>> {
-    Json(process_validation_request(body.as_ref()))
+// Validates with the same render options, FrontendForgeConfig and its namespace overrides
+// included, that the FI's build Jobs get.
+async fn validate_frontend_integrations(
+    State(ctx): State<Arc<ContextData>>,
+    body: Bytes,
+) -> Json<AdmissionReview<DynamicObject>> {
+    Json(process_validation_request(body.as_ref(), |fi| {
+        ctx.config.snapshot_for(fi).render_options
+    }))
 }
 
-fn process_validation_request(body: &[u8]) -> AdmissionReview<DynamicObject> {
+fn process_validation_request(
+    body: &[u8],
+    options_for: impl Fn(&FrontendIntegration) -> RenderOptions,
+) -> AdmissionReview<DynamicObject> {
     match serde_json::from_slice::<AdmissionReview<FrontendIntegration>>(body) {
-        Ok(review) => validate_review(review),
+        Ok(review) => validate_review(review, options_for),
         Err(err) => {
             AdmissionResponse::invalid(format!("failed to deserialize AdmissionReview: {err}"))
                 .into_review()
@@ -160,7 +181,10 @@ fn process_validation_request(body: &[u8]) -> AdmissionReview<DynamicObject> {
     }
 }
 
-fn validate_review(review: AdmissionReview<FrontendIntegration>) -> AdmissionReview<DynamicObject> {
+fn validate_review(
+    review: AdmissionReview<FrontendIntegration>,
+    options_for: impl Fn(&FrontendIntegration) -> RenderOptions,
+) -> AdmissionReview<DynamicObject> {
     let request: AdmissionRequest<FrontendIntegration> = match review.try_into() {
         Ok(request) => request,
         Err(_) => {
@@ -170,14 +194,17 @@ fn validate_review(review: AdmissionReview<FrontendIntegration>) -> AdmissionRev
     };
 
     let response = match request.operation {
-        Operation::Create | Operation::Update => validate_request_object(&request),
+        Operation::Create | Operation::Update => validate_request_object(&request, options_for),
         _ => AdmissionResponse::from(&request),
     };
 
     response.into_review()
 }
 
-fn validate_request_object(request: &AdmissionRequest<FrontendIntegration>) -> AdmissionResponse {
+fn validate_request_object(
+    request: &AdmissionRequest<FrontendIntegration>,
+    options_for: impl Fn(&FrontendIntegration) -> RenderOptions,
+) -> AdmissionResponse {
     let Some(fi) = request.object.as_ref() else {
         warn!(uid = %request.uid, operation = ?request.operation, "admission request missing object");
         return invalid_response(
@@ -189,28 +216,29 @@ fn validate_request_object(request: &AdmissionRequest<FrontendIntegration>) -> A
         );
     };
 
-    match validate_frontend_integration(fi) {
-        Ok(()) => {
-            info!(
-                uid = %request.uid,
-                operation = ?request.operation,
-                name = fi.metadata.name.as_deref().unwrap_or("<unknown>"),
-                allowed = true,
-                "admission request processed"
-            );
-            AdmissionResponse::from(request)
-        }
-        Err(err) => {
-            info!(
-                uid = %request.uid,
-                operation = ?request.operation,
-                name = fi.metadata.name.as_deref().unwrap_or("<unknown>"),
-                allowed = false,
-                reason = %err,
-                "admission request processed"
-            );
-            AdmissionResponse::from(request).deny(err.to_string())
-        }
+    let (errors, warnings): (Vec<_>, Vec<_>) =
+        classify_frontend_integration_with_options(fi, &options_for(fi))
+            .into_iter()
+            .partition(|issue| issue.severity == ValidationSeverity::Error);
+    let warnings: Vec<String> = warnings.into_iter().map(|issue| issue.message).collect();
+    let denied = errors.into_iter().next().map(|issue| issue.message);
+    info!(
+        uid = %request.uid,
+        operation = ?request.operation,
+        name = fi.metadata.name.as_deref().unwrap_or("<unknown>"),
+        allowed = denied.is_none(),
+        reason = denied.as_deref().unwrap_or_default(),
+        warnings = warnings.len(),
+        "admission request processed"
+    );
+
+    let mut response = AdmissionResponse::from(request);
+    if !warnings.is_empty() {
+        response.warnings = Some(warnings);
+    }
+    match denied {
+        Some(message) => response.deny(message),
+        None => response,
     }
 }
 
@@ -243,15 +271,26 @@ mod tests {
             },
             spec: FrontendIntegrationSpec {
                 display_name: None,
+                description: None,
+                metadata: None,
                 locales: Default::default(),
                 enabled: Some(true),
+                paused: None,
+                force_rebuild_token: None,
+                bundle_name: None,
+                bundle_config_map_namespace: None,
+                propagate_labels: None,
+                propagate_annotations: None,
                 menus: vec![PrimaryMenuSpec {
                     display_name: "demo".to_string(),
                     key: "demo".to_string(),
                     icon: None,
+                    order: None,
+                    parent: None,
                     placement: MenuPlacement::Global,
                     type_: MenuNodeType::Page,
                     children: vec![],
+                    aliases: vec![],
                 }],
                 pages: vec![PageSpec {
                     key: "demo".to_string(),
@@ -259,7 +298,13 @@ mod tests {
                     crd_table: None,
                     iframe: Some(IframePageSpec {
                         src: "http://example.test".to_string(),
+                        sandbox: None,
+                        allow: None,
+                        height: None,
+                        loading: None,
                     }),
+                    custom: None,
+                    permissions: vec![],
                 }],
                 builder: None,
             },
@@ -296,7 +341,7 @@ mod tests {
     }
 
     fn response_for(body: &[u8]) -> AdmissionResponse {
-        process_validation_request(body)
+        process_validation_request(body, |_| RenderOptions::default())
             .response
             .expect("admission response")
     }
@@ -313,6 +358,28 @@ mod tests {
         assert_eq!(response.uid, "req-1");
     }
 
+    #[test]
+    fn validates_with_the_supplied_render_options() {
+        let body = review_bytes(
+            "CREATE",
+            Some(serde_json::to_value(frontend_integration("demo")).unwrap()),
+        );
+        let options = RenderOptions {
+            iframe_allowed_hosts: vec!["grafana.example".to_string()],
+            ..RenderOptions::default()
+        };
+        let response = process_validation_request(&body, |_| options.clone())
+            .response
+            .expect("admission response");
+
+        assert!(!response.allowed);
+        assert!(
+            response.result.message.contains("example.test"),
+            "{}",
+            response.result.message
+        );
+    }
+
     #[test]
     fn update_request_denies_duplicate_page_key() {
         let mut fi = frontend_integration("demo");
@@ -322,7 +389,13 @@ mod tests {
             crd_table: None,
             iframe: Some(IframePageSpec {
                 src: "http://example.test/other".to_string(),
+                sandbox: None,
+                allow: None,
+                height: None,
+                loading: None,
             }),
+            custom: None,
+            permissions: vec![],
         });
 
         let response = response_for(&review_bytes(
@@ -337,6 +410,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn soft_issues_warn_while_hard_issues_reject() {
+        let fi = frontend_integration("demo");
+        let response = response_for(&review_bytes(
+            "CREATE",
+            Some(serde_json::to_value(&fi).unwrap()),
+        ));
+        assert!(response.allowed);
+        assert_eq!(
+            response.warnings,
+            Some(vec![
+                "FrontendIntegration demo only uses global placement; pages will not be scoped to a workspace or cluster"
+                    .to_string()
+            ])
+        );
+
+        let mut fi = fi;
+        fi.spec.menus[0].placement = MenuPlacement::Cluster;
+        fi.spec.pages[0].iframe = Some(IframePageSpec {
+            src: "javascript:alert(1)".to_string(),
+            sandbox: None,
+            allow: None,
+            height: None,
+            loading: None,
+        });
+        let response = response_for(&review_bytes(
+            "CREATE",
+            Some(serde_json::to_value(&fi).unwrap()),
+        ));
+        assert!(!response.allowed);
+        assert_eq!(response.warnings, None);
+        assert!(response.result.message.contains("invalid iframe src"));
+    }
+
     #[test]
     fn create_request_without_object_is_invalid() {
         let response = response_for(&review_bytes("CREATE", None));
@@ -1,9 +1,14 @@
+mod selector;
 mod v1;
+mod v2;
 
-use frontend_forge_api::FrontendIntegration;
+use frontend_forge_api::{
+    FrontendIntegration, MenuPlacement, RoutingPathError, SpecValidationError,
+};
 use kube::ResourceExt;
 use serde_json::Value;
 use snafu::Snafu;
+use std::env;
 
 #[derive(Debug, Snafu)]
 pub enum ManifestRenderError {
@@ -49,14 +54,247 @@ pub enum ManifestRenderError {
         key: String,
         message: String,
     },
+    #[snafu(display(
+        "FrontendIntegration {} page '{}' has type={} but also defines {} config",
+        fi_name,
+        key,
+        page_type,
+        conflicting
+    ))]
+    ConflictingPageConfig {
+        fi_name: String,
+        key: String,
+        page_type: String,
+        conflicting: String,
+    },
+    #[snafu(display(
+        "FrontendIntegration {} builder.runnerImage '{}' is not a valid image reference: {}",
+        fi_name,
+        image,
+        message
+    ))]
+    InvalidRunnerImage {
+        fi_name: String,
+        image: String,
+        message: String,
+    },
+    #[snafu(display(
+        "FrontendIntegration {} {} '{}' is invalid: {}",
+        fi_name,
+        field,
+        value,
+        message
+    ))]
+    InvalidBundleTarget {
+        fi_name: String,
+        field: String,
+        value: String,
+        message: String,
+    },
+    #[snafu(display(
+        "FrontendIntegration {} {} entry '{}' is invalid: {}",
+        fi_name,
+        field,
+        key,
+        message
+    ))]
+    InvalidPropagatedMetadata {
+        fi_name: String,
+        field: String,
+        key: String,
+        message: String,
+    },
+    #[snafu(display(
+        "FrontendIntegration {} menu '{}' title is {} characters, exceeding the limit of {}",
+        fi_name,
+        key,
+        length,
+        max
+    ))]
+    MenuTitleTooLong {
+        fi_name: String,
+        key: String,
+        length: usize,
+        max: usize,
+    },
+    #[snafu(display(
+        "FrontendIntegration {} page '{}' has invalid iframe src '{}': {}",
+        fi_name,
+        key,
+        src,
+        message
+    ))]
+    InvalidIframeSrc {
+        fi_name: String,
+        key: String,
+        src: String,
+        message: String,
+    },
+    #[snafu(display(
+        "FrontendIntegration {} page '{}' column '{}' renders {} but path '{}' is not a map field",
+        fi_name,
+        key,
+        column,
+        render_type,
+        path
+    ))]
+    InvalidMapColumnPath {
+        fi_name: String,
+        key: String,
+        column: String,
+        render_type: String,
+        path: String,
+    },
+    #[snafu(display(
+        "FrontendIntegration {} page '{}' column '{}' is invalid: {}",
+        fi_name,
+        key,
+        column,
+        message
+    ))]
+    InvalidColumn {
+        fi_name: String,
+        key: String,
+        column: String,
+        message: String,
+    },
+    #[snafu(display(
+        "FrontendIntegration {} page '{}' declares column '{}' more than once",
+        fi_name,
+        key,
+        column
+    ))]
+    DuplicateColumnKey {
+        fi_name: String,
+        key: String,
+        column: String,
+    },
+    #[snafu(display(
+        "FrontendIntegration {} page '{}' filter '{}' is invalid: {}",
+        fi_name,
+        key,
+        filter,
+        message
+    ))]
+    InvalidFilter {
+        fi_name: String,
+        key: String,
+        filter: String,
+        message: String,
+    },
+    #[snafu(display(
+        "FrontendIntegration {} page '{}' column '{}' has invalid link template '{}': {}",
+        fi_name,
+        key,
+        column,
+        template,
+        message
+    ))]
+    InvalidLinkTemplate {
+        fi_name: String,
+        key: String,
+        column: String,
+        template: String,
+        message: String,
+    },
+    #[snafu(display(
+        "FrontendIntegration {} page '{}' table.defaultSortKey '{}' {}",
+        fi_name,
+        key,
+        column,
+        message
+    ))]
+    InvalidDefaultSort {
+        fi_name: String,
+        key: String,
+        column: String,
+        message: String,
+    },
+    #[snafu(display(
+        "FrontendIntegration {} page '{}' has invalid {} '{}': {}",
+        fi_name,
+        key,
+        field,
+        selector,
+        message
+    ))]
+    InvalidSelector {
+        fi_name: String,
+        key: String,
+        field: String,
+        selector: String,
+        message: String,
+    },
+    #[snafu(display(
+        "FrontendIntegration {} references locale keys missing from every spec.locales entry: {}",
+        fi_name,
+        keys.join(", ")
+    ))]
+    MissingLocaleKeys { fi_name: String, keys: Vec<String> },
+    #[snafu(display(
+        "FrontendIntegration {} page '{}' is placed in a project but lists a Cluster-scoped CRD; project pages only list namespaced resources",
+        fi_name,
+        key
+    ))]
+    ClusterScopedCrdInProject { fi_name: String, key: String },
+    #[snafu(display(
+        "FrontendIntegration {} page '{}' is placed in a workspace but lists a Cluster-scoped CRD; workspace pages only list namespaced resources",
+        fi_name,
+        key
+    ))]
+    ClusterScopedCrdInWorkspace { fi_name: String, key: String },
+    #[snafu(display(
+        "FrontendIntegration {} page '{}' is placed in global but lists a Namespaced CRD without a namespace; add 'metadata.namespace=<ns>' to crdTable.fieldSelector",
+        fi_name,
+        key
+    ))]
+    NamespacedCrdWithoutNamespace { fi_name: String, key: String },
+    #[snafu(display(
+        "FrontendIntegration {} menu '{}' has invalid routing path '{}': {}",
+        fi_name,
+        key,
+        path,
+        source
+    ))]
+    InvalidRoutingPath {
+        fi_name: String,
+        key: String,
+        path: String,
+        source: RoutingPathError,
+    },
+    #[snafu(display(
+        "FrontendIntegration {} menu '{}' route '{}' duplicates another route in the same placement",
+        fi_name,
+        key,
+        path
+    ))]
+    DuplicateRoutePath {
+        fi_name: String,
+        key: String,
+        path: String,
+    },
     #[snafu(display("FrontendIntegration {} has invalid menu key '{}'", fi_name, key))]
     InvalidMenuKey { fi_name: String, key: String },
     #[snafu(display(
-        "FrontendIntegration {} requires columns for CRD page '{}'",
+        "FrontendIntegration {} requires columns for CRD page '{}'{}",
         fi_name,
-        key
+        key,
+        placement
+            .map(|placement| format!(" in placement {}", placement.as_str()))
+            .unwrap_or_default()
     ))]
-    MissingCrdColumns { fi_name: String, key: String },
+    MissingCrdColumns {
+        fi_name: String,
+        key: String,
+        // Set when columns exist but none are shown in this placement.
+        placement: Option<MenuPlacement>,
+    },
+    #[snafu(display(
+        "FrontendIntegration {} has invalid builder.output: {}",
+        fi_name,
+        message
+    ))]
+    InvalidBuildOutput { fi_name: String, message: String },
     #[snafu(display(
         "FrontendIntegration {} requested unsupported builder.engineVersion '{}'",
         fi_name,
@@ -68,8 +306,170 @@ pub enum ManifestRenderError {
     },
 }
 
-// Rendering remains versioned so runner and webhook share the same validation semantics.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MenuTitleOverflow {
+    #[default]
+    Truncate,
+    Reject,
+}
+
+impl MenuTitleOverflow {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Truncate => "truncate",
+            Self::Reject => "reject",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "truncate" => Some(Self::Truncate),
+            "reject" | "error" => Some(Self::Reject),
+            _ => None,
+        }
+    }
+}
+
+pub const DEFAULT_PAGE_ID_NAMESPACE: &str = "frontend-forge";
+
+// How route/page ids are derived; every engine version picks its own default.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PageIdStrategy {
+    FiPlacement,
+    NamespaceFiPlacement,
+    HashBased,
+}
+
+impl PageIdStrategy {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::FiPlacement => "fi-placement",
+            Self::NamespaceFiPlacement => "namespace-fi-placement",
+            Self::HashBased => "hash",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "fi-placement" => Some(Self::FiPlacement),
+            "namespace-fi-placement" => Some(Self::NamespaceFiPlacement),
+            "hash" | "hash-based" => Some(Self::HashBased),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RenderOptions {
+    pub default_menu_icon: Option<String>,
+    // None leaves titles unlimited, so existing FIs keep their manifest hash on upgrade.
+    pub menu_title_max_chars: Option<usize>,
+    pub menu_title_overflow: MenuTitleOverflow,
+    // Empty allows any host; entries are exact hosts or `*.domain` wildcards.
+    pub iframe_allowed_hosts: Vec<String>,
+    // None keeps the engine version's default strategy.
+    pub page_id_strategy: Option<PageIdStrategy>,
+    pub page_id_namespace: String,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            default_menu_icon: None,
+            menu_title_max_chars: None,
+            menu_title_overflow: MenuTitleOverflow::default(),
+            iframe_allowed_hosts: Vec::new(),
+            page_id_strategy: None,
+            page_id_namespace: DEFAULT_PAGE_ID_NAMESPACE.to_string(),
+        }
+    }
+}
+
+impl RenderOptions {
+    pub fn from_env() -> Self {
+        Self {
+            default_menu_icon: env::var("DEFAULT_MENU_ICON")
+                .ok()
+                .map(|icon| icon.trim().to_string())
+                .filter(|icon| !icon.is_empty()),
+            menu_title_max_chars: env::var("MENU_TITLE_MAX_CHARS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|max| *max > 0),
+            menu_title_overflow: env::var("MENU_TITLE_OVERFLOW")
+                .ok()
+                .and_then(|v| MenuTitleOverflow::parse(&v))
+                .unwrap_or_default(),
+            iframe_allowed_hosts: env::var("IFRAME_ALLOWED_HOSTS")
+                .map(|hosts| {
+                    hosts
+                        .split(',')
+                        .map(|host| host.trim().to_ascii_lowercase())
+                        .filter(|host| !host.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            page_id_strategy: env::var("PAGE_ID_STRATEGY")
+                .ok()
+                .and_then(|v| PageIdStrategy::parse(&v)),
+            page_id_namespace: env::var("PAGE_ID_NAMESPACE")
+                .ok()
+                .map(|namespace| namespace.trim().to_string())
+                .filter(|namespace| !namespace.is_empty())
+                .unwrap_or_else(|| DEFAULT_PAGE_ID_NAMESPACE.to_string()),
+        }
+    }
+}
+
 pub fn render_extension_manifest(fi: &FrontendIntegration) -> Result<Value, ManifestRenderError> {
+    render_extension_manifest_with_options(fi, &RenderOptions::from_env())
+}
+
+// Rendering remains versioned so runner and webhook share the same validation semantics.
+pub fn render_extension_manifest_with_options(
+    fi: &FrontendIntegration,
+    options: &RenderOptions,
+) -> Result<Value, ManifestRenderError> {
+    fi.spec.validate().map_err(|err| match err {
+        SpecValidationError::ConflictingPageConfig {
+            key,
+            page_type,
+            conflicting,
+        } => ManifestRenderError::ConflictingPageConfig {
+            fi_name: fi.name_any(),
+            key,
+            page_type,
+            conflicting,
+        },
+        SpecValidationError::InvalidRunnerImage { image, message } => {
+            ManifestRenderError::InvalidRunnerImage {
+                fi_name: fi.name_any(),
+                image,
+                message,
+            }
+        }
+        SpecValidationError::InvalidBundleTarget {
+            field,
+            value,
+            message,
+        } => ManifestRenderError::InvalidBundleTarget {
+            fi_name: fi.name_any(),
+            field,
+            value,
+            message,
+        },
+        SpecValidationError::InvalidPropagatedMetadata {
+            field,
+            key,
+            message,
+        } => ManifestRenderError::InvalidPropagatedMetadata {
+            fi_name: fi.name_any(),
+            field,
+            key,
+            message,
+        },
+    })?;
+
     let requested = fi.spec.engine_version().unwrap_or("v1").trim();
     let normalized = if requested.is_empty() {
         "v1"
@@ -79,7 +479,8 @@ pub fn render_extension_manifest(fi: &FrontendIntegration) -> Result<Value, Mani
     .to_ascii_lowercase();
 
     match normalized.as_str() {
-        "v1" | "v1alpha1" | "1" | "1.0" => v1::render_v1_manifest(fi),
+        "v1" | "v1alpha1" | "1" | "1.0" => v1::render_v1_manifest(fi, options),
+        "v2" | "2" | "2.0" => v2::render_v2_manifest(fi, options),
         _ => Err(ManifestRenderError::UnsupportedEngineVersion {
             fi_name: fi.name_any(),
             engine_version: requested.to_string(),
@@ -91,10 +492,66 @@ pub fn validate_frontend_integration(fi: &FrontendIntegration) -> Result<(), Man
     render_extension_manifest(fi).map(|_| ())
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValidationSeverity {
+    Warning,
+    Error,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationIssue {
+    pub severity: ValidationSeverity,
+    pub message: String,
+}
+
+// Errors come from the shared render path; warnings flag specs that render but are likely mistakes.
+pub fn classify_frontend_integration(fi: &FrontendIntegration) -> Vec<ValidationIssue> {
+    classify_frontend_integration_with_options(fi, &RenderOptions::from_env())
+}
+
+pub fn classify_frontend_integration_with_options(
+    fi: &FrontendIntegration,
+    options: &RenderOptions,
+) -> Vec<ValidationIssue> {
+    let mut issues: Vec<ValidationIssue> = soft_issues(fi)
+        .into_iter()
+        .map(|message| ValidationIssue {
+            severity: ValidationSeverity::Warning,
+            message,
+        })
+        .collect();
+    if let Err(err) = render_extension_manifest_with_options(fi, options) {
+        issues.push(ValidationIssue {
+            severity: ValidationSeverity::Error,
+            message: err.to_string(),
+        });
+    }
+    issues
+}
+
+fn soft_issues(fi: &FrontendIntegration) -> Vec<String> {
+    let fi_name = fi.name_any();
+    let menus = &fi.spec.menus;
+    if menus.is_empty() {
+        return vec![format!(
+            "FrontendIntegration {fi_name} declares no menus, so nothing will appear in the console"
+        )];
+    }
+    let mut warnings = Vec::new();
+    if menus
+        .iter()
+        .all(|menu| menu.placement == MenuPlacement::Global)
+    {
+        warnings.push(format!(
+            "FrontendIntegration {fi_name} only uses global placement; pages will not be scoped to a workspace or cluster"
+        ));
+    }
+    warnings
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use serde_yaml;
 
     #[test]
     fn defaults_to_v1_renderer() {
@@ -154,6 +611,52 @@ spec:
         ));
     }
 
+    #[test]
+    fn dispatches_each_engine_version_alias() {
+        let render = |engine_version: &str| {
+            let mut fi: FrontendIntegration = serde_yaml::from_str(
+                r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: demo
+spec:
+  menus:
+    - displayName: Demo
+      key: demo
+      placement: cluster
+      type: page
+  pages:
+    - key: demo
+      type: iframe
+      iframe:
+        src: http://example.test
+"#,
+            )
+            .unwrap();
+            fi.spec.builder = Some(frontend_forge_api::BuilderSpec {
+                engine_version: Some(engine_version.to_string()),
+                ..Default::default()
+            });
+            render_extension_manifest_with_options(&fi, &RenderOptions::default()).unwrap()
+        };
+
+        let v1 = render("v1");
+        assert_eq!(v1["version"], "1.0");
+        assert_eq!(v1["build"]["systemjs"], true);
+        for alias in ["", "V1", "v1alpha1", "1", "1.0"] {
+            assert_eq!(render(alias), v1, "{alias}");
+        }
+
+        let v2 = render("v2");
+        assert_eq!(v2["version"], "2.0");
+        assert_eq!(v2["build"]["format"], "esm");
+        assert!(v2.get("menus").is_none());
+        for alias in [" 2 ", "2.0", "V2"] {
+            assert_eq!(render(alias), v2, "{alias}");
+        }
+    }
+
     #[test]
     fn validate_frontend_integration_reuses_render_path() {
         let fi: FrontendIntegration = serde_yaml::from_str(
@@ -180,6 +683,62 @@ spec:
         assert!(validate_frontend_integration(&fi).is_ok());
     }
 
+    #[test]
+    fn rejects_page_config_for_the_other_type() {
+        let fi: FrontendIntegration = serde_yaml::from_str(
+            r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: demo
+spec:
+  menus:
+    - displayName: Demo
+      key: demo
+      placement: global
+      type: page
+  pages:
+    - key: demo
+      type: crdTable
+      iframe:
+        src: http://example.test
+"#,
+        )
+        .unwrap();
+
+        let err = validate_frontend_integration(&fi).unwrap_err();
+        assert!(matches!(
+            err,
+            ManifestRenderError::ConflictingPageConfig { ref page_type, ref conflicting, .. }
+                if page_type == "crdTable" && conflicting == "iframe"
+        ));
+        assert_eq!(
+            err.to_string(),
+            "FrontendIntegration demo page 'demo' has type=crdTable but also defines iframe config"
+        );
+
+        let mut iframe_with_crd = fi.clone();
+        iframe_with_crd.spec.pages[0].type_ = frontend_forge_api::PageType::Iframe;
+        iframe_with_crd.spec.pages[0].crd_table = Some(
+            serde_yaml::from_str(
+                r#"
+names:
+  plural: inspecttasks
+group: kubeeye.kubesphere.io
+version: v1alpha2
+scope: Cluster
+columns: []
+"#,
+            )
+            .unwrap(),
+        );
+        assert!(matches!(
+            render_extension_manifest(&iframe_with_crd),
+            Err(ManifestRenderError::ConflictingPageConfig { ref conflicting, .. })
+                if conflicting == "crdTable"
+        ));
+    }
+
     #[test]
     fn validate_frontend_integration_returns_domain_errors() {
         let fi: FrontendIntegration = serde_yaml::from_str(
@@ -212,4 +771,45 @@ spec:
             Err(ManifestRenderError::DuplicatePageKey { .. })
         ));
     }
+
+    #[test]
+    fn classifies_soft_issues_as_warnings_and_render_failures_as_errors() {
+        let mut fi: FrontendIntegration = serde_yaml::from_str(
+            r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: demo
+spec:
+  menus:
+    - displayName: Demo
+      key: demo
+      placement: cluster
+      type: page
+  pages:
+    - key: demo
+      type: iframe
+      iframe:
+        src: http://example.test
+"#,
+        )
+        .unwrap();
+        assert_eq!(classify_frontend_integration(&fi), vec![]);
+
+        fi.spec.menus[0].placement = MenuPlacement::Global;
+        let issues = classify_frontend_integration(&fi);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].severity, ValidationSeverity::Warning);
+        assert!(issues[0].message.contains("only uses global placement"));
+
+        fi.spec.menus.clear();
+        let severities = classify_frontend_integration(&fi)
+            .into_iter()
+            .map(|issue| issue.severity)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            severities,
+            vec![ValidationSeverity::Warning, ValidationSeverity::Error]
+        );
+    }
 }
@@ -0,0 +1,215 @@
+// String-form selector checks. kube only models structured selectors, so this follows the
+// apimachinery grammar closely enough to catch typos before the console sends a bad list request.
+
+const MAX_LABEL_NAME_CHARS: usize = 63;
+const MAX_LABEL_PREFIX_CHARS: usize = 253;
+
+pub(crate) fn validate_label_selector(selector: &str) -> Result<(), String> {
+    for requirement in split_requirements(selector)? {
+        validate_label_requirement(requirement)?;
+    }
+    Ok(())
+}
+
+pub(crate) fn validate_field_selector(selector: &str) -> Result<(), String> {
+    for requirement in split_requirements(selector)? {
+        let (field, _) = ["!=", "==", "="]
+            .iter()
+            .find_map(|op| requirement.split_once(op))
+            .ok_or_else(|| {
+                format!("requirement '{requirement}' must use one of '=', '==' or '!='")
+            })?;
+        let field = field.trim();
+        if field.is_empty()
+            || !field
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'))
+        {
+            return Err(format!("invalid field name '{field}'"));
+        }
+    }
+    Ok(())
+}
+
+// True when an equality requirement fixes `field` to a single value.
+pub(crate) fn field_selector_pins(selector: &str, field: &str) -> bool {
+    split_requirements(selector).is_ok_and(|requirements| {
+        requirements.iter().any(|requirement| {
+            !requirement.contains("!=")
+                && requirement.split_once('=').is_some_and(|(name, value)| {
+                    name.trim() == field && !value.trim_start_matches('=').trim().is_empty()
+                })
+        })
+    })
+}
+
+// Commas inside `in (...)` sets do not separate requirements.
+fn split_requirements(selector: &str) -> Result<Vec<&str>, String> {
+    let mut requirements = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (idx, c) in selector.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| "unbalanced ')'".to_string())?;
+            }
+            ',' if depth == 0 => {
+                requirements.push(selector[start..idx].trim());
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return Err("unbalanced '('".to_string());
+    }
+    requirements.push(selector[start..].trim());
+    if requirements
+        .iter()
+        .any(|requirement| requirement.is_empty())
+    {
+        return Err("selector contains an empty requirement".to_string());
+    }
+    Ok(requirements)
+}
+
+fn validate_label_requirement(requirement: &str) -> Result<(), String> {
+    if let Some(key) = requirement.strip_prefix('!') {
+        return validate_label_key(key.trim());
+    }
+    if let Some(open) = requirement.find('(') {
+        let values = requirement[open + 1..]
+            .strip_suffix(')')
+            .ok_or_else(|| format!("requirement '{requirement}' has text after its value set"))?;
+        let head = requirement[..open].split_whitespace().collect::<Vec<_>>();
+        let [key, "in" | "notin"] = head.as_slice() else {
+            return Err(format!(
+                "requirement '{requirement}' must have the form '<key> in|notin (<values>)'"
+            ));
+        };
+        validate_label_key(key)?;
+        for value in values.split(',') {
+            let value = value.trim();
+            if value.is_empty() {
+                return Err(format!("requirement '{requirement}' has an empty value"));
+            }
+            validate_label_value(value)?;
+        }
+        return Ok(());
+    }
+    match ["!=", "==", "="]
+        .iter()
+        .find_map(|op| requirement.split_once(op))
+    {
+        Some((key, value)) => {
+            validate_label_key(key.trim())?;
+            validate_label_value(value.trim())
+        }
+        None => validate_label_key(requirement),
+    }
+}
+
+fn validate_label_key(key: &str) -> Result<(), String> {
+    let (prefix, name) = match key.split_once('/') {
+        Some((prefix, name)) => (Some(prefix), name),
+        None => (None, key),
+    };
+    if let Some(prefix) = prefix {
+        let valid = !prefix.is_empty()
+            && prefix.len() <= MAX_LABEL_PREFIX_CHARS
+            && prefix.split('.').all(|part| {
+                !part.is_empty()
+                    && part
+                        .chars()
+                        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+                    && !part.starts_with('-')
+                    && !part.ends_with('-')
+            });
+        if !valid {
+            return Err(format!("label key '{key}' has an invalid prefix"));
+        }
+    }
+    if name.is_empty() || !is_label_name(name) {
+        return Err(format!("invalid label key '{key}'"));
+    }
+    Ok(())
+}
+
+fn validate_label_value(value: &str) -> Result<(), String> {
+    if value.is_empty() || is_label_name(value) {
+        Ok(())
+    } else {
+        Err(format!("invalid label value '{value}'"))
+    }
+}
+
+fn is_label_name(name: &str) -> bool {
+    name.len() <= MAX_LABEL_NAME_CHARS
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        && name.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && name.ends_with(|c: char| c.is_ascii_alphanumeric())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_apimachinery_label_selectors() {
+        for selector in [
+            "team=payments",
+            "team==payments,tier!=frontend",
+            "app.kubernetes.io/name in (web, api),!canary",
+            "env notin (dev,staging), release",
+            "empty=",
+        ] {
+            assert_eq!(validate_label_selector(selector), Ok(()), "{selector}");
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_label_selectors() {
+        for selector in [
+            "",
+            "team=payments,",
+            "team in (a,b",
+            "team in ()",
+            "team within (a)",
+            "team=pay ments",
+            "-team=payments",
+            "Example.com/team=payments",
+            "team=payments)",
+        ] {
+            assert!(validate_label_selector(selector).is_err(), "{selector}");
+        }
+    }
+
+    #[test]
+    fn validates_field_selectors() {
+        assert_eq!(
+            validate_field_selector("metadata.namespace=default,status.phase!=Failed"),
+            Ok(())
+        );
+        assert!(validate_field_selector("metadata.name").is_err());
+        assert!(validate_field_selector("=default").is_err());
+        assert!(validate_field_selector("metadata name=default").is_err());
+    }
+
+    #[test]
+    fn detects_fields_pinned_by_equality() {
+        let field = "metadata.namespace";
+        assert!(field_selector_pins("metadata.namespace=default", field));
+        assert!(field_selector_pins(
+            "status.phase!=Failed, metadata.namespace==team-a",
+            field
+        ));
+        assert!(!field_selector_pins("metadata.namespace!=default", field));
+        assert!(!field_selector_pins("metadata.namespace=", field));
+        assert!(!field_selector_pins("metadata.name=default", field));
+    }
+}
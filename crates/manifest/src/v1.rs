@@ -1,28 +1,62 @@
 use frontend_forge_api::{
-    ColumnRenderType, ColumnSpec, CrdScope, CrdTablePageSpec, FrontendIntegration,
-    FrontendIntegrationSpec, MenuNodeType, MenuPlacement, PageSpec, PageType,
+    BuildOutputFormat, ColumnLink, ColumnRenderType, ColumnSpec, CrdActionsSpec, CrdScope,
+    CrdTablePageSpec, CustomPageSpec, FilterSpec, FilterType, FrontendIntegration,
+    FrontendIntegrationSpec, IframePageSpec, MenuNodeType, MenuPlacement, PageSpec, PageType,
+    PermissionSpec, SortOrder, TableSpec, validate_routing_path,
 };
 use kube::ResourceExt;
 use serde_json::{Map, Value, json};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use url::Url;
 
-use crate::ManifestRenderError;
+use crate::{ManifestRenderError, MenuTitleOverflow, PageIdStrategy, RenderOptions, selector};
 
 const DEFAULT_MENU_ICON: &str = "GridDuotone";
+const DEFAULT_PAGE_ID_STRATEGY: PageIdStrategy = PageIdStrategy::FiPlacement;
+// Anything faster hammers the API server; anything slower is effectively manual refresh.
+const CRD_REFRESH_INTERVAL_SECONDS: std::ops::RangeInclusive<u32> = 5..=3600;
+const CRD_TABLE_PAGE_SIZE: std::ops::RangeInclusive<u32> = 1..=500;
+const DEFAULT_SORT_ORDER: SortOrder = SortOrder::Asc;
+// Badge intent for status values missing from `statusMap`.
+const DEFAULT_STATUS_INTENT: &str = "default";
+const DEFAULT_MENU_ORDER: i32 = 999;
+// Units the console number formatter knows how to label.
+const NUMBER_UNITS: &[&str] = &["bytes", "percent", "cores", "millicores", "seconds"];
+const NUMBER_MAX_PRECISION: u8 = 6;
+// Pinned so time columns without format/pattern render the same across console versions.
+const DEFAULT_TIME_FORMAT: &str = "YYYY-MM-DD HH:mm:ss";
+// What the console offered before filters were configurable.
+const DEFAULT_FILTER_KEY: &str = "metadata.name";
+const DEFAULT_FILTER_LABEL: &str = "Name";
+// Route params the console substitutes into FRAME_URL_TEMPLATE; other `:word`s (ports, userinfo) are literal.
+const IFRAME_PLACEHOLDERS: &[&str] = &["cluster", "workspace", "namespace"];
+// Keywords the HTML spec defines for the iframe sandbox attribute; browsers silently ignore others.
+const IFRAME_SANDBOX_TOKENS: &[&str] = &[
+    "allow-downloads",
+    "allow-forms",
+    "allow-modals",
+    "allow-orientation-lock",
+    "allow-pointer-lock",
+    "allow-popups",
+    "allow-popups-to-escape-sandbox",
+    "allow-presentation",
+    "allow-same-origin",
+    "allow-scripts",
+    "allow-top-navigation",
+    "allow-top-navigation-by-user-activation",
+    "allow-top-navigation-to-custom-protocols",
+];
+const IFRAME_HEIGHT_UNITS: &[&str] = &["px", "%", "vh", "em", "rem"];
 
-pub(super) fn render_v1_manifest(fi: &FrontendIntegration) -> Result<Value, ManifestRenderError> {
+pub(super) fn render_v1_manifest(
+    fi: &FrontendIntegration,
+    options: &RenderOptions,
+) -> Result<Value, ManifestRenderError> {
     let fi_name = fi.name_any();
-    let display_name = fi
-        .spec
-        .display_name
-        .clone()
-        .unwrap_or_else(|| fi_name.clone());
-    let description = fi
-        .metadata
-        .annotations
-        .as_ref()
-        .and_then(|a| a.get("kubesphere.io/description").cloned());
-    let resolved_menus = resolve_spec(&fi.spec, &fi_name)?;
+    let default_icon = default_menu_icon(options);
+    let (display_name, description) = display_name_and_description(fi);
+    let resolved_menus = resolve_spec(&fi.spec, &fi_name, options)?;
+    validate_locale_references(&fi_name, &fi.spec)?;
 
     let mut routes = Vec::new();
     let mut menus = Vec::new();
@@ -31,15 +65,15 @@ pub(super) fn render_v1_manifest(fi: &FrontendIntegration) -> Result<Value, Mani
     for menu in resolved_menus {
         match menu {
             ResolvedTopMenu::Page(page) => {
-                menus.push(render_leaf_menu(&page));
-                routes.push(render_route(&fi_name, &page));
+                menus.push(render_leaf_menu(&page, default_icon));
+                routes.extend(render_routes(&fi_name, &page));
                 pages.push(render_page(&fi_name, &page)?);
             }
             ResolvedTopMenu::Organization { menu, children } => {
-                menus.push(render_organization_menu(&menu));
+                menus.push(render_organization_menu(&menu, default_icon));
                 for child in children {
-                    menus.push(render_leaf_menu(&child));
-                    routes.push(render_route(&fi_name, &child));
+                    menus.push(render_leaf_menu(&child, default_icon));
+                    routes.extend(render_routes(&fi_name, &child));
                     pages.push(render_page(&fi_name, &child)?);
                 }
             }
@@ -53,25 +87,110 @@ pub(super) fn render_v1_manifest(fi: &FrontendIntegration) -> Result<Value, Mani
     if let Some(description) = description {
         manifest.insert("description".to_string(), json!(description));
     }
+    if let Some(meta) = render_meta(&fi.spec) {
+        manifest.insert("meta".to_string(), meta);
+    }
     manifest.insert("routes".to_string(), Value::Array(routes));
     manifest.insert("menus".to_string(), Value::Array(menus));
     manifest.insert("locales".to_string(), render_locales(&fi.spec));
     manifest.insert("pages".to_string(), Value::Array(pages));
-    manifest.insert(
-        "build".to_string(),
-        json!({
-            "target": "kubesphere-extension",
-            "moduleName": fi.name_any(),
-            "systemjs": true,
-        }),
-    );
+    let mut build = json!({
+        "target": "kubesphere-extension",
+        "moduleName": fi.name_any(),
+        "systemjs": true,
+    });
+    apply_build_output(&fi_name, &fi.spec, &mut build)?;
+    manifest.insert("build".to_string(), build);
 
     Ok(Value::Object(manifest))
 }
 
+// Only keys the author set are written, so FIs without builder.output keep their manifest hash.
+pub(super) fn apply_build_output(
+    fi_name: &str,
+    spec: &FrontendIntegrationSpec,
+    build: &mut Value,
+) -> Result<(), ManifestRenderError> {
+    let Some(output) = spec
+        .builder
+        .as_ref()
+        .and_then(|builder| builder.output.as_ref())
+    else {
+        return Ok(());
+    };
+    validate_externals(fi_name, &output.externals)?;
+    if let Some(format) = output.format {
+        build["format"] = json!(format.as_str());
+        build["systemjs"] = json!(format == BuildOutputFormat::Systemjs);
+    }
+    if !output.externals.is_empty() {
+        build["externals"] = json!(output.externals);
+    }
+    if let Some(minify) = output.minify {
+        build["minify"] = json!(minify);
+    }
+    Ok(())
+}
+
+fn validate_externals(fi_name: &str, externals: &[String]) -> Result<(), ManifestRenderError> {
+    let mut seen = HashSet::new();
+    for external in externals {
+        let name = external.strip_prefix('@').unwrap_or(external);
+        let valid = !name.is_empty()
+            && !name.starts_with(['.', '_', '/'])
+            && !name.ends_with('/')
+            && !name.contains("//")
+            && name.chars().all(|c| {
+                c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '-' | '.' | '_' | '/')
+            });
+        let message = if !valid {
+            format!("externals entry '{external}' is not a package name")
+        } else if !seen.insert(external.as_str()) {
+            format!("externals entry '{external}' is listed twice")
+        } else {
+            continue;
+        };
+        return Err(ManifestRenderError::InvalidBuildOutput {
+            fi_name: fi_name.to_string(),
+            message,
+        });
+    }
+    Ok(())
+}
+
+pub(super) fn default_menu_icon(options: &RenderOptions) -> &str {
+    options
+        .default_menu_icon
+        .as_deref()
+        .unwrap_or(DEFAULT_MENU_ICON)
+}
+
+pub(super) fn display_name_and_description(fi: &FrontendIntegration) -> (String, Option<String>) {
+    let display_name = fi
+        .spec
+        .display_name
+        .clone()
+        .unwrap_or_else(|| fi.name_any());
+    let description = fi.spec.description.clone().or_else(|| {
+        fi.metadata
+            .annotations
+            .as_ref()
+            .and_then(|a| a.get("kubesphere.io/description").cloned())
+    });
+    (display_name, description)
+}
+
+// Omitted entirely when nothing is set so manifests of existing FIs stay byte-identical.
+pub(super) fn render_meta(spec: &FrontendIntegrationSpec) -> Option<Value> {
+    spec.metadata
+        .as_ref()
+        .filter(|metadata| !metadata.is_empty())
+        .map(|metadata| json!(metadata))
+}
+
 #[derive(Clone, Debug)]
-enum ResolvedTopMenu {
-    Page(ResolvedPageBinding),
+pub(super) enum ResolvedTopMenu {
+    Page(Box<ResolvedPageBinding>),
     Organization {
         menu: ResolvedOrganizationMenu,
         children: Vec<ResolvedPageBinding>,
@@ -79,35 +198,44 @@ enum ResolvedTopMenu {
 }
 
 #[derive(Clone, Debug)]
-struct ResolvedOrganizationMenu {
+pub(super) struct ResolvedOrganizationMenu {
     name: String,
     title: String,
     icon: Option<String>,
-    placement: MenuPlacement,
+    order: i32,
+    parent: String,
 }
 
 #[derive(Clone, Debug)]
-struct ResolvedPageBinding {
+pub(super) struct ResolvedPageBinding {
     title: String,
     icon: Option<String>,
+    order: i32,
     placement: MenuPlacement,
     route_suffix: String,
+    route_aliases: Vec<String>,
+    pub(super) page_id: String,
     menu_name: String,
     parent: String,
-    page: PageSpec,
+    pub(super) page: PageSpec,
 }
 
-fn resolve_spec(
+pub(super) fn resolve_spec(
     spec: &FrontendIntegrationSpec,
     fi_name: &str,
+    options: &RenderOptions,
 ) -> Result<Vec<ResolvedTopMenu>, ManifestRenderError> {
-    let pages_by_key = resolve_pages(spec, fi_name)?;
+    let pages_by_key = resolve_pages(spec, fi_name, options)?;
     let mut top_level_keys = HashSet::new();
     let mut bound_page_keys = HashSet::new();
     let mut bound_page_bindings = HashSet::new();
+    let mut route_paths = HashSet::new();
     let mut resolved = Vec::new();
 
-    for menu in &spec.menus {
+    // Stable, so the author's order within a placement still decides the nav order.
+    let mut menus = spec.menus.iter().collect::<Vec<_>>();
+    menus.sort_by_key(|menu| menu.placement.rank());
+    for menu in menus {
         validate_key(fi_name, &menu.key, true)?;
         if !top_level_keys.insert(menu.key.clone()) {
             return Err(ManifestRenderError::DuplicateTopLevelMenuKey {
@@ -115,6 +243,8 @@ fn resolve_spec(
                 key: menu.key.clone(),
             });
         }
+        validate_menu_icon(fi_name, &menu.key, menu.icon.as_deref())?;
+        let menu_parent = menu_parent(fi_name, &menu.key, menu.placement, menu.parent.as_deref())?;
 
         match menu.type_ {
             MenuNodeType::Page => {
@@ -135,15 +265,33 @@ fn resolve_spec(
                     &mut bound_page_keys,
                     &mut bound_page_bindings,
                 )?;
-                resolved.push(ResolvedTopMenu::Page(ResolvedPageBinding {
-                    title: menu.display_name.clone(),
+                let route_suffix =
+                    routing_path(fi_name, &menu.key, route_suffix_for_menu(&menu.key))?;
+                let route_aliases = route_aliases(
+                    fi_name,
+                    &menu.key,
+                    menu.placement,
+                    &route_suffix,
+                    &menu.aliases,
+                    &mut route_paths,
+                )?;
+                resolved.push(ResolvedTopMenu::Page(Box::new(ResolvedPageBinding {
+                    title: sanitize_menu_title(fi_name, &menu.key, &menu.display_name, options)?,
                     icon: menu.icon.clone(),
+                    order: menu.order.unwrap_or(DEFAULT_MENU_ORDER),
                     placement: menu.placement,
-                    route_suffix: route_suffix_for_menu(&menu.key),
+                    route_suffix,
+                    route_aliases,
+                    page_id: page_id_for_suffix(
+                        fi_name,
+                        menu.placement,
+                        &route_suffix_for_menu(&menu.key),
+                        options,
+                    ),
                     menu_name: top_menu_name,
-                    parent: menu.placement.as_str().to_string(),
+                    parent: menu_parent,
                     page,
-                }));
+                })));
             }
             MenuNodeType::Organization => {
                 let top_menu_name = menu_name_for_suffix(fi_name, &menu.key);
@@ -161,10 +309,18 @@ fn resolve_spec(
                         message: "organization menus cannot bind to page configs".to_string(),
                     });
                 }
+                if !menu.aliases.is_empty() {
+                    return Err(ManifestRenderError::InvalidMenuShape {
+                        fi_name: fi_name.to_string(),
+                        key: menu.key.clone(),
+                        message: "organization menus have no route to alias".to_string(),
+                    });
+                }
 
                 let mut children = Vec::new();
                 for child in &menu.children {
                     validate_key(fi_name, &child.key, true)?;
+                    validate_menu_icon(fi_name, &child.key, child.icon.as_deref())?;
                     let page = bind_page(
                         fi_name,
                         menu.placement,
@@ -173,14 +329,39 @@ fn resolve_spec(
                         &mut bound_page_keys,
                         &mut bound_page_bindings,
                     )?;
-                    let route_suffix = route_suffix_for_child(&menu.key, &child.key);
+                    let route_suffix = routing_path(
+                        fi_name,
+                        &child.key,
+                        route_suffix_for_child(&menu.key, &child.key),
+                    )?;
+                    let route_aliases = route_aliases(
+                        fi_name,
+                        &child.key,
+                        menu.placement,
+                        &route_suffix,
+                        &child.aliases,
+                        &mut route_paths,
+                    )?;
                     children.push(ResolvedPageBinding {
-                        title: child.display_name.clone(),
+                        title: sanitize_menu_title(
+                            fi_name,
+                            &child.key,
+                            &child.display_name,
+                            options,
+                        )?,
                         icon: child.icon.clone(),
+                        order: child.order.unwrap_or(DEFAULT_MENU_ORDER),
                         placement: menu.placement,
                         route_suffix: route_suffix.clone(),
+                        route_aliases,
+                        page_id: page_id_for_suffix(
+                            fi_name,
+                            menu.placement,
+                            &route_suffix,
+                            options,
+                        ),
                         menu_name: menu_name_for_suffix(fi_name, &route_suffix),
-                        parent: nested_menu_parent(menu.placement, &top_menu_name),
+                        parent: nested_menu_parent(&menu_parent, &top_menu_name),
                         page,
                     });
                 }
@@ -188,9 +369,15 @@ fn resolve_spec(
                 resolved.push(ResolvedTopMenu::Organization {
                     menu: ResolvedOrganizationMenu {
                         name: top_menu_name,
-                        title: menu.display_name.clone(),
+                        title: sanitize_menu_title(
+                            fi_name,
+                            &menu.key,
+                            &menu.display_name,
+                            options,
+                        )?,
                         icon: menu.icon.clone(),
-                        placement: menu.placement,
+                        order: menu.order.unwrap_or(DEFAULT_MENU_ORDER),
+                        parent: menu_parent,
                     },
                     children,
                 });
@@ -210,15 +397,107 @@ fn resolve_spec(
     Ok(resolved)
 }
 
+// `{{key}}` values are looked up in `spec.locales` by the console; anything else is literal text.
+fn locale_reference(value: &str) -> Option<&str> {
+    value
+        .trim()
+        .strip_prefix("{{")?
+        .strip_suffix("}}")
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+}
+
+pub(super) fn validate_locale_references(
+    fi_name: &str,
+    spec: &FrontendIntegrationSpec,
+) -> Result<(), ManifestRenderError> {
+    let menu_titles = spec.menus.iter().flat_map(|menu| {
+        std::iter::once(menu.display_name.as_str()).chain(
+            menu.children
+                .iter()
+                .map(|child| child.display_name.as_str()),
+        )
+    });
+    let column_titles = spec
+        .pages
+        .iter()
+        .filter_map(|page| page.crd_table.as_ref())
+        .flat_map(|crd_table| crd_table.columns.iter().map(|column| column.title.as_str()));
+    let missing = spec
+        .display_name
+        .as_deref()
+        .into_iter()
+        .chain(menu_titles)
+        .chain(column_titles)
+        .filter_map(locale_reference)
+        .filter(|key| {
+            !spec
+                .locales
+                .values()
+                .any(|messages| messages.contains_key(*key))
+        })
+        .map(str::to_string)
+        .collect::<BTreeSet<_>>();
+    if missing.is_empty() {
+        return Ok(());
+    }
+    Err(ManifestRenderError::MissingLocaleKeys {
+        fi_name: fi_name.to_string(),
+        keys: missing.into_iter().collect(),
+    })
+}
+
+// Newlines and other control characters break the console nav, so they never reach the manifest.
+fn sanitize_menu_title(
+    fi_name: &str,
+    key: &str,
+    title: &str,
+    options: &RenderOptions,
+) -> Result<String, ManifestRenderError> {
+    let mut sanitized = String::with_capacity(title.len());
+    for c in title.chars() {
+        if !c.is_control() {
+            sanitized.push(c);
+        } else if c.is_whitespace() && !sanitized.ends_with(' ') {
+            sanitized.push(' ');
+        }
+    }
+    let sanitized = sanitized.trim();
+    // Truncating a reference would leave a key the console cannot resolve.
+    if locale_reference(sanitized).is_some() {
+        return Ok(sanitized.to_string());
+    }
+
+    let length = sanitized.chars().count();
+    let Some(max) = options.menu_title_max_chars.filter(|max| length > *max) else {
+        return Ok(sanitized.to_string());
+    };
+    match options.menu_title_overflow {
+        MenuTitleOverflow::Truncate => Ok(sanitized
+            .chars()
+            .take(max)
+            .collect::<String>()
+            .trim_end()
+            .to_string()),
+        MenuTitleOverflow::Reject => Err(ManifestRenderError::MenuTitleTooLong {
+            fi_name: fi_name.to_string(),
+            key: key.to_string(),
+            length,
+            max,
+        }),
+    }
+}
+
 fn resolve_pages(
     spec: &FrontendIntegrationSpec,
     fi_name: &str,
+    options: &RenderOptions,
 ) -> Result<HashMap<String, PageSpec>, ManifestRenderError> {
     let mut pages = HashMap::new();
 
     for page in &spec.pages {
         validate_key(fi_name, &page.key, false)?;
-        validate_page_shape(fi_name, page)?;
+        validate_page_shape(fi_name, page, options)?;
         if pages.insert(page.key.clone(), page.clone()).is_some() {
             return Err(ManifestRenderError::DuplicatePageKey {
                 fi_name: fi_name.to_string(),
@@ -246,13 +525,59 @@ fn bind_page(
     }
     bound_page_keys.insert(key.to_string());
 
-    pages_by_key
-        .get(key)
-        .cloned()
-        .ok_or_else(|| ManifestRenderError::MissingPageForMenuKey {
+    let page = pages_by_key.get(key).cloned().ok_or_else(|| {
+        ManifestRenderError::MissingPageForMenuKey {
             fi_name: fi_name.to_string(),
             key: key.to_string(),
-        })
+        }
+    })?;
+    if let Some(crd_table) = page.crd_table.as_ref() {
+        validate_crd_scope(fi_name, key, placement, crd_table)?;
+    }
+    if let Some(iframe) = page.iframe.as_ref()
+        && let Some(placeholder) = iframe_placeholders(&iframe.src)
+            .into_iter()
+            .find(|placeholder| !placement_provides(placement, placeholder))
+    {
+        return Err(ManifestRenderError::InvalidIframeSrc {
+            fi_name: fi_name.to_string(),
+            key: key.to_string(),
+            src: iframe.src.clone(),
+            message: format!(
+                "placeholder ':{placeholder}' is not available in the {} placement",
+                placement.as_str()
+            ),
+        });
+    }
+    Ok(page)
+}
+
+// Project and workspace routes scope list requests to namespaces, and global has no namespace
+// context at all, so only these combinations produce a working list query.
+fn validate_crd_scope(
+    fi_name: &str,
+    key: &str,
+    placement: MenuPlacement,
+    crd_table: &CrdTablePageSpec,
+) -> Result<(), ManifestRenderError> {
+    let fi_name = fi_name.to_string();
+    let key = key.to_string();
+    match (placement, &crd_table.scope) {
+        (MenuPlacement::Project, CrdScope::Cluster) => {
+            Err(ManifestRenderError::ClusterScopedCrdInProject { fi_name, key })
+        }
+        (MenuPlacement::Workspace, CrdScope::Cluster) => {
+            Err(ManifestRenderError::ClusterScopedCrdInWorkspace { fi_name, key })
+        }
+        (MenuPlacement::Global, CrdScope::Namespaced)
+            if !crd_table.field_selector.as_deref().is_some_and(|selector| {
+                selector::field_selector_pins(selector, "metadata.namespace")
+            }) =>
+        {
+            Err(ManifestRenderError::NamespacedCrdWithoutNamespace { fi_name, key })
+        }
+        _ => Ok(()),
+    }
 }
 
 fn validate_key(fi_name: &str, key: &str, is_menu_key: bool) -> Result<(), ManifestRenderError> {
@@ -279,21 +604,188 @@ fn validate_key(fi_name: &str, key: &str, is_menu_key: bool) -> Result<(), Manif
     }
 }
 
-fn validate_page_shape(fi_name: &str, page: &PageSpec) -> Result<(), ManifestRenderError> {
+// Only used to tell relative sources, which the console loads from its own origin, apart from
+// protocol-relative ones that name another host.
+const CONSOLE_ORIGIN_PLACEHOLDER: &str = "http://console.frontend-forge.invalid/";
+
+// FRAME_URL is loaded by the console as-is, so anything but http(s) could run script in its origin.
+fn validate_iframe_src(
+    fi_name: &str,
+    key: &str,
+    src: &str,
+    options: &RenderOptions,
+) -> Result<(), ManifestRenderError> {
+    let invalid = |message: String| ManifestRenderError::InvalidIframeSrc {
+        fi_name: fi_name.to_string(),
+        key: key.to_string(),
+        src: src.to_string(),
+        message,
+    };
+    let src = src.trim();
+    let url = match Url::parse(src) {
+        Ok(url) => url,
+        Err(url::ParseError::RelativeUrlWithoutBase) => {
+            let console =
+                Url::parse(CONSOLE_ORIGIN_PLACEHOLDER).expect("placeholder origin parses");
+            let url = console.join(src).map_err(|err| invalid(err.to_string()))?;
+            if url.origin() == console.origin() {
+                return Ok(());
+            }
+            url
+        }
+        Err(err) => return Err(invalid(err.to_string())),
+    };
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(invalid(format!(
+            "scheme '{}' is not allowed; use http or https",
+            url.scheme()
+        )));
+    }
+    let Some(host) = url.host_str() else {
+        return Err(invalid("missing host".to_string()));
+    };
+    if !options.iframe_allowed_hosts.is_empty()
+        && !options
+            .iframe_allowed_hosts
+            .iter()
+            .any(|allowed| host_matches(host, allowed))
+    {
+        return Err(invalid(format!("host '{host}' is not in the allowlist")));
+    }
+    Ok(())
+}
+
+fn validate_iframe_attributes(
+    fi_name: &str,
+    key: &str,
+    iframe: &IframePageSpec,
+) -> Result<(), ManifestRenderError> {
+    let invalid = |message: String| ManifestRenderError::InvalidPageShape {
+        fi_name: fi_name.to_string(),
+        key: key.to_string(),
+        message,
+    };
+    for token in iframe.sandbox.iter().flatten() {
+        if !IFRAME_SANDBOX_TOKENS.contains(&token.as_str()) {
+            return Err(invalid(format!(
+                "iframe.sandbox token '{token}' is not an HTML sandbox keyword"
+            )));
+        }
+    }
+    for directive in iframe.allow.iter().flatten() {
+        if directive.trim().is_empty() || directive.contains(';') {
+            return Err(invalid(format!(
+                "iframe.allow entry '{directive}' must be a single non-empty directive"
+            )));
+        }
+    }
+    if let Some(height) = iframe.height.as_deref()
+        && !is_css_height(height)
+    {
+        return Err(invalid(format!(
+            "iframe.height '{height}' must be a positive length in {}",
+            IFRAME_HEIGHT_UNITS.join(", ")
+        )));
+    }
+    Ok(())
+}
+
+fn is_css_height(height: &str) -> bool {
+    IFRAME_HEIGHT_UNITS.iter().any(|unit| {
+        height
+            .strip_suffix(unit)
+            .and_then(|value| value.parse::<f64>().ok())
+            .is_some_and(|value| value.is_finite() && value > 0.0)
+    })
+}
+
+fn iframe_placeholders(src: &str) -> Vec<&'static str> {
+    let mut found = Vec::new();
+    for (idx, _) in src.match_indices(':') {
+        let rest = &src[idx + 1..];
+        let end = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        if let Some(name) = IFRAME_PLACEHOLDERS
+            .iter()
+            .find(|name| **name == &rest[..end])
+            && !found.contains(name)
+        {
+            found.push(*name);
+        }
+    }
+    found
+}
+
+fn placement_provides(placement: MenuPlacement, placeholder: &str) -> bool {
+    placement
+        .route_prefix()
+        .split('/')
+        .any(|segment| segment.strip_prefix(':') == Some(placeholder))
+}
+
+// Dotted segments allow components namespaced under a bundle export.
+fn is_component_name(name: &str) -> bool {
+    name.split('.').all(|segment| {
+        segment.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && segment
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_')
+    })
+}
+
+fn host_matches(host: &str, allowed: &str) -> bool {
+    match allowed.strip_prefix("*.") {
+        Some(domain) => host
+            .strip_suffix(domain)
+            .is_some_and(|prefix| prefix.ends_with('.')),
+        None => host == allowed,
+    }
+}
+
+fn validate_page_shape(
+    fi_name: &str,
+    page: &PageSpec,
+    options: &RenderOptions,
+) -> Result<(), ManifestRenderError> {
+    for permission in &page.permissions {
+        if permission.resource.trim().is_empty() || permission.verbs.is_empty() {
+            return Err(ManifestRenderError::InvalidPageShape {
+                fi_name: fi_name.to_string(),
+                key: page.key.clone(),
+                message: "permissions require a resource and at least one verb".to_string(),
+            });
+        }
+    }
+
     match page.type_ {
         PageType::Iframe => {
-            if page.iframe.is_none() {
+            let Some(iframe) = page.iframe.as_ref() else {
                 return Err(ManifestRenderError::InvalidPageShape {
                     fi_name: fi_name.to_string(),
                     key: page.key.clone(),
                     message: "type=iframe requires iframe config".to_string(),
                 });
-            }
-            if page.crd_table.is_some() {
+            };
+            validate_iframe_src(fi_name, &page.key, &iframe.src, options)?;
+            validate_iframe_attributes(fi_name, &page.key, iframe)?;
+        }
+        PageType::Custom => {
+            let Some(custom) = page.custom.as_ref() else {
+                return Err(ManifestRenderError::InvalidPageShape {
+                    fi_name: fi_name.to_string(),
+                    key: page.key.clone(),
+                    message: "type=custom requires custom config".to_string(),
+                });
+            };
+            if !is_component_name(&custom.type_) {
                 return Err(ManifestRenderError::InvalidPageShape {
                     fi_name: fi_name.to_string(),
                     key: page.key.clone(),
-                    message: "type=iframe cannot define crdTable config".to_string(),
+                    message: format!(
+                        "custom.type '{}' must be a component name such as 'Dashboard' or 'Ops.Dashboard'",
+                        custom.type_
+                    ),
                 });
             }
         }
@@ -305,153 +797,771 @@ fn validate_page_shape(fi_name: &str, page: &PageSpec) -> Result<(), ManifestRen
                     message: "type=crdTable requires crdTable config".to_string(),
                 });
             };
-            if page.iframe.is_some() {
-                return Err(ManifestRenderError::InvalidPageShape {
+            if crd_table.columns.is_empty() {
+                return Err(ManifestRenderError::MissingCrdColumns {
                     fi_name: fi_name.to_string(),
                     key: page.key.clone(),
-                    message: "type=crdTable cannot define iframe config".to_string(),
+                    placement: None,
                 });
             }
-            if crd_table.columns.is_empty() {
-                return Err(ManifestRenderError::MissingCrdColumns {
+            if let Some(interval) = crd_table.refresh_interval_seconds
+                && !CRD_REFRESH_INTERVAL_SECONDS.contains(&interval)
+            {
+                return Err(ManifestRenderError::InvalidPageShape {
                     fi_name: fi_name.to_string(),
                     key: page.key.clone(),
+                    message: format!(
+                        "crdTable.refreshIntervalSeconds must be between {} and {}, got {interval}",
+                        CRD_REFRESH_INTERVAL_SECONDS.start(),
+                        CRD_REFRESH_INTERVAL_SECONDS.end()
+                    ),
                 });
             }
+            for (field, selector, validate) in [
+                (
+                    "labelSelector",
+                    crd_table.label_selector.as_deref(),
+                    selector::validate_label_selector as fn(&str) -> Result<(), String>,
+                ),
+                (
+                    "fieldSelector",
+                    crd_table.field_selector.as_deref(),
+                    selector::validate_field_selector,
+                ),
+            ] {
+                if let Some(selector) = selector {
+                    validate(selector.trim()).map_err(|message| {
+                        ManifestRenderError::InvalidSelector {
+                            fi_name: fi_name.to_string(),
+                            key: page.key.clone(),
+                            field: field.to_string(),
+                            selector: selector.to_string(),
+                            message,
+                        }
+                    })?;
+                }
+            }
+            if let Some(table) = crd_table.table.as_ref() {
+                validate_table_settings(fi_name, &page.key, crd_table, table)?;
+            }
+            validate_filters(fi_name, &page.key, &crd_table.filters)?;
+            // The console keys table state by column key, so a repeat would share one slot.
+            let mut column_keys = HashSet::new();
+            for column in &crd_table.columns {
+                if !column_keys.insert(column.key.as_str()) {
+                    return Err(ManifestRenderError::DuplicateColumnKey {
+                        fi_name: fi_name.to_string(),
+                        key: page.key.clone(),
+                        column: column.key.clone(),
+                    });
+                }
+                if is_map_render_type(&column.render.type_)
+                    && !is_map_field_path(&column.render.path)
+                {
+                    return Err(ManifestRenderError::InvalidMapColumnPath {
+                        fi_name: fi_name.to_string(),
+                        key: page.key.clone(),
+                        column: column.key.clone(),
+                        render_type: render_type_str(&column.render.type_).to_string(),
+                        path: column.render.path.clone(),
+                    });
+                }
+                validate_render_fields(fi_name, &page.key, column)?;
+                if column.render.type_ == ColumnRenderType::Number {
+                    validate_number_column(fi_name, &page.key, column)?;
+                }
+                if column.render.type_ == ColumnRenderType::Template {
+                    column_template_params(fi_name, &page.key, column)?;
+                }
+                if column.render.type_ == ColumnRenderType::Custom {
+                    validate_custom_column(fi_name, &page.key, column)?;
+                }
+                if column.render.type_ == ColumnRenderType::Time
+                    && column.render.relative == Some(true)
+                    && column.render.pattern.is_some()
+                {
+                    return Err(ManifestRenderError::InvalidColumn {
+                        fi_name: fi_name.to_string(),
+                        key: page.key.clone(),
+                        column: column.key.clone(),
+                        message: "pattern cannot be combined with relative: true".to_string(),
+                    });
+                }
+                if let Some(ColumnLink::Spec(link)) = &column.render.link {
+                    link_template_params(&link.template).map_err(|message| {
+                        ManifestRenderError::InvalidLinkTemplate {
+                            fi_name: fi_name.to_string(),
+                            key: page.key.clone(),
+                            column: column.key.clone(),
+                            template: link.template.clone(),
+                            message,
+                        }
+                    })?;
+                }
+            }
         }
     }
 
     Ok(())
 }
 
-fn route_suffix_for_menu(key: &str) -> String {
-    key.to_string()
-}
-
-fn route_suffix_for_child(parent_key: &str, child_key: &str) -> String {
-    format!("{parent_key}/{child_key}")
-}
-
-fn menu_name_for_suffix(fi_name: &str, suffix: &str) -> String {
-    format!("frontendintegrations/{fi_name}/{suffix}")
+// Placeholders follow the link template rules; a template without any is just a constant.
+fn column_template_params(
+    fi_name: &str,
+    page_key: &str,
+    column: &ColumnSpec,
+) -> Result<Vec<String>, ManifestRenderError> {
+    let invalid = |message: String| ManifestRenderError::InvalidColumn {
+        fi_name: fi_name.to_string(),
+        key: page_key.to_string(),
+        column: column.key.clone(),
+        message,
+    };
+    let template = column
+        .render
+        .template
+        .as_deref()
+        .ok_or_else(|| invalid("type=template requires render.template".to_string()))?;
+    let params = link_template_params(template)
+        .map_err(|message| invalid(format!("template '{template}': {message}")))?;
+    if params.is_empty() {
+        return Err(invalid(format!(
+            "template '{template}' must reference at least one {{field.path}}"
+        )));
+    }
+    Ok(params)
 }
 
-fn nested_menu_parent(placement: MenuPlacement, menu_name: &str) -> String {
-    format!("{}.{}", placement.as_str(), menu_name)
+fn validate_number_column(
+    fi_name: &str,
+    page_key: &str,
+    column: &ColumnSpec,
+) -> Result<(), ManifestRenderError> {
+    let render = &column.render;
+    let message = if let Some(unit) = render
+        .unit
+        .as_deref()
+        .filter(|unit| !NUMBER_UNITS.contains(unit))
+    {
+        format!("unit '{unit}' is not one of {}", NUMBER_UNITS.join(", "))
+    } else if let Some(precision) = render
+        .precision
+        .filter(|precision| *precision > NUMBER_MAX_PRECISION)
+    {
+        format!("precision must be between 0 and {NUMBER_MAX_PRECISION}, got {precision}")
+    } else if render.downscale == Some(true) && render.unit.as_deref() != Some("bytes") {
+        "downscale requires unit: bytes".to_string()
+    } else {
+        return Ok(());
+    };
+    Err(ManifestRenderError::InvalidColumn {
+        fi_name: fi_name.to_string(),
+        key: page_key.to_string(),
+        column: column.key.clone(),
+        message,
+    })
 }
 
-fn page_id_for_suffix(fi_name: &str, placement: MenuPlacement, suffix: &str) -> String {
-    format!(
-        "{}-{}-{}",
-        fi_name,
-        placement.as_str(),
-        suffix.replace('/', "_")
-    )
-}
+// transform_columns only emits type-specific fields for their own render type, so setting one
+// elsewhere is rejected instead of silently dropped.
+fn validate_render_fields(
+    fi_name: &str,
+    page_key: &str,
+    column: &ColumnSpec,
+) -> Result<(), ManifestRenderError> {
+    use ColumnRenderType::*;
 
-fn render_route(fi_name: &str, page: &ResolvedPageBinding) -> Value {
-    let page_id = page_id_for_suffix(fi_name, page.placement, &page.route_suffix);
-    json!({
-        "path": format!(
-            "{}{}",
-            page.placement.route_prefix(),
-            route_tail(fi_name, &page.route_suffix)
+    let render = &column.render;
+    let fields: [(&str, bool, &[ColumnRenderType]); 11] = [
+        ("statusMap", render.status_map.is_some(), &[Status]),
+        ("defaultIntent", render.default_intent.is_some(), &[Status]),
+        (
+            "includeKeys",
+            !render.include_keys.is_empty(),
+            &[Labels, Annotations],
+        ),
+        (
+            "excludeKeys",
+            !render.exclude_keys.is_empty(),
+            &[Labels, Annotations],
+        ),
+        ("copyable", render.copyable.is_some(), &[Text, Link]),
+        ("unit", render.unit.is_some(), &[Number]),
+        ("precision", render.precision.is_some(), &[Number]),
+        ("downscale", render.downscale.is_some(), &[Number]),
+        ("relative", render.relative.is_some(), &[Time]),
+        ("tooltipFormat", render.tooltip_format.is_some(), &[Time]),
+        ("template", render.template.is_some(), &[Template]),
+    ];
+    let Some((field, _, types)) = fields
+        .into_iter()
+        .find(|(_, set, types)| *set && !types.contains(&render.type_))
+    else {
+        return Ok(());
+    };
+    let types: Vec<&str> = types.iter().map(render_type_str).collect();
+    Err(ManifestRenderError::InvalidColumn {
+        fi_name: fi_name.to_string(),
+        key: page_key.to_string(),
+        column: column.key.clone(),
+        message: format!(
+            "{field} is only supported by {} columns, not {}",
+            types.join("/"),
+            render_type_str(&render.type_)
         ),
-        "pageId": page_id,
-    })
-}
-
-fn render_leaf_menu(page: &ResolvedPageBinding) -> Value {
-    json!({
-        "parent": page.parent,
-        "name": page.menu_name,
-        "title": page.title,
-        "icon": menu_icon(page.icon.as_ref()),
-        "order": 999,
     })
 }
 
-fn render_organization_menu(menu: &ResolvedOrganizationMenu) -> Value {
-    json!({
-        "parent": menu.placement.as_str(),
-        "name": menu.name,
-        "title": menu.title,
-        "icon": menu_icon(menu.icon.as_ref()),
-        "order": 999,
+// The component name is resolved by the console at runtime, so only its presence is checked.
+fn validate_custom_column(
+    fi_name: &str,
+    page_key: &str,
+    column: &ColumnSpec,
+) -> Result<(), ManifestRenderError> {
+    let payload = column.render.payload.as_ref();
+    let message = match payload.and_then(|payload| payload.get("component")) {
+        Some(Value::String(component)) if !component.trim().is_empty() => {
+            match payload.and_then(|payload| payload.get("props")) {
+                None | Some(Value::Object(_)) => return Ok(()),
+                Some(_) => "payload.props must be an object".to_string(),
+            }
+        }
+        Some(Value::String(_)) | None => {
+            "custom render requires a non-empty payload.component".to_string()
+        }
+        Some(_) => "payload.component must be a string".to_string(),
+    };
+    Err(ManifestRenderError::InvalidColumn {
+        fi_name: fi_name.to_string(),
+        key: page_key.to_string(),
+        column: column.key.clone(),
+        message,
     })
 }
 
-fn menu_icon(icon: Option<&String>) -> &str {
-    icon.map(String::as_str).unwrap_or(DEFAULT_MENU_ICON)
+// Returns the `{field.path}` placeholders in order; each must be a dotted JSON path whose
+// segments may carry a numeric index, e.g. `{spec.ports[0].name}`.
+fn link_template_params(template: &str) -> Result<Vec<String>, String> {
+    if template.trim().is_empty() {
+        return Err("template must not be empty".to_string());
+    }
+    let mut params = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find(['{', '}']) {
+        if rest[start..].starts_with('}') {
+            return Err("unmatched '}'".to_string());
+        }
+        let after = &rest[start + 1..];
+        let end = after.find('}').ok_or("unclosed '{'")?;
+        let placeholder = &after[..end];
+        if !is_json_field_path(placeholder) {
+            return Err(format!(
+                "placeholder '{{{placeholder}}}' is not a valid field path"
+            ));
+        }
+        params.push(placeholder.to_string());
+        rest = &after[end + 1..];
+    }
+    Ok(params)
 }
 
-fn route_tail(fi_name: &str, suffix: &str) -> String {
-    format!("/frontendintegrations/{fi_name}/{suffix}")
+fn is_json_field_path(path: &str) -> bool {
+    !path.is_empty()
+        && path.split('.').all(|segment| {
+            let (name, indexes) = segment.split_at(segment.find('[').unwrap_or(segment.len()));
+            let name_ok = name
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+            let indexes_ok = indexes.is_empty()
+                || indexes
+                    .strip_prefix('[')
+                    .and_then(|inner| inner.strip_suffix(']'))
+                    .is_some_and(|inner| {
+                        inner.split("][").all(|index| {
+                            !index.is_empty() && index.chars().all(|c| c.is_ascii_digit())
+                        })
+                    });
+            name_ok && indexes_ok
+        })
 }
 
-fn render_page(fi_name: &str, page: &ResolvedPageBinding) -> Result<Value, ManifestRenderError> {
-    let page_id = page_id_for_suffix(fi_name, page.placement, &page.route_suffix);
-
-    match page.page.type_ {
-        PageType::Iframe => {
-            let iframe =
-                page.page
-                    .iframe
-                    .as_ref()
-                    .ok_or_else(|| ManifestRenderError::InvalidPageShape {
-                        fi_name: fi_name.to_string(),
-                        key: page.page.key.clone(),
-                        message: "type=iframe requires iframe config".to_string(),
-                    })?;
-            Ok(iframe_page(&page_id, &page.title, &iframe.src))
+fn validate_filters(
+    fi_name: &str,
+    page_key: &str,
+    filters: &[FilterSpec],
+) -> Result<(), ManifestRenderError> {
+    let mut seen = HashSet::new();
+    for filter in filters {
+        let invalid = |message: String| ManifestRenderError::InvalidFilter {
+            fi_name: fi_name.to_string(),
+            key: page_key.to_string(),
+            filter: filter.key.clone(),
+            message,
+        };
+        if !is_json_field_path(&filter.key) {
+            return Err(invalid(
+                "key must be a field path such as 'status.phase'".to_string(),
+            ));
         }
-        PageType::CrdTable => {
-            let crd_table = page.page.crd_table.as_ref().ok_or_else(|| {
-                ManifestRenderError::InvalidPageShape {
-                    fi_name: fi_name.to_string(),
-                    key: page.page.key.clone(),
-                    message: "type=crdTable requires crdTable config".to_string(),
+        if !seen.insert(filter.key.as_str()) {
+            return Err(invalid("key is declared more than once".to_string()));
+        }
+        if filter.label.trim().is_empty() {
+            return Err(invalid("label must not be empty".to_string()));
+        }
+        match filter.type_ {
+            FilterType::Select if filter.options.is_empty() => {
+                return Err(invalid("type=select requires options".to_string()));
+            }
+            FilterType::Select => {
+                let mut options = HashSet::new();
+                if let Some(option) = filter
+                    .options
+                    .iter()
+                    .find(|option| option.trim().is_empty() || !options.insert(option.as_str()))
+                {
+                    return Err(invalid(format!(
+                        "option '{option}' is empty or declared more than once"
+                    )));
                 }
-            })?;
-            Ok(crd_page(
-                &page_id,
-                &page.title,
-                page.placement,
-                crd_table,
-                &crd_table.columns,
-            ))
+            }
+            FilterType::LabelSelector if !is_map_field_path(&filter.key) => {
+                return Err(invalid(
+                    "type=labelSelector requires a labels or selector map path".to_string(),
+                ));
+            }
+            FilterType::Text | FilterType::LabelSelector => {}
         }
     }
+    Ok(())
 }
 
-fn page_meta(page_id: &str, title: &str) -> Value {
-    json!({
-      "id": page_id,
-      "name": page_id,
-      "title": title,
-      "path": format!("/{}", page_id),
-    })
-}
-
-fn iframe_page(page_id: &str, display_name: &str, frame_src: &str) -> Value {
-    json!({
-      "id": page_id,
-      "entryComponent": page_id,
-      "componentsTree": {
-        "meta": page_meta(page_id, display_name),
-        "context": {},
-        "root": {
-          "id": format!("{}-root", page_id),
+fn validate_table_settings(
+    fi_name: &str,
+    page_key: &str,
+    crd_table: &CrdTablePageSpec,
+    table: &TableSpec,
+) -> Result<(), ManifestRenderError> {
+    if let Some(page_size) = table.page_size
+        && !CRD_TABLE_PAGE_SIZE.contains(&page_size)
+    {
+        return Err(ManifestRenderError::InvalidPageShape {
+            fi_name: fi_name.to_string(),
+            key: page_key.to_string(),
+            message: format!(
+                "crdTable.table.pageSize must be between {} and {}, got {page_size}",
+                CRD_TABLE_PAGE_SIZE.start(),
+                CRD_TABLE_PAGE_SIZE.end()
+            ),
+        });
+    }
+    let Some(sort_key) = table.default_sort_key.as_deref() else {
+        if table.default_sort_order.is_some() {
+            return Err(ManifestRenderError::InvalidPageShape {
+                fi_name: fi_name.to_string(),
+                key: page_key.to_string(),
+                message: "crdTable.table.defaultSortOrder requires defaultSortKey".to_string(),
+            });
+        }
+        return Ok(());
+    };
+    let invalid = |message: String| ManifestRenderError::InvalidDefaultSort {
+        fi_name: fi_name.to_string(),
+        key: page_key.to_string(),
+        column: sort_key.to_string(),
+        message,
+    };
+    let Some(column) = crd_table
+        .columns
+        .iter()
+        .find(|column| column.key == sort_key)
+    else {
+        let declared = crd_table
+            .columns
+            .iter()
+            .map(|column| column.key.as_str())
+            .collect::<Vec<_>>();
+        return Err(invalid(format!(
+            "is not a declared column (declared: {})",
+            declared.join(", ")
+        )));
+    };
+    if column.enable_sorting == Some(false) {
+        return Err(invalid(
+            "refers to a column with enableSorting: false".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn is_map_render_type(render_type: &ColumnRenderType) -> bool {
+    matches!(
+        render_type,
+        ColumnRenderType::Labels | ColumnRenderType::Annotations
+    )
+}
+
+// Without the CRD schema the map check is by name: the last path segment must be a
+// well-known string map such as `metadata.labels` or `spec.selector.matchLabels`.
+fn is_map_field_path(path: &str) -> bool {
+    let last = path
+        .trim()
+        .rsplit('.')
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    !path.contains('[')
+        && ["labels", "annotations", "selector"]
+            .iter()
+            .any(|suffix| last.ends_with(suffix))
+}
+
+fn route_suffix_for_menu(key: &str) -> String {
+    key.to_string()
+}
+
+fn route_suffix_for_child(parent_key: &str, child_key: &str) -> String {
+    format!("{parent_key}/{child_key}")
+}
+
+// Primary paths are unique among themselves, but an earlier menu's alias may already claim one;
+// either way the console router would pick a page arbitrarily.
+fn route_aliases(
+    fi_name: &str,
+    key: &str,
+    placement: MenuPlacement,
+    primary: &str,
+    aliases: &[String],
+    route_paths: &mut HashSet<(&'static str, String)>,
+) -> Result<Vec<String>, ManifestRenderError> {
+    if !route_paths.insert((placement.route_prefix(), primary.to_string())) {
+        return Err(ManifestRenderError::DuplicateRoutePath {
+            fi_name: fi_name.to_string(),
+            key: key.to_string(),
+            path: primary.to_string(),
+        });
+    }
+    let mut resolved = Vec::with_capacity(aliases.len());
+    for alias in aliases {
+        let alias = routing_path(fi_name, key, alias.clone())?;
+        if !route_paths.insert((placement.route_prefix(), alias.clone())) {
+            return Err(ManifestRenderError::DuplicateRoutePath {
+                fi_name: fi_name.to_string(),
+                key: key.to_string(),
+                path: alias,
+            });
+        }
+        resolved.push(alias);
+    }
+    Ok(resolved)
+}
+
+// Keys are already kebab-case; this catches what only shows up once they are joined, like length.
+fn routing_path(fi_name: &str, key: &str, path: String) -> Result<String, ManifestRenderError> {
+    validate_routing_path(&path).map_err(|source| ManifestRenderError::InvalidRoutingPath {
+        fi_name: fi_name.to_string(),
+        key: key.to_string(),
+        path: path.clone(),
+        source,
+    })?;
+    Ok(path)
+}
+
+fn menu_name_for_suffix(fi_name: &str, suffix: &str) -> String {
+    format!("frontendintegrations/{fi_name}/{suffix}")
+}
+
+fn nested_menu_parent(parent: &str, menu_name: &str) -> String {
+    format!("{parent}.{menu_name}")
+}
+
+// Routes are registered per placement, so a menu can only move under groups of its own placement.
+fn menu_parent(
+    fi_name: &str,
+    key: &str,
+    placement: MenuPlacement,
+    parent: Option<&str>,
+) -> Result<String, ManifestRenderError> {
+    let Some(parent) = parent.map(str::trim) else {
+        return Ok(placement.as_str().to_string());
+    };
+    let valid = parent
+        .strip_prefix(placement.as_str())
+        .and_then(|rest| rest.strip_prefix('.'))
+        .is_some_and(|group| group.split('.').all(is_menu_group_segment));
+    if valid {
+        return Ok(parent.to_string());
+    }
+    Err(ManifestRenderError::InvalidMenuShape {
+        fi_name: fi_name.to_string(),
+        key: key.to_string(),
+        message: format!(
+            "parent '{parent}' must name a menu group under '{0}', e.g. '{0}.monitoring'",
+            placement.as_str()
+        ),
+    })
+}
+
+fn is_menu_group_segment(segment: &str) -> bool {
+    !segment.is_empty()
+        && segment
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '/'))
+}
+
+// Icons name console icon components such as `GridDuotone`; separators cover kebab-case and
+// prefixed names like `mdi:account-box`, but whitespace and markup are rejected.
+fn validate_menu_icon(
+    fi_name: &str,
+    key: &str,
+    icon: Option<&str>,
+) -> Result<(), ManifestRenderError> {
+    let Some(icon) = icon else {
+        return Ok(());
+    };
+    if icon.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && icon
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ':' | '/'))
+    {
+        return Ok(());
+    }
+    Err(ManifestRenderError::InvalidMenuShape {
+        fi_name: fi_name.to_string(),
+        key: key.to_string(),
+        message: format!("icon '{icon}' must be an icon component name such as 'GridDuotone'"),
+    })
+}
+
+// Resolved once per binding so routes and pages can never disagree on the id.
+fn page_id_for_suffix(
+    fi_name: &str,
+    placement: MenuPlacement,
+    suffix: &str,
+    options: &RenderOptions,
+) -> String {
+    let base = format!(
+        "{}-{}-{}",
+        fi_name,
+        placement.as_str(),
+        suffix.replace('/', "_")
+    );
+    match options.page_id_strategy.unwrap_or(DEFAULT_PAGE_ID_STRATEGY) {
+        PageIdStrategy::FiPlacement => base,
+        PageIdStrategy::NamespaceFiPlacement => format!("{}-{base}", options.page_id_namespace),
+        PageIdStrategy::HashBased => format!(
+            "{fi_name}-{:016x}",
+            fnv1a_64(format!("{}/{suffix}", placement.as_str()).as_bytes())
+        ),
+    }
+}
+
+// FNV-1a keeps hash-based ids stable across Rust releases, unlike DefaultHasher.
+fn fnv1a_64(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+// The primary route first, then one per alias; all of them open the same page.
+pub(super) fn render_routes(fi_name: &str, page: &ResolvedPageBinding) -> Vec<Value> {
+    std::iter::once(&page.route_suffix)
+        .chain(&page.route_aliases)
+        .map(|suffix| {
+            json!({
+                "path": format!(
+                    "{}{}",
+                    page.placement.route_prefix(),
+                    route_tail(fi_name, suffix)
+                ),
+                "pageId": page.page_id,
+            })
+        })
+        .collect()
+}
+
+pub(super) fn render_leaf_menu(page: &ResolvedPageBinding, default_icon: &str) -> Value {
+    let mut menu = json!({
+        "parent": page.parent,
+        "name": page.menu_name,
+        "title": page.title,
+        "icon": page.icon.as_deref().unwrap_or(default_icon),
+        "order": page.order,
+    });
+    if let Some(crd_table) = page.page.crd_table.as_ref() {
+        menu["authKey"] = json!(crd_auth_key(crd_table));
+    }
+    with_required_permissions(menu, &page.page.permissions)
+}
+
+pub(super) fn render_organization_menu(
+    menu: &ResolvedOrganizationMenu,
+    default_icon: &str,
+) -> Value {
+    json!({
+        "parent": menu.parent,
+        "name": menu.name,
+        "title": menu.title,
+        "icon": menu.icon.as_deref().unwrap_or(default_icon),
+        "order": menu.order,
+    })
+}
+
+// The frontend hides menus and pages whose requiredPermissions the viewer fails; absent means visible to all.
+fn with_required_permissions(mut node: Value, permissions: &[PermissionSpec]) -> Value {
+    if permissions.is_empty() {
+        return node;
+    }
+    let rendered = permissions
+        .iter()
+        .map(|permission| {
+            json!({
+                "group": permission.group.as_deref().unwrap_or(""),
+                "resource": permission.resource,
+                "verbs": permission.verbs,
+            })
+        })
+        .collect();
+    if let Some(object) = node.as_object_mut() {
+        object.insert("requiredPermissions".to_string(), Value::Array(rendered));
+    }
+    node
+}
+
+fn route_tail(fi_name: &str, suffix: &str) -> String {
+    format!("/frontendintegrations/{fi_name}/{suffix}")
+}
+
+pub(super) fn render_page(
+    fi_name: &str,
+    page: &ResolvedPageBinding,
+) -> Result<Value, ManifestRenderError> {
+    let rendered =
+        match page.page.type_ {
+            PageType::Iframe => {
+                let iframe = page.page.iframe.as_ref().ok_or_else(|| {
+                    ManifestRenderError::InvalidPageShape {
+                        fi_name: fi_name.to_string(),
+                        key: page.page.key.clone(),
+                        message: "type=iframe requires iframe config".to_string(),
+                    }
+                })?;
+                iframe_page(&page.page_id, &page.title, iframe)
+            }
+            PageType::Custom => {
+                let custom = page.page.custom.as_ref().ok_or_else(|| {
+                    ManifestRenderError::InvalidPageShape {
+                        fi_name: fi_name.to_string(),
+                        key: page.page.key.clone(),
+                        message: "type=custom requires custom config".to_string(),
+                    }
+                })?;
+                custom_page(&page.page_id, &page.title, custom)
+            }
+            PageType::CrdTable => {
+                let crd_table = page.page.crd_table.as_ref().ok_or_else(|| {
+                    ManifestRenderError::InvalidPageShape {
+                        fi_name: fi_name.to_string(),
+                        key: page.page.key.clone(),
+                        message: "type=crdTable requires crdTable config".to_string(),
+                    }
+                })?;
+                let columns = crd_table
+                    .columns
+                    .iter()
+                    .filter(|column| column.shown_in(page.placement))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                if columns.is_empty() {
+                    return Err(ManifestRenderError::MissingCrdColumns {
+                        fi_name: fi_name.to_string(),
+                        key: page.page.key.clone(),
+                        placement: Some(page.placement),
+                    });
+                }
+                crd_page(
+                    &page.page_id,
+                    &page.title,
+                    page.placement,
+                    crd_table,
+                    &columns,
+                )
+            }
+        };
+
+    Ok(with_required_permissions(rendered, &page.page.permissions))
+}
+
+fn page_meta(page_id: &str, title: &str) -> Value {
+    json!({
+      "id": page_id,
+      "name": page_id,
+      "title": title,
+      "path": format!("/{}", page_id),
+    })
+}
+
+// FRAME_URL stays for consoles that predate FRAME_URL_TEMPLATE and load it verbatim.
+fn iframe_page(page_id: &str, display_name: &str, iframe: &IframePageSpec) -> Value {
+    let frame_src = iframe.src.trim();
+    let mut props = Map::new();
+    props.insert("FRAME_URL".to_string(), json!(frame_src));
+    if !iframe_placeholders(frame_src).is_empty() {
+        props.insert("FRAME_URL_TEMPLATE".to_string(), json!(frame_src));
+    }
+    if let Some(sandbox) = iframe.sandbox.as_ref() {
+        props.insert("SANDBOX".to_string(), json!(sandbox.join(" ")));
+    }
+    if let Some(allow) = iframe.allow.as_ref() {
+        let directives = allow
+            .iter()
+            .map(|directive| directive.trim())
+            .collect::<Vec<_>>();
+        props.insert("ALLOW".to_string(), json!(directives.join("; ")));
+    }
+    if let Some(height) = iframe.height.as_ref() {
+        props.insert("HEIGHT".to_string(), json!(height));
+    }
+    if let Some(loading) = iframe.loading {
+        props.insert("LOADING".to_string(), json!(loading));
+    }
+    json!({
+      "id": page_id,
+      "entryComponent": page_id,
+      "componentsTree": {
+        "meta": page_meta(page_id, display_name),
+        "context": {},
+        "root": {
+          "id": format!("{}-root", page_id),
           "type": "Iframe",
-          "props": {
-            "FRAME_URL": frame_src,
-          },
+          "props": props,
           "meta": { "title": "Iframe", "scope": true }
         }
       }
     })
 }
 
+fn custom_page(page_id: &str, display_name: &str, custom: &CustomPageSpec) -> Value {
+    json!({
+      "id": page_id,
+      "entryComponent": page_id,
+      "componentsTree": {
+        "meta": page_meta(page_id, display_name),
+        "context": {},
+        "root": {
+          "id": format!("{}-root", page_id),
+          "type": custom.type_,
+          "props": custom.props.clone().unwrap_or_default(),
+          "meta": { "title": custom.type_, "scope": true }
+        }
+      }
+    })
+}
+
 fn crd_page(
     page_id: &str,
     display_name: &str,
@@ -461,10 +1571,10 @@ fn crd_page(
 ) -> Value {
     let columns_config = transform_columns(columns);
     let page_state_type = crd_page_state_type(placement);
-    let page_state_config = crd_page_state_config(page_id, placement, crd);
+    let page_state_config = crd_page_state_config(page_id, placement, crd, columns);
     let auth_key = crd.auth_key.as_deref().unwrap_or("");
 
-    json!({
+    let mut page = json!({
       "id": page_id,
       "entryComponent": page_id,
       "componentsTree": {
@@ -515,7 +1625,64 @@ fn crd_page(
           "meta": { "title": "CrdTable", "scope": true }
         }
       }
-    })
+    });
+    if let Some(actions) = crd.actions.as_ref() {
+        apply_crd_actions(&mut page, actions);
+    }
+    page["permissions"] = json!([{
+        "group": crd.group,
+        "resource": crd.names.plural,
+        "verbs": crd_required_verbs(crd),
+    }]);
+    page
+}
+
+// The console checks menu and page access against this key; the plural is what KubeSphere
+// role templates use when no dedicated key is registered.
+fn crd_auth_key(crd: &CrdTablePageSpec) -> &str {
+    crd.auth_key
+        .as_deref()
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+        .unwrap_or(&crd.names.plural)
+}
+
+fn crd_required_verbs(crd: &CrdTablePageSpec) -> Vec<&'static str> {
+    let mut verbs = vec!["get", "list", "watch"];
+    let actions = crd.actions.clone().unwrap_or_default();
+    if actions.create() {
+        verbs.push("create");
+    }
+    if actions.edit() {
+        verbs.push("update");
+    }
+    if actions.delete() || actions.batch_delete() {
+        verbs.push("delete");
+    }
+    verbs
+}
+
+// Pages without `actions` keep their original shape so existing manifests hash the same.
+fn apply_crd_actions(page: &mut Value, actions: &CrdActionsSpec) {
+    if let Some(props) = page["componentsTree"]["root"]["props"].as_object_mut() {
+        if !actions.create() {
+            props.remove("CREATE");
+            props.remove("CREATE_INITIAL_VALUE");
+        }
+        if !actions.edit() {
+            props.remove("UPDATE");
+        }
+        // Batch deletion goes through the same binding as single-row deletion.
+        if !actions.delete() && !actions.batch_delete() {
+            props.remove("DEL");
+        }
+    }
+    page["capabilities"] = json!({
+        "create": actions.create(),
+        "edit": actions.edit(),
+        "delete": actions.delete(),
+        "batchDelete": actions.batch_delete(),
+    });
 }
 
 fn crd_create_initial_value(crd: &CrdTablePageSpec) -> Value {
@@ -547,26 +1714,96 @@ fn crd_page_state_type(placement: MenuPlacement) -> &'static str {
     }
 }
 
-fn crd_page_state_config(page_id: &str, placement: MenuPlacement, crd: &CrdTablePageSpec) -> Value {
+fn crd_page_state_config(
+    page_id: &str,
+    placement: MenuPlacement,
+    crd: &CrdTablePageSpec,
+    columns: &[ColumnSpec],
+) -> Value {
     let mut config = Map::new();
     config.insert("PAGE_ID".to_string(), json!(page_id));
     config.insert("CRD_CONFIG".to_string(), crd_page_config(crd));
-    if placement != MenuPlacement::Workspace {
-        config.insert("SCOPE".to_string(), json!(crd_page_scope(crd)));
+    match placement {
+        MenuPlacement::Workspace => {}
+        // The console fills in the namespace from the project route.
+        MenuPlacement::Project => {
+            config.insert("SCOPE".to_string(), json!("project"));
+        }
+        _ => {
+            config.insert("SCOPE".to_string(), json!(crd_page_scope(crd)));
+        }
+    }
+    if let Some(interval) = crd.refresh_interval_seconds {
+        config.insert("REFRESH_INTERVAL".to_string(), json!(interval));
+    }
+    if let Some(table) = crd.table.as_ref() {
+        // A sort column hidden in this placement would sort by something the user cannot see.
+        if let Some(sort_key) = table.default_sort_key.as_deref()
+            && columns.iter().any(|column| column.key == sort_key)
+        {
+            config.insert(
+                "DEFAULT_SORT".to_string(),
+                json!({
+                    "key": sort_key,
+                    "order": table.default_sort_order.unwrap_or(DEFAULT_SORT_ORDER),
+                }),
+            );
+        }
+        if let Some(page_size) = table.page_size {
+            config.insert("PAGE_SIZE".to_string(), json!(page_size));
+        }
     }
+    config.insert(
+        "FILTERS_CONFIG".to_string(),
+        crd_filters_config(&crd.filters),
+    );
     config.insert("HOOK_NAME".to_string(), json!("useCrdPageState"));
     Value::Object(config)
 }
 
+fn crd_filters_config(filters: &[FilterSpec]) -> Value {
+    if filters.is_empty() {
+        return json!([{
+            "key": DEFAULT_FILTER_KEY,
+            "label": DEFAULT_FILTER_LABEL,
+            "type": FilterType::Text,
+        }]);
+    }
+    Value::Array(
+        filters
+            .iter()
+            .map(|filter| {
+                let mut config = json!({
+                    "key": filter.key,
+                    "label": filter.label,
+                    "type": filter.type_,
+                });
+                if filter.type_ == FilterType::Select {
+                    config["options"] = json!(filter.options);
+                }
+                config
+            })
+            .collect(),
+    )
+}
+
 fn crd_page_config(crd: &CrdTablePageSpec) -> Value {
     let mut config = Map::new();
     config.insert("apiVersion".to_string(), json!(crd.version));
     config.insert("plural".to_string(), json!(crd.names.plural));
     config.insert("group".to_string(), json!(crd.group));
     config.insert("kapi".to_string(), json!(true));
+    config.insert("authKey".to_string(), json!(crd_auth_key(crd)));
+    config.insert("RESOURCE_SCOPE".to_string(), json!(crd.scope));
     if let Some(kind) = crd.names.kind.as_ref() {
         config.insert("kind".to_string(), json!(kind));
     }
+    if let Some(selector) = crd.label_selector.as_deref() {
+        config.insert("labelSelector".to_string(), json!(selector.trim()));
+    }
+    if let Some(selector) = crd.field_selector.as_deref() {
+        config.insert("fieldSelector".to_string(), json!(selector.trim()));
+    }
     Value::Object(config)
 }
 
@@ -588,8 +1825,114 @@ fn transform_columns(columns: &[ColumnSpec]) -> Vec<Value> {
             if let Some(pattern) = &col.render.pattern {
                 payload.insert("pattern".to_string(), json!(pattern));
             }
-            if let Some(link) = &col.render.link {
-                payload.insert("link".to_string(), json!(link));
+            match &col.render.link {
+                Some(ColumnLink::Plain(link)) => {
+                    payload.insert("link".to_string(), json!(link));
+                }
+                // Templates were checked in validate_page_shape, so params cannot fail here.
+                Some(ColumnLink::Spec(link)) => {
+                    let mut out = Map::new();
+                    out.insert("template".to_string(), json!(link.template));
+                    out.insert(
+                        "params".to_string(),
+                        json!(link_template_params(&link.template).unwrap_or_default()),
+                    );
+                    let external = link.external.unwrap_or(false);
+                    out.insert("external".to_string(), json!(external));
+                    // External links open in a new tab unless a target is given; the opened page
+                    // must not get a handle back to the console window.
+                    match (&link.target, external) {
+                        (Some(target), _) => {
+                            out.insert("target".to_string(), json!(target));
+                        }
+                        (None, true) => {
+                            out.insert("target".to_string(), json!("_blank"));
+                        }
+                        (None, false) => {}
+                    }
+                    if external {
+                        out.insert("rel".to_string(), json!("noopener"));
+                    }
+                    payload.insert("link".to_string(), Value::Object(out));
+                }
+                None => {}
+            }
+            if col.render.copyable == Some(true)
+                && matches!(
+                    col.render.type_,
+                    ColumnRenderType::Text | ColumnRenderType::Link
+                )
+            {
+                payload.insert("copyable".to_string(), json!(true));
+            }
+            if is_map_render_type(&col.render.type_) {
+                if !col.render.include_keys.is_empty() {
+                    payload.insert("includeKeys".to_string(), json!(col.render.include_keys));
+                }
+                if !col.render.exclude_keys.is_empty() {
+                    payload.insert("excludeKeys".to_string(), json!(col.render.exclude_keys));
+                }
+            }
+            if col.render.type_ == ColumnRenderType::Time {
+                if col.render.format.is_none() && col.render.pattern.is_none() {
+                    payload.insert("format".to_string(), json!(DEFAULT_TIME_FORMAT));
+                }
+                if col.render.relative == Some(true) {
+                    payload.insert("relative".to_string(), json!(true));
+                    payload.insert(
+                        "tooltipFormat".to_string(),
+                        json!(
+                            col.render
+                                .tooltip_format
+                                .as_deref()
+                                .unwrap_or(DEFAULT_TIME_FORMAT)
+                        ),
+                    );
+                } else if let Some(tooltip_format) = &col.render.tooltip_format {
+                    payload.insert("tooltipFormat".to_string(), json!(tooltip_format));
+                }
+            }
+            // Checked in validate_page_shape like link templates.
+            if col.render.type_ == ColumnRenderType::Template
+                && let Some(template) = &col.render.template
+            {
+                payload.insert("template".to_string(), json!(template));
+                payload.insert(
+                    "params".to_string(),
+                    json!(link_template_params(template).unwrap_or_default()),
+                );
+            }
+            if col.render.type_ == ColumnRenderType::Number {
+                if let Some(unit) = &col.render.unit {
+                    payload.insert("unit".to_string(), json!(unit));
+                }
+                if let Some(precision) = col.render.precision {
+                    payload.insert("precision".to_string(), json!(precision));
+                }
+                if let Some(downscale) = col.render.downscale {
+                    payload.insert("downscale".to_string(), json!(downscale));
+                }
+            }
+            // The component always gets a props object, even when the spec leaves it out.
+            if col.render.type_ == ColumnRenderType::Custom {
+                payload
+                    .entry("props".to_string())
+                    .or_insert_with(|| json!({}));
+            }
+            if col.render.type_ == ColumnRenderType::Status {
+                payload.insert(
+                    "statusMap".to_string(),
+                    json!(col.render.status_map.clone().unwrap_or_default()),
+                );
+                payload.insert(
+                    "defaultIntent".to_string(),
+                    json!(
+                        col.render
+                            .default_intent
+                            .as_deref()
+                            .unwrap_or(DEFAULT_STATUS_INTENT)
+                    ),
+                );
             }
 
             let mut out = Map::new();
@@ -609,6 +1952,19 @@ fn transform_columns(columns: &[ColumnSpec]) -> Vec<Value> {
             if let Some(v) = col.enable_hiding {
                 out.insert("enableHiding".to_string(), json!(v));
             }
+            // Evaluated by the frontend against the current user; omitted means visible to all.
+            if !col.visible_to_roles.is_empty() {
+                out.insert("visibleToRoles".to_string(), json!(col.visible_to_roles));
+            }
+            if let Some(width) = &col.width {
+                out.insert("width".to_string(), json!(width));
+            }
+            if let Some(align) = col.align {
+                out.insert("align".to_string(), json!(align));
+            }
+            if let Some(v) = col.default_hidden {
+                out.insert("defaultHidden".to_string(), json!(v));
+            }
             Value::Object(out)
         })
         .collect()
@@ -623,6 +1979,12 @@ fn render_type_str(t: &ColumnRenderType) -> &'static str {
         ColumnRenderType::Text => "text",
         ColumnRenderType::Time => "time",
         ColumnRenderType::Link => "link",
+        ColumnRenderType::Status => "status",
+        ColumnRenderType::Labels => "labels",
+        ColumnRenderType::Annotations => "annotations",
+        ColumnRenderType::Number => "number",
+        ColumnRenderType::Template => "template",
+        ColumnRenderType::Custom => "custom",
     }
 }
 
@@ -643,10 +2005,10 @@ fn render_locales(spec: &FrontendIntegrationSpec) -> Value {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use frontend_forge_api::{ColumnAlign, IframeLoading, IntegrationMetadata, RoutingPathError};
 
-    #[test]
-    fn renders_workspace_crd_pages_with_workspace_page_state() {
-        let fi: FrontendIntegration = serde_yaml::from_str(
+    fn placement_columns_fi(namespace_placements: &str) -> FrontendIntegration {
+        serde_yaml::from_str(&format!(
             r#"
 apiVersion: frontend-forge.kubesphere.io/v1alpha1
 kind: FrontendIntegration
@@ -654,32 +2016,141 @@ metadata:
   name: test
 spec:
   menus:
-    - displayName: Cluster Tasks
-      key: cluster-tasks
+    - displayName: Tasks
+      key: tasks
       placement: cluster
       type: page
-    - displayName: Workspace Tasks
-      key: workspace-tasks
+    - displayName: Ops
+      key: ops
       placement: workspace
-      type: page
+      type: organization
+      children:
+        - displayName: Tasks
+          key: tasks
   pages:
-    - key: cluster-tasks
+    - key: tasks
       type: crdTable
       crdTable:
         names:
-          plural: serviceaccounts
-          kind: ServiceAccount
-        version: v1alpha1
-        group: kubesphere.io
+          plural: inspecttasks
+        version: v1alpha2
+        group: kubeeye.kubesphere.io
         scope: Namespaced
+        table:
+          defaultSortKey: namespace
         columns:
+          - key: namespace
+            title: NAMESPACE
+{namespace_placements}
+            render:
+              type: text
+              path: metadata.namespace
           - key: name
             title: NAME
-            enableSorting: true
             render:
               type: text
               path: metadata.name
-    - key: workspace-tasks
+"#
+        ))
+        .unwrap()
+    }
+
+    fn column_keys(page: &Value) -> Vec<&str> {
+        page["componentsTree"]["dataSources"][0]["config"]["COLUMNS_CONFIG"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|column| column["key"].as_str().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn columns_are_filtered_per_placement() {
+        let manifest = render_v1_manifest(
+            &placement_columns_fi("            placements: [cluster]"),
+            &RenderOptions::default(),
+        )
+        .unwrap();
+        let pages = manifest["pages"].as_array().unwrap();
+        // Workspace pages render before cluster pages.
+        assert_eq!(column_keys(&pages[0]), ["name"]);
+        assert_eq!(column_keys(&pages[1]), ["namespace", "name"]);
+        let sort = |page: &Value| {
+            page["componentsTree"]["dataSources"][1]["config"]["DEFAULT_SORT"].clone()
+        };
+        assert!(sort(&pages[0]).is_null());
+        assert_eq!(sort(&pages[1])["key"], "namespace");
+
+        // Listing every placement the page is mounted in is the same as listing none.
+        let unrestricted =
+            render_v1_manifest(&placement_columns_fi(""), &RenderOptions::default()).unwrap();
+        let everywhere = render_v1_manifest(
+            &placement_columns_fi("            placements: [cluster, workspace]"),
+            &RenderOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(unrestricted, everywhere);
+        // Unused, the field stays out of the serialized spec, so spec hashes do not move.
+        let spec = serde_json::to_value(&placement_columns_fi("").spec).unwrap();
+        assert!(
+            spec["pages"][0]["crdTable"]["columns"][0]
+                .get("placements")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn rejects_placements_left_without_columns() {
+        let mut fi = placement_columns_fi("            placements: [cluster]");
+        fi.spec.pages[0].crd_table.as_mut().unwrap().columns[1].placements =
+            vec![MenuPlacement::Cluster];
+        let err = render_v1_manifest(&fi, &RenderOptions::default()).unwrap_err();
+        assert!(matches!(
+            err,
+            ManifestRenderError::MissingCrdColumns {
+                placement: Some(MenuPlacement::Workspace),
+                ..
+            }
+        ));
+        assert!(err.to_string().ends_with("in placement workspace"), "{err}");
+    }
+
+    #[test]
+    fn renders_workspace_crd_pages_with_workspace_page_state() {
+        let fi: FrontendIntegration = serde_yaml::from_str(
+            r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: test
+spec:
+  menus:
+    - displayName: Cluster Tasks
+      key: cluster-tasks
+      placement: cluster
+      type: page
+    - displayName: Workspace Tasks
+      key: workspace-tasks
+      placement: workspace
+      type: page
+  pages:
+    - key: cluster-tasks
+      type: crdTable
+      crdTable:
+        names:
+          plural: serviceaccounts
+          kind: ServiceAccount
+        version: v1alpha1
+        group: kubesphere.io
+        scope: Namespaced
+        columns:
+          - key: name
+            title: NAME
+            enableSorting: true
+            render:
+              type: text
+              path: metadata.name
+    - key: workspace-tasks
       type: crdTable
       crdTable:
         names:
@@ -699,11 +2170,11 @@ spec:
         )
         .unwrap();
 
-        let manifest = render_v1_manifest(&fi).unwrap();
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
         assert_eq!(manifest["locales"], json!([]));
         let pages = manifest["pages"].as_array().unwrap();
 
-        let cluster_page_state = &pages[0]["componentsTree"]["dataSources"][1];
+        let cluster_page_state = &pages[1]["componentsTree"]["dataSources"][1];
         assert_eq!(cluster_page_state["type"], "crd-page-state");
         assert_eq!(
             cluster_page_state["config"]["PAGE_ID"],
@@ -711,7 +2182,7 @@ spec:
         );
         assert_eq!(cluster_page_state["config"]["SCOPE"], "namespace");
 
-        let workspace_page_state = &pages[1]["componentsTree"]["dataSources"][1];
+        let workspace_page_state = &pages[0]["componentsTree"]["dataSources"][1];
         assert_eq!(workspace_page_state["type"], "workspace-crd-page-state");
         assert_eq!(
             workspace_page_state["config"]["PAGE_ID"],
@@ -752,7 +2223,7 @@ spec:
         )
         .unwrap();
 
-        let manifest = render_v1_manifest(&fi).unwrap();
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
         assert_eq!(manifest["displayName"], "demo-fi");
         assert_eq!(
             manifest["locales"],
@@ -781,101 +2252,227 @@ spec:
         );
     }
 
-    #[test]
-    fn renders_nested_org_menu_bindings() {
-        let fi: FrontendIntegration = serde_yaml::from_str(
+    fn localized_fi(display_name: &str, column_title: &str) -> FrontendIntegration {
+        serde_yaml::from_str(&format!(
             r#"
 apiVersion: frontend-forge.kubesphere.io/v1alpha1
 kind: FrontendIntegration
 metadata:
   name: demo-fi
 spec:
-  displayName: Demo
+  displayName: "{display_name}"
+  locales:
+    en:
+      menu.ops: Operations
+      menu.tasks.with.a.very.long.key: Inspect Tasks
+    zh:
+      menu.ops: 运维
+      column.name: 名称
   menus:
-    - displayName: Ops
+    - displayName: "{{{{menu.ops}}}}"
       key: ops
-      icon: Folder
-      placement: workspace
+      placement: cluster
       type: organization
       children:
-        - displayName: Inspect Tasks
+        - displayName: "{{{{menu.tasks.with.a.very.long.key}}}}"
           key: inspecttasks
-        - displayName: Ops Guide
-          key: ops-guide
-          icon: File
   pages:
     - key: inspecttasks
       type: crdTable
       crdTable:
         names:
           plural: inspecttasks
-          kind: InspectTask
         group: kubeeye.kubesphere.io
         version: v1alpha2
         scope: Cluster
         columns:
           - key: name
-            title: NAME
+            title: "{column_title}"
             render:
               type: text
               path: metadata.name
-    - key: ops-guide
-      type: iframe
-      iframe:
-        src: http://example.test/ops-guide
-"#,
-        )
-        .unwrap();
+"#
+        ))
+        .unwrap()
+    }
 
-        let manifest = render_v1_manifest(&fi).unwrap();
-        let menus = manifest["menus"].as_array().unwrap();
-        let routes = manifest["routes"].as_array().unwrap();
-        let pages = manifest["pages"].as_array().unwrap();
+    #[test]
+    fn locale_references_are_kept_verbatim_when_every_key_exists() {
+        let fi = localized_fi("{{ app.title }}", "{{column.name}}");
+        let mut fi_with_title = fi.clone();
+        fi_with_title
+            .spec
+            .locales
+            .get_mut("en")
+            .unwrap()
+            .insert("app.title".to_string(), "Demo".to_string());
+        let options = RenderOptions {
+            menu_title_max_chars: Some(11),
+            ..RenderOptions::default()
+        };
 
-        assert_eq!(menus.len(), 3);
-        assert_eq!(routes.len(), 2);
-        assert_eq!(pages.len(), 2);
-        assert_eq!(menus[0]["name"], "frontendintegrations/demo-fi/ops");
-        assert_eq!(menus[0]["parent"], "workspace");
-        assert_eq!(menus[0]["icon"], "Folder");
+        let manifest = render_v1_manifest(&fi_with_title, &options).unwrap();
+        assert_eq!(manifest["displayName"], "{{ app.title }}");
+        assert_eq!(manifest["menus"][0]["title"], "{{menu.ops}}");
+        // References are exempt from title truncation.
         assert_eq!(
-            menus[1]["parent"],
-            "workspace.frontendintegrations/demo-fi/ops"
+            manifest["menus"][1]["title"],
+            "{{menu.tasks.with.a.very.long.key}}"
         );
         assert_eq!(
-            menus[1]["name"],
-            "frontendintegrations/demo-fi/ops/inspecttasks"
+            manifest["pages"][0]["componentsTree"]["dataSources"][0]["config"]["COLUMNS_CONFIG"][0]
+                ["title"],
+            "{{column.name}}"
         );
-        assert_eq!(menus[1]["icon"], "GridDuotone");
+
+        // Plain strings, including brace-like text, need no locale entry.
+        let mut plain = localized_fi("Demo {{", "{{}}");
+        plain.spec.menus[0].display_name = "Ops".to_string();
+        assert!(render_v1_manifest(&plain, &RenderOptions::default()).is_ok());
+    }
+
+    #[test]
+    fn rejects_locale_references_missing_from_every_locale() {
+        let err = render_v1_manifest(
+            &localized_fi("{{app.title}}", "{{column.namespace}}"),
+            &RenderOptions::default(),
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ManifestRenderError::MissingLocaleKeys { ref keys, .. }
+                if keys == &["app.title".to_string(), "column.namespace".to_string()]
+        ));
         assert_eq!(
-            menus[2]["parent"],
-            "workspace.frontendintegrations/demo-fi/ops"
+            err.to_string(),
+            "FrontendIntegration demo-fi references locale keys missing from every spec.locales entry: app.title, column.namespace"
         );
+    }
+
+    #[test]
+    fn prefers_spec_description_over_annotation() {
+        let fi: FrontendIntegration = serde_yaml::from_str(
+            r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: demo-fi
+  annotations:
+    kubesphere.io/description: From annotation
+spec:
+  description: From spec
+  menus:
+    - displayName: Overview
+      key: overview
+      placement: cluster
+      type: page
+  pages:
+    - key: overview
+      type: iframe
+      iframe:
+        src: http://example.test
+"#,
+        )
+        .unwrap();
+
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        assert_eq!(manifest["description"], "From spec");
+
+        let mut legacy = fi.clone();
+        legacy.spec.description = None;
+        let manifest = render_v1_manifest(&legacy, &RenderOptions::default()).unwrap();
+        assert_eq!(manifest["description"], "From annotation");
+
+        let mut without = legacy.clone();
+        without.metadata.annotations = None;
+        let manifest = render_v1_manifest(&without, &RenderOptions::default()).unwrap();
+        assert!(manifest.get("description").is_none());
+    }
+
+    #[test]
+    fn renders_builder_output_into_the_build_section() {
+        let build = |output: &str| {
+            let mut fi = single_column_fi("{type: text, path: metadata.name}");
+            fi.spec.builder = serde_yaml::from_str(&format!("{{output: {output}}}")).unwrap();
+            render_v1_manifest(&fi, &RenderOptions::default())
+                .map(|manifest| manifest["build"].clone())
+        };
+        let module = |build: Value| build["moduleName"].clone();
+        let default_build = build("{}").unwrap();
+        let module_name = module(default_build.clone());
+
         assert_eq!(
-            menus[2]["name"],
-            "frontendintegrations/demo-fi/ops/ops-guide"
+            default_build,
+            json!({"target": "kubesphere-extension", "moduleName": module_name, "systemjs": true})
         );
-        assert_eq!(menus[2]["icon"], "File");
         assert_eq!(
-            routes[0]["path"],
-            "/workspaces/:workspace/frontendintegrations/demo-fi/ops/inspecttasks"
+            build("{format: esm, externals: [react, react-dom/client, '@kubed/components'], minify: true}")
+                .unwrap(),
+            json!({
+                "target": "kubesphere-extension",
+                "moduleName": module_name,
+                "format": "esm",
+                "systemjs": false,
+                "externals": ["react", "react-dom/client", "@kubed/components"],
+                "minify": true
+            })
         );
-        assert_eq!(routes[0]["pageId"], "demo-fi-workspace-ops_inspecttasks");
         assert_eq!(
-            routes[1]["path"],
-            "/workspaces/:workspace/frontendintegrations/demo-fi/ops/ops-guide"
+            build("{format: systemjs, minify: false}").unwrap(),
+            json!({
+                "target": "kubesphere-extension",
+                "moduleName": module_name,
+                "format": "systemjs",
+                "systemjs": true,
+                "minify": false
+            })
         );
-        assert_eq!(routes[1]["pageId"], "demo-fi-workspace-ops_ops-guide");
-        assert_eq!(pages[0]["componentsTree"]["meta"]["title"], "Inspect Tasks");
+
+        for output in [
+            "{externals: ['']}",
+            "{externals: [React]}",
+            "{externals: ['./local']}",
+            "{externals: ['lodash es']}",
+            "{externals: [react, react]}",
+        ] {
+            assert!(
+                matches!(
+                    build(output),
+                    Err(ManifestRenderError::InvalidBuildOutput { .. })
+                ),
+                "{output}"
+            );
+        }
+    }
+
+    #[test]
+    fn emits_spec_metadata_under_meta_only_when_set() {
+        let mut fi = single_column_fi("{type: text, path: metadata.name}");
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        assert!(manifest.get("meta").is_none());
+
+        fi.spec.metadata = Some(IntegrationMetadata::default());
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        assert!(manifest.get("meta").is_none());
+
+        fi.spec.metadata = serde_yaml::from_str(
+            "{tags: [ops, batch], owner: platform-team, docsUrl: 'https://docs.example.test/tasks'}",
+        )
+        .unwrap();
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
         assert_eq!(
-            pages[0]["componentsTree"]["dataSources"][1]["type"],
-            "workspace-crd-page-state"
+            manifest["meta"],
+            json!({
+                "tags": ["ops", "batch"],
+                "owner": "platform-team",
+                "docsUrl": "https://docs.example.test/tasks"
+            })
         );
-        assert_eq!(pages[1]["componentsTree"]["meta"]["title"], "Ops Guide");
     }
 
     #[test]
-    fn allows_reusing_page_keys_across_cluster_and_workspace() {
+    fn uses_configured_default_icon_for_menus_without_icon() {
         let fi: FrontendIntegration = serde_yaml::from_str(
             r#"
 apiVersion: frontend-forge.kubesphere.io/v1alpha1
@@ -884,40 +2481,16 @@ metadata:
   name: demo-fi
 spec:
   menus:
-    - displayName: Cluster Ops
-      key: cluster-ops
-      placement: cluster
-      type: organization
-      children:
-        - displayName: Global Rule Groups
-          key: globalrulegroups
-        - displayName: Guide
-          key: guide
-    - displayName: Workspace Ops
-      key: workspace-ops
+    - displayName: Ops
+      key: ops
       placement: workspace
       type: organization
       children:
-        - displayName: Global Rule Groups
-          key: globalrulegroups
         - displayName: Guide
           key: guide
+          icon: File
+          type: page
   pages:
-    - key: globalrulegroups
-      type: crdTable
-      crdTable:
-        names:
-          plural: globalrulegroups
-          kind: GlobalRuleGroup
-        group: alerting.kubesphere.io
-        version: v2beta1
-        scope: Cluster
-        columns:
-          - key: name
-            title: NAME
-            render:
-              type: text
-              path: metadata.name
     - key: guide
       type: iframe
       iframe:
@@ -925,95 +2498,1929 @@ spec:
 "#,
         )
         .unwrap();
+        let options = RenderOptions {
+            default_menu_icon: Some("Cluster".to_string()),
+            ..Default::default()
+        };
 
-        let manifest = render_v1_manifest(&fi).unwrap();
-        let routes = manifest["routes"].as_array().unwrap();
-        let pages = manifest["pages"].as_array().unwrap();
+        let manifest = render_v1_manifest(&fi, &options).unwrap();
 
-        assert_eq!(routes.len(), 4);
-        assert_eq!(pages.len(), 4);
+        assert_eq!(manifest["menus"][0]["icon"], "Cluster");
+        assert_eq!(manifest["menus"][1]["icon"], "File");
+    }
+
+    fn titled_fi(title: &str) -> FrontendIntegration {
+        let mut fi: FrontendIntegration = serde_yaml::from_str(
+            r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: demo-fi
+spec:
+  menus:
+    - displayName: placeholder
+      key: overview
+      placement: cluster
+      type: page
+  pages:
+    - key: overview
+      type: iframe
+      iframe:
+        src: http://example.test
+"#,
+        )
+        .unwrap();
+        fi.spec.menus[0].display_name = title.to_string();
+        fi
+    }
+
+    #[test]
+    fn strips_control_characters_from_menu_titles() {
+        let fi = titled_fi("  Ops\nOverview\u{7}\t ");
+
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+
+        assert_eq!(manifest["menus"][0]["title"], "Ops Overview");
         assert_eq!(
-            routes[0]["pageId"],
-            "demo-fi-cluster-cluster-ops_globalrulegroups"
+            manifest["pages"][0]["componentsTree"]["meta"]["title"],
+            "Ops Overview"
+        );
+    }
+
+    #[test]
+    fn rejects_iframe_sources_that_are_not_http_urls() {
+        for src in [
+            "javascript:alert(1)",
+            "data:text/html,<script>alert(1)</script>",
+            "file:///etc/passwd",
+        ] {
+            let mut fi = titled_fi("Overview");
+            fi.spec.pages[0].iframe.as_mut().unwrap().src = src.to_string();
+
+            assert!(
+                matches!(
+                    render_v1_manifest(&fi, &RenderOptions::default()),
+                    Err(ManifestRenderError::InvalidIframeSrc { .. })
+                ),
+                "{src} should be rejected"
+            );
+        }
+
+        // Relative sources load from the console's own origin and are accepted as before.
+        for src in ["/relative/path", "proxy/grafana/d/abc?orgId=1"] {
+            let mut fi = titled_fi("Overview");
+            fi.spec.pages[0].iframe.as_mut().unwrap().src = src.to_string();
+            assert!(
+                render_v1_manifest(&fi, &RenderOptions::default()).is_ok(),
+                "{src} should be accepted"
+            );
+        }
+    }
+
+    #[test]
+    fn enforces_the_iframe_host_allowlist() {
+        let options = RenderOptions {
+            iframe_allowed_hosts: vec![
+                "grafana.internal".to_string(),
+                "*.example.test".to_string(),
+            ],
+            ..Default::default()
+        };
+        let with_src = |src: &str| {
+            let mut fi = titled_fi("Overview");
+            fi.spec.pages[0].iframe.as_mut().unwrap().src = src.to_string();
+            fi
+        };
+
+        let manifest =
+            render_v1_manifest(&with_src(" https://GRAFANA.internal/d/x "), &options).unwrap();
+        assert_eq!(
+            manifest["pages"][0]["componentsTree"]["root"]["props"]["FRAME_URL"],
+            "https://GRAFANA.internal/d/x"
+        );
+        assert!(render_v1_manifest(&with_src("http://docs.example.test"), &options).is_ok());
+        // The allowlist only covers absolute URLs; relative ones stay on the console origin.
+        assert!(render_v1_manifest(&with_src("/proxy/grafana"), &options).is_ok());
+        for src in [
+            "http://example.test",
+            "http://evilexample.test",
+            "http://other.internal",
+            "//other.internal/d/x",
+        ] {
+            assert!(matches!(
+                render_v1_manifest(&with_src(src), &options),
+                Err(ManifestRenderError::InvalidIframeSrc { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn passes_placement_placeholders_through_as_a_frame_url_template() {
+        let with_src = |placement: MenuPlacement, src: &str| {
+            let mut fi = titled_fi("Overview");
+            fi.spec.menus[0].placement = placement;
+            fi.spec.pages[0].iframe.as_mut().unwrap().src = src.to_string();
+            fi
+        };
+
+        let manifest = render_v1_manifest(
+            &with_src(
+                MenuPlacement::Cluster,
+                "https://grafana.example:3000/d/abc?var-cluster=:cluster",
+            ),
+            &RenderOptions::default(),
+        )
+        .unwrap();
+        let props = &manifest["pages"][0]["componentsTree"]["root"]["props"];
+        assert_eq!(
+            props["FRAME_URL_TEMPLATE"],
+            "https://grafana.example:3000/d/abc?var-cluster=:cluster"
+        );
+        assert_eq!(props["FRAME_URL"], props["FRAME_URL_TEMPLATE"]);
+
+        let manifest = render_v1_manifest(
+            &with_src(MenuPlacement::Cluster, "https://grafana.example:3000/d/abc"),
+            &RenderOptions::default(),
+        )
+        .unwrap();
+        assert!(
+            manifest["pages"][0]["componentsTree"]["root"]["props"]
+                .get("FRAME_URL_TEMPLATE")
+                .is_none()
         );
+        assert!(
+            render_v1_manifest(
+                &with_src(
+                    MenuPlacement::Project,
+                    "https://grafana.example/d/abc?var-cluster=:cluster&var-ns=:namespace"
+                ),
+                &RenderOptions::default()
+            )
+            .is_ok()
+        );
+
+        for (placement, src) in [
+            (
+                MenuPlacement::Global,
+                "https://grafana.example/d/abc?ws=:workspace",
+            ),
+            (
+                MenuPlacement::Cluster,
+                "https://grafana.example/d/abc?ws=:workspace",
+            ),
+            (
+                MenuPlacement::Workspace,
+                "https://grafana.example/d/abc?c=:cluster",
+            ),
+        ] {
+            let err = render_v1_manifest(&with_src(placement, src), &RenderOptions::default())
+                .unwrap_err();
+            assert!(
+                matches!(err, ManifestRenderError::InvalidIframeSrc { ref message, .. }
+                    if message.contains("is not available in the")),
+                "{src} in {placement:?}: {err}"
+            );
+        }
+    }
+
+    #[test]
+    fn renders_iframe_sandbox_and_allow_attributes() {
+        let mut fi = titled_fi("Overview");
+        let iframe = fi.spec.pages[0].iframe.as_mut().unwrap();
+        iframe.sandbox = Some(vec![
+            "allow-scripts".to_string(),
+            "allow-same-origin".to_string(),
+        ]);
+        iframe.allow = Some(vec![
+            "fullscreen".to_string(),
+            " clipboard-write 'self' ".to_string(),
+        ]);
+
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        let props = &manifest["pages"][0]["componentsTree"]["root"]["props"];
+        assert_eq!(props["SANDBOX"], "allow-scripts allow-same-origin");
+        assert_eq!(props["ALLOW"], "fullscreen; clipboard-write 'self'");
+
+        fi.spec.pages[0].iframe.as_mut().unwrap().sandbox = Some(Vec::new());
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
         assert_eq!(
-            routes[2]["pageId"],
-            "demo-fi-workspace-workspace-ops_globalrulegroups"
+            manifest["pages"][0]["componentsTree"]["root"]["props"]["SANDBOX"],
+            ""
+        );
+
+        for (sandbox, allow) in [
+            (vec!["scripts"], vec![]),
+            (vec!["allow-Scripts"], vec![]),
+            (vec!["allow-everything"], vec![]),
+            (vec![], vec!["fullscreen; camera"]),
+            (vec![], vec![" "]),
+        ] {
+            let iframe = fi.spec.pages[0].iframe.as_mut().unwrap();
+            iframe.sandbox = Some(sandbox.iter().map(|s| s.to_string()).collect());
+            iframe.allow = Some(allow.iter().map(|s| s.to_string()).collect());
+            assert!(matches!(
+                render_v1_manifest(&fi, &RenderOptions::default()),
+                Err(ManifestRenderError::InvalidPageShape { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn renders_iframe_height_and_loading_only_when_set() {
+        let mut fi = titled_fi("Overview");
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        assert_eq!(
+            manifest["pages"][0]["componentsTree"]["root"]["props"],
+            json!({ "FRAME_URL": "http://example.test" })
+        );
+
+        let iframe = fi.spec.pages[0].iframe.as_mut().unwrap();
+        iframe.height = Some("80vh".to_string());
+        iframe.loading = Some(IframeLoading::Lazy);
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        let props = &manifest["pages"][0]["componentsTree"]["root"]["props"];
+        assert_eq!(props["HEIGHT"], "80vh");
+        assert_eq!(props["LOADING"], "lazy");
+
+        for height in ["tall", "0px", "-10px", "100", "12pt"] {
+            fi.spec.pages[0].iframe.as_mut().unwrap().height = Some(height.to_string());
+            assert!(
+                matches!(
+                    render_v1_manifest(&fi, &RenderOptions::default()),
+                    Err(ManifestRenderError::InvalidPageShape { .. })
+                ),
+                "{height}"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_sandbox_tokens_outside_the_html_allowlist() {
+        let mut fi = titled_fi("Overview");
+        fi.spec.pages[0].iframe.as_mut().unwrap().sandbox = Some(vec![
+            "allow-scripts".to_string(),
+            "alow-scripts".to_string(),
+        ]);
+
+        let err = render_v1_manifest(&fi, &RenderOptions::default()).unwrap_err();
+
+        assert!(err.to_string().contains("'alow-scripts'"), "{err}");
+    }
+
+    #[test]
+    fn truncates_or_rejects_over_length_menu_titles() {
+        let fi = titled_fi("Überwachung und Auswertung");
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        assert_eq!(manifest["menus"][0]["title"], "Überwachung und Auswertung");
+
+        let mut options = RenderOptions {
+            menu_title_max_chars: Some(11),
+            ..Default::default()
+        };
+
+        let manifest = render_v1_manifest(&fi, &options).unwrap();
+        assert_eq!(manifest["menus"][0]["title"], "Überwachung");
+
+        options.menu_title_overflow = MenuTitleOverflow::Reject;
+        assert!(matches!(
+            render_v1_manifest(&fi, &options),
+            Err(ManifestRenderError::MenuTitleTooLong {
+                length: 26,
+                max: 11,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn renders_nested_org_menu_bindings() {
+        let fi: FrontendIntegration = serde_yaml::from_str(
+            r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: demo-fi
+spec:
+  displayName: Demo
+  menus:
+    - displayName: Ops
+      key: ops
+      icon: Folder
+      placement: cluster
+      type: organization
+      children:
+        - displayName: Inspect Tasks
+          key: inspecttasks
+        - displayName: Ops Guide
+          key: ops-guide
+          icon: File
+  pages:
+    - key: inspecttasks
+      type: crdTable
+      crdTable:
+        names:
+          plural: inspecttasks
+          kind: InspectTask
+        group: kubeeye.kubesphere.io
+        version: v1alpha2
+        scope: Cluster
+        columns:
+          - key: name
+            title: NAME
+            render:
+              type: text
+              path: metadata.name
+    - key: ops-guide
+      type: iframe
+      iframe:
+        src: http://example.test/ops-guide
+"#,
+        )
+        .unwrap();
+
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        let menus = manifest["menus"].as_array().unwrap();
+        let routes = manifest["routes"].as_array().unwrap();
+        let pages = manifest["pages"].as_array().unwrap();
+
+        assert_eq!(menus.len(), 3);
+        assert_eq!(routes.len(), 2);
+        assert_eq!(pages.len(), 2);
+        assert_eq!(menus[0]["name"], "frontendintegrations/demo-fi/ops");
+        assert_eq!(menus[0]["parent"], "cluster");
+        assert_eq!(menus[0]["icon"], "Folder");
+        assert_eq!(
+            menus[1]["parent"],
+            "cluster.frontendintegrations/demo-fi/ops"
+        );
+        assert_eq!(
+            menus[1]["name"],
+            "frontendintegrations/demo-fi/ops/inspecttasks"
+        );
+        assert_eq!(menus[1]["icon"], "GridDuotone");
+        assert_eq!(
+            menus[2]["parent"],
+            "cluster.frontendintegrations/demo-fi/ops"
+        );
+        assert_eq!(
+            menus[2]["name"],
+            "frontendintegrations/demo-fi/ops/ops-guide"
+        );
+        assert_eq!(menus[2]["icon"], "File");
+        assert_eq!(
+            routes[0]["path"],
+            "/clusters/:cluster/frontendintegrations/demo-fi/ops/inspecttasks"
+        );
+        assert_eq!(routes[0]["pageId"], "demo-fi-cluster-ops_inspecttasks");
+        assert_eq!(
+            routes[1]["path"],
+            "/clusters/:cluster/frontendintegrations/demo-fi/ops/ops-guide"
+        );
+        assert_eq!(routes[1]["pageId"], "demo-fi-cluster-ops_ops-guide");
+        assert_eq!(pages[0]["componentsTree"]["meta"]["title"], "Inspect Tasks");
+        assert_eq!(
+            pages[0]["componentsTree"]["dataSources"][1]["type"],
+            "crd-page-state"
+        );
+        assert_eq!(pages[1]["componentsTree"]["meta"]["title"], "Ops Guide");
+    }
+
+    fn menu_fi(menus: &str) -> FrontendIntegration {
+        serde_yaml::from_str(&format!(
+            r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: demo-fi
+spec:
+  menus:
+{menus}
+  pages:
+    - key: overview
+      type: iframe
+      iframe:
+        src: http://example.test
+    - key: guide
+      type: iframe
+      iframe:
+        src: http://example.test/guide
+"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn menus_default_to_the_placement_root_with_order_999() {
+        let fi = menu_fi(
+            r#"    - displayName: Overview
+      key: overview
+      placement: cluster
+      type: page
+    - displayName: Guide
+      key: guide
+      placement: workspace
+      type: page"#,
+        );
+
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        assert_eq!(
+            manifest["menus"],
+            json!([
+                {
+                    "parent": "workspace",
+                    "name": "frontendintegrations/demo-fi/guide",
+                    "title": "Guide",
+                    "icon": "GridDuotone",
+                    "order": 999
+                },
+                {
+                    "parent": "cluster",
+                    "name": "frontendintegrations/demo-fi/overview",
+                    "title": "Overview",
+                    "icon": "GridDuotone",
+                    "order": 999
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn placement_order_in_the_spec_does_not_change_the_manifest() {
+        let cluster = "    - displayName: Overview\n      key: overview\n      placement: cluster\n      type: page";
+        let workspace = "    - displayName: Guide\n      key: guide\n      placement: workspace\n      type: page";
+        let mut reordered = menu_fi(&format!("{workspace}\n{cluster}"));
+        reordered.spec.pages.reverse();
+
+        let manifest = render_v1_manifest(
+            &menu_fi(&format!("{cluster}\n{workspace}")),
+            &RenderOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            manifest,
+            render_v1_manifest(&reordered, &RenderOptions::default()).unwrap()
+        );
+        assert_eq!(manifest["menus"][0]["parent"], "workspace");
+        assert_eq!(manifest["menus"][1]["parent"], "cluster");
+
+        // Within one placement the spec order is the nav order and is kept.
+        let first = "    - displayName: Overview\n      key: overview\n      placement: cluster\n      type: page";
+        let second = "    - displayName: Guide\n      key: guide\n      placement: cluster\n      type: page";
+        let manifest = render_v1_manifest(
+            &menu_fi(&format!("{second}\n{first}")),
+            &RenderOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(manifest["menus"][0]["title"], "Guide");
+        assert_eq!(manifest["menus"][1]["title"], "Overview");
+    }
+
+    #[test]
+    fn menus_use_order_and_attach_under_existing_console_groups() {
+        let fi = menu_fi(
+            r#"    - displayName: Overview
+      key: overview
+      placement: cluster
+      parent: cluster.monitoring
+      order: 10
+      type: page
+    - displayName: Ops
+      key: ops
+      placement: cluster
+      parent: cluster.platform.tools
+      order: -1
+      type: organization
+      children:
+        - displayName: Guide
+          key: guide
+          order: 3"#,
+        );
+
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        let menus = manifest["menus"].as_array().unwrap();
+        assert_eq!(menus[0]["parent"], "cluster.monitoring");
+        assert_eq!(menus[0]["order"], 10);
+        assert_eq!(menus[1]["parent"], "cluster.platform.tools");
+        assert_eq!(menus[1]["order"], -1);
+        assert_eq!(
+            menus[2]["parent"],
+            "cluster.platform.tools.frontendintegrations/demo-fi/ops"
+        );
+        assert_eq!(menus[2]["order"], 3);
+        // Routes stay under the placement regardless of where the menu is shown.
+        assert_eq!(
+            manifest["routes"][0]["path"],
+            "/clusters/:cluster/frontendintegrations/demo-fi/overview"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_menu_parents_and_icons() {
+        for (menus, message) in [
+            (
+                "      parent: workspace.monitoring\n      icon: GridDuotone",
+                "parent 'workspace.monitoring' must name a menu group under 'cluster'",
+            ),
+            (
+                "      parent: cluster",
+                "must name a menu group under 'cluster'",
+            ),
+            (
+                "      parent: cluster..monitoring",
+                "must name a menu group",
+            ),
+            ("      icon: ''", "icon '' must be an icon component name"),
+            ("      icon: grid duotone", "icon 'grid duotone' must be"),
+            ("      icon: '-grid'", "icon '-grid' must be"),
+            ("      icon: '<svg/>'", "icon '<svg/>' must be"),
+        ] {
+            let fi = menu_fi(&format!(
+                "    - displayName: Overview\n      key: overview\n      placement: cluster\n      type: page\n{menus}\n    - displayName: Guide\n      key: guide\n      placement: cluster\n      type: page"
+            ));
+            let err = render_v1_manifest(&fi, &RenderOptions::default()).unwrap_err();
+            assert!(
+                matches!(err, ManifestRenderError::InvalidMenuShape { ref key, .. } if key == "overview")
+                    && err.to_string().contains(message),
+                "{menus}: {err}"
+            );
+        }
+    }
+
+    #[test]
+    fn accepts_kebab_case_and_prefixed_icon_names() {
+        for icon in [
+            "kubesphere-icon",
+            "mdi:account-box",
+            "icons/grid_duotone.v2",
+        ] {
+            let fi = menu_fi(&format!(
+                "    - displayName: Overview\n      key: overview\n      placement: cluster\n      type: page\n      icon: {icon}\n    - displayName: Guide\n      key: guide\n      placement: cluster\n      type: page"
+            ));
+            let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+            assert_eq!(manifest["menus"][0]["icon"], icon);
+        }
+    }
+
+    fn project_crd_fi(scope: &str) -> FrontendIntegration {
+        scoped_crd_fi("project", scope)
+    }
+
+    fn scoped_crd_fi(placement: &str, scope: &str) -> FrontendIntegration {
+        serde_yaml::from_str(&format!(
+            r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: demo-fi
+spec:
+  menus:
+    - displayName: Backups
+      key: backups
+      placement: {placement}
+      type: page
+  pages:
+    - key: backups
+      type: crdTable
+      crdTable:
+        names:
+          plural: backups
+        group: example.io
+        version: v1
+        scope: {scope}
+        columns:
+          - key: name
+            title: NAME
+            render:
+              type: text
+              path: metadata.name
+"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn renders_project_placement_routes_menus_and_scope() {
+        let manifest =
+            render_v1_manifest(&project_crd_fi("Namespaced"), &RenderOptions::default()).unwrap();
+
+        assert_eq!(
+            manifest["routes"][0]["path"],
+            "/clusters/:cluster/projects/:namespace/frontendintegrations/demo-fi/backups"
+        );
+        assert_eq!(manifest["routes"][0]["pageId"], "demo-fi-project-backups");
+        assert_eq!(manifest["menus"][0]["parent"], "project");
+        let page_state = &manifest["pages"][0]["componentsTree"]["dataSources"][1];
+        assert_eq!(page_state["type"], "crd-page-state");
+        assert_eq!(page_state["config"]["SCOPE"], "project");
+    }
+
+    #[test]
+    fn rejects_cluster_scoped_crds_in_project_placement() {
+        let err =
+            render_v1_manifest(&project_crd_fi("Cluster"), &RenderOptions::default()).unwrap_err();
+
+        assert!(matches!(
+            err,
+            ManifestRenderError::ClusterScopedCrdInProject { ref key, .. } if key == "backups"
+        ));
+    }
+
+    #[test]
+    fn checks_crd_scope_against_every_placement() {
+        for (placement, scope, expected_scope) in [
+            ("cluster", "Namespaced", Some("namespace")),
+            ("cluster", "Cluster", Some("cluster")),
+            ("workspace", "Namespaced", None),
+            ("project", "Namespaced", Some("project")),
+            ("global", "Cluster", Some("cluster")),
+        ] {
+            let manifest =
+                render_v1_manifest(&scoped_crd_fi(placement, scope), &RenderOptions::default())
+                    .unwrap();
+            let config = &manifest["pages"][0]["componentsTree"]["dataSources"][1]["config"];
+            assert_eq!(config["CRD_CONFIG"]["RESOURCE_SCOPE"], scope, "{placement}");
+            assert_eq!(
+                config["SCOPE"].as_str(),
+                expected_scope,
+                "{placement}/{scope}"
+            );
+        }
+
+        let render = |placement: &str, scope: &str| {
+            render_v1_manifest(&scoped_crd_fi(placement, scope), &RenderOptions::default())
+        };
+        assert!(matches!(
+            render("project", "Cluster"),
+            Err(ManifestRenderError::ClusterScopedCrdInProject { .. })
+        ));
+        assert!(matches!(
+            render("workspace", "Cluster"),
+            Err(ManifestRenderError::ClusterScopedCrdInWorkspace { ref key, .. }) if key == "backups"
+        ));
+        assert!(matches!(
+            render("global", "Namespaced"),
+            Err(ManifestRenderError::NamespacedCrdWithoutNamespace { ref key, .. }) if key == "backups"
+        ));
+
+        let mut pinned = scoped_crd_fi("global", "Namespaced");
+        pinned.spec.pages[0]
+            .crd_table
+            .as_mut()
+            .unwrap()
+            .field_selector = Some("metadata.namespace=backup-system".to_string());
+        let manifest = render_v1_manifest(&pinned, &RenderOptions::default()).unwrap();
+        let config = &manifest["pages"][0]["componentsTree"]["dataSources"][1]["config"];
+        assert_eq!(config["SCOPE"], "namespace");
+        assert_eq!(
+            config["CRD_CONFIG"]["fieldSelector"],
+            "metadata.namespace=backup-system"
+        );
+    }
+
+    #[test]
+    fn allows_reusing_page_keys_across_placements() {
+        let fi: FrontendIntegration = serde_yaml::from_str(
+            r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: demo-fi
+spec:
+  menus:
+    - displayName: Cluster Ops
+      key: cluster-ops
+      placement: cluster
+      type: organization
+      children:
+        - displayName: Global Rule Groups
+          key: globalrulegroups
+        - displayName: Guide
+          key: guide
+    - displayName: Global Ops
+      key: global-ops
+      placement: global
+      type: organization
+      children:
+        - displayName: Global Rule Groups
+          key: globalrulegroups
+        - displayName: Guide
+          key: guide
+  pages:
+    - key: globalrulegroups
+      type: crdTable
+      crdTable:
+        names:
+          plural: globalrulegroups
+          kind: GlobalRuleGroup
+        group: alerting.kubesphere.io
+        version: v2beta1
+        scope: Cluster
+        columns:
+          - key: name
+            title: NAME
+            render:
+              type: text
+              path: metadata.name
+    - key: guide
+      type: iframe
+      iframe:
+        src: http://example.test/guide
+"#,
+        )
+        .unwrap();
+
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        let routes = manifest["routes"].as_array().unwrap();
+        let pages = manifest["pages"].as_array().unwrap();
+
+        assert_eq!(routes.len(), 4);
+        assert_eq!(pages.len(), 4);
+        assert_eq!(
+            routes[0]["pageId"],
+            "demo-fi-global-global-ops_globalrulegroups"
+        );
+        assert_eq!(
+            routes[2]["pageId"],
+            "demo-fi-cluster-cluster-ops_globalrulegroups"
+        );
+        assert_eq!(
+            pages[2]["componentsTree"]["dataSources"][1]["type"],
+            "crd-page-state"
+        );
+        assert_eq!(
+            pages[0]["componentsTree"]["dataSources"][1]["config"]["PAGE_ID"],
+            "demo-fi-global-global-ops_globalrulegroups"
+        );
+    }
+
+    #[test]
+    fn omits_kind_when_crd_table_kind_is_missing() {
+        let fi: FrontendIntegration = serde_yaml::from_str(
+            r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: demo-fi
+spec:
+  menus:
+    - displayName: Inspect Tasks
+      key: inspecttasks
+      placement: cluster
+      type: page
+  pages:
+    - key: inspecttasks
+      type: crdTable
+      crdTable:
+        names:
+          plural: inspecttasks
+        group: kubeeye.kubesphere.io
+        version: v1alpha2
+        scope: Cluster
+        columns:
+          - key: name
+            title: NAME
+            render:
+              type: text
+              path: metadata.name
+"#,
+        )
+        .unwrap();
+
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        let page = &manifest["pages"].as_array().unwrap()[0];
+        let props = &page["componentsTree"]["root"]["props"];
+        let page_state = &page["componentsTree"]["dataSources"][1];
+
+        assert_eq!(
+            props["CREATE_INITIAL_VALUE"],
+            json!({
+                "apiVersion": "kubeeye.kubesphere.io/v1alpha2",
+                "metadata": {
+                    "name": "",
+                    "labels": {},
+                    "annotations": {}
+                },
+                "spec": {}
+            })
+        );
+        assert_eq!(
+            page_state["config"]["CRD_CONFIG"],
+            json!({
+                "apiVersion": "v1alpha2",
+                "plural": "inspecttasks",
+                "group": "kubeeye.kubesphere.io",
+                "kapi": true,
+                "authKey": "inspecttasks",
+                "RESOURCE_SCOPE": "Cluster"
+            })
+        );
+        assert_eq!(props["AUTH_KEY"], "");
+    }
+
+    fn custom_page_fi(custom: &str) -> FrontendIntegration {
+        serde_yaml::from_str(&format!(
+            r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: demo-fi
+spec:
+  menus:
+    - displayName: Ops Dashboard
+      key: dashboard
+      placement: cluster
+      type: page
+  pages:
+    - key: dashboard
+      type: custom
+{custom}
+"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn custom_pages_insert_the_component_as_the_page_root() {
+        let fi = custom_page_fi(
+            r#"      custom:
+        type: Ops.Dashboard
+        props:
+          refreshSeconds: 30
+          panels: [{ title: Nodes, query: { kind: Node } }]"#,
+        );
+
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        let page = &manifest["pages"][0];
+        assert_eq!(page["entryComponent"], page["id"]);
+        assert_eq!(page["componentsTree"]["meta"]["title"], "Ops Dashboard");
+        assert_eq!(
+            page["componentsTree"]["root"],
+            json!({
+                "id": format!("{}-root", page["id"].as_str().unwrap()),
+                "type": "Ops.Dashboard",
+                "props": {
+                    "refreshSeconds": 30,
+                    "panels": [{ "title": "Nodes", "query": { "kind": "Node" } }]
+                },
+                "meta": { "title": "Ops.Dashboard", "scope": true }
+            })
+        );
+        assert!(page["componentsTree"].get("dataSources").is_none());
+
+        let bare = custom_page_fi("      custom:\n        type: Dashboard");
+        let manifest = render_v1_manifest(&bare, &RenderOptions::default()).unwrap();
+        assert_eq!(
+            manifest["pages"][0]["componentsTree"]["root"]["props"],
+            json!({})
+        );
+    }
+
+    #[test]
+    fn rejects_custom_pages_without_a_valid_component_name() {
+        for custom in [
+            "",
+            "      custom:\n        type: ''",
+            "      custom:\n        type: 1Dashboard",
+            "      custom:\n        type: Ops..Dashboard",
+            "      custom:\n        type: <Dashboard/>",
+        ] {
+            let err =
+                render_v1_manifest(&custom_page_fi(custom), &RenderOptions::default()).unwrap_err();
+            assert!(
+                matches!(err, ManifestRenderError::InvalidPageShape { ref key, .. } if key == "dashboard"),
+                "{custom}: {err}"
+            );
+        }
+    }
+
+    fn crd_page_with_actions(actions: &str) -> Value {
+        let fi: FrontendIntegration = serde_yaml::from_str(&format!(
+            r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: demo-fi
+spec:
+  menus:
+    - displayName: Inspect Tasks
+      key: inspecttasks
+      placement: cluster
+      type: page
+  pages:
+    - key: inspecttasks
+      type: crdTable
+      crdTable:
+        names:
+          plural: inspecttasks
+        group: kubeeye.kubesphere.io
+        version: v1alpha2
+        scope: Cluster
+        columns:
+          - key: name
+            title: NAME
+            render:
+              type: text
+              path: metadata.name
+{actions}
+"#
+        ))
+        .unwrap();
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        manifest["pages"][0].clone()
+    }
+
+    fn render_with_filters(filters: &str) -> Result<Value, ManifestRenderError> {
+        let fi: FrontendIntegration = serde_yaml::from_str(&format!(
+            r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: demo-fi
+spec:
+  menus:
+    - displayName: Inspect Tasks
+      key: inspecttasks
+      placement: cluster
+      type: page
+  pages:
+    - key: inspecttasks
+      type: crdTable
+      crdTable:
+        names:
+          plural: inspecttasks
+        group: kubeeye.kubesphere.io
+        version: v1alpha2
+        scope: Cluster
+        columns:
+          - key: name
+            title: NAME
+            render:
+              type: text
+              path: metadata.name
+{filters}
+"#
+        ))
+        .unwrap();
+        render_v1_manifest(&fi, &RenderOptions::default()).map(|manifest| {
+            manifest["pages"][0]["componentsTree"]["dataSources"][1]["config"]["FILTERS_CONFIG"]
+                .clone()
+        })
+    }
+
+    #[test]
+    fn crd_filters_default_to_name_search_and_render_in_order() {
+        assert_eq!(
+            render_with_filters("").unwrap(),
+            json!([{ "key": "metadata.name", "label": "Name", "type": "text" }])
+        );
+        assert_eq!(
+            render_with_filters(
+                r#"        filters:
+          - {key: status.phase, label: Phase, type: select, options: [Running, Done]}
+          - {key: metadata.labels, label: Labels, type: labelSelector, options: [ignored]}
+          - {key: spec.owner, label: Owner, type: text}"#
+            )
+            .unwrap(),
+            json!([
+                { "key": "status.phase", "label": "Phase", "type": "select", "options": ["Running", "Done"] },
+                { "key": "metadata.labels", "label": "Labels", "type": "labelSelector" },
+                { "key": "spec.owner", "label": "Owner", "type": "text" },
+            ])
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_crd_filters() {
+        for filters in [
+            "{key: status.phase, label: Phase, type: select}",
+            "{key: status.phase, label: Phase, type: select, options: [a, a]}",
+            "{key: status.phase, label: Phase, type: labelSelector}",
+            "{key: 'status phase', label: Phase, type: text}",
+            "{key: status.phase, label: ' ', type: text}",
+            "{key: spec.owner, label: A, type: text}, {key: spec.owner, label: B, type: text}",
+        ] {
+            let err = render_with_filters(&format!("        filters: [{filters}]")).unwrap_err();
+            assert!(
+                matches!(err, ManifestRenderError::InvalidFilter { .. }),
+                "{filters}: {err}"
+            );
+        }
+    }
+
+    fn action_props(page: &Value) -> Vec<&str> {
+        ["CREATE", "CREATE_INITIAL_VALUE", "UPDATE", "DEL"]
+            .into_iter()
+            .filter(|key| page["componentsTree"]["root"]["props"].get(key).is_some())
+            .collect()
+    }
+
+    #[test]
+    fn crd_actions_toggle_toolbar_bindings_and_capabilities() {
+        let unset = crd_page_with_actions("");
+        assert_eq!(
+            action_props(&unset),
+            ["CREATE", "CREATE_INITIAL_VALUE", "UPDATE", "DEL"]
+        );
+        assert!(unset.get("capabilities").is_none());
+
+        let all_enabled = crd_page_with_actions("        actions: {}");
+        assert_eq!(
+            action_props(&all_enabled),
+            ["CREATE", "CREATE_INITIAL_VALUE", "UPDATE", "DEL"]
+        );
+        assert_eq!(
+            all_enabled["capabilities"],
+            json!({ "create": true, "edit": true, "delete": true, "batchDelete": true })
+        );
+        assert_eq!(all_enabled["componentsTree"], unset["componentsTree"]);
+
+        let all_disabled = crd_page_with_actions(
+            "        actions: {create: false, edit: false, delete: false, batchDelete: false}",
+        );
+        assert!(action_props(&all_disabled).is_empty());
+        assert_eq!(
+            all_disabled["capabilities"],
+            json!({ "create": false, "edit": false, "delete": false, "batchDelete": false })
+        );
+        assert_eq!(
+            all_disabled["componentsTree"]["root"]["props"]["AUTH_KEY"],
+            ""
+        );
+
+        let mixed = crd_page_with_actions("        actions: {create: false, delete: false}");
+        assert_eq!(action_props(&mixed), ["UPDATE", "DEL"]);
+        assert_eq!(
+            mixed["capabilities"],
+            json!({ "create": false, "edit": true, "delete": false, "batchDelete": true })
+        );
+    }
+
+    #[test]
+    fn includes_kind_metadata_and_spec_in_crd_create_initial_value() {
+        let fi: FrontendIntegration = serde_yaml::from_str(
+            r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: demo-fi
+spec:
+  menus:
+    - displayName: Cluster Tasks
+      key: cluster-tasks
+      placement: cluster
+      type: page
+  pages:
+    - key: cluster-tasks
+      type: crdTable
+      crdTable:
+        names:
+          plural: serviceaccounts
+          kind: ServiceAccount
+        version: v1alpha1
+        group: kubesphere.io
+        scope: Namespaced
+        columns:
+          - key: name
+            title: NAME
+            render:
+              type: text
+              path: metadata.name
+"#,
+        )
+        .unwrap();
+
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        let page = &manifest["pages"].as_array().unwrap()[0];
+        let props = &page["componentsTree"]["root"]["props"];
+
+        assert_eq!(
+            props["CREATE_INITIAL_VALUE"],
+            json!({
+                "apiVersion": "kubesphere.io/v1alpha1",
+                "kind": "ServiceAccount",
+                "metadata": {
+                    "name": "",
+                    "labels": {},
+                    "annotations": {},
+                    "namespace": ""
+                },
+                "spec": {}
+            })
+        );
+    }
+
+    #[test]
+    fn includes_auth_key_in_menus_props_and_crd_config_when_present() {
+        let fi: FrontendIntegration = serde_yaml::from_str(
+            r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: demo-fi
+spec:
+  menus:
+    - displayName: Inspect Tasks
+      key: inspecttasks
+      placement: cluster
+      type: page
+  pages:
+    - key: inspecttasks
+      type: crdTable
+      crdTable:
+        names:
+          plural: inspecttasks
+          kind: InspectTask
+        group: kubeeye.kubesphere.io
+        version: v1alpha2
+        authKey: kubeeye-auth
+        scope: Cluster
+        columns:
+          - key: name
+            title: NAME
+            render:
+              type: text
+              path: metadata.name
+"#,
+        )
+        .unwrap();
+
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        let page = &manifest["pages"].as_array().unwrap()[0];
+        let props = &page["componentsTree"]["root"]["props"];
+        let page_state = &page["componentsTree"]["dataSources"][1];
+
+        assert_eq!(props["AUTH_KEY"], "kubeeye-auth");
+        assert_eq!(
+            page_state["config"]["CRD_CONFIG"]["authKey"],
+            "kubeeye-auth"
+        );
+        assert_eq!(manifest["menus"][0]["authKey"], "kubeeye-auth");
+    }
+
+    #[test]
+    fn auth_key_falls_back_to_the_plural_and_permissions_follow_actions() {
+        let fi = scoped_crd_fi("cluster", "Namespaced");
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        let page = &manifest["pages"][0];
+
+        assert_eq!(manifest["menus"][0]["authKey"], "backups");
+        assert_eq!(
+            page["componentsTree"]["dataSources"][1]["config"]["CRD_CONFIG"]["authKey"],
+            "backups"
+        );
+        assert_eq!(page["componentsTree"]["root"]["props"]["AUTH_KEY"], "");
+        assert_eq!(
+            page["permissions"],
+            json!([{
+                "group": "example.io",
+                "resource": "backups",
+                "verbs": ["get", "list", "watch", "create", "update", "delete"]
+            }])
+        );
+
+        let mut read_only = fi.clone();
+        read_only.spec.pages[0].crd_table.as_mut().unwrap().actions = Some(CrdActionsSpec {
+            create: Some(false),
+            edit: Some(false),
+            delete: Some(false),
+            batch_delete: Some(true),
+        });
+        let manifest = render_v1_manifest(&read_only, &RenderOptions::default()).unwrap();
+        assert_eq!(
+            manifest["pages"][0]["permissions"][0]["verbs"],
+            json!(["get", "list", "watch", "delete"])
+        );
+
+        // Iframe pages have no resource to check.
+        let manifest =
+            render_v1_manifest(&titled_fi("Overview"), &RenderOptions::default()).unwrap();
+        assert!(manifest["menus"][0].get("authKey").is_none());
+        assert!(manifest["pages"][0].get("permissions").is_none());
+    }
+
+    #[test]
+    fn renders_column_role_visibility_only_when_set() {
+        let fi: FrontendIntegration = serde_yaml::from_str(
+            r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: demo-fi
+spec:
+  menus:
+    - displayName: Inspect Tasks
+      key: inspecttasks
+      placement: cluster
+      type: page
+  pages:
+    - key: inspecttasks
+      type: crdTable
+      crdTable:
+        names:
+          plural: inspecttasks
+        group: kubeeye.kubesphere.io
+        version: v1alpha2
+        scope: Cluster
+        columns:
+          - key: name
+            title: NAME
+            render:
+              type: text
+              path: metadata.name
+          - key: uid
+            title: UID
+            visibleToRoles: [platform-admin, cluster-admin]
+            render:
+              type: text
+              path: metadata.uid
+"#,
+        )
+        .unwrap();
+
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        let columns =
+            &manifest["pages"][0]["componentsTree"]["dataSources"][0]["config"]["COLUMNS_CONFIG"];
+
+        assert!(columns[0].get("visibleToRoles").is_none());
+        assert_eq!(
+            columns[1]["visibleToRoles"],
+            json!(["platform-admin", "cluster-admin"])
+        );
+    }
+
+    #[test]
+    fn renders_status_columns_with_badge_mapping() {
+        let fi: FrontendIntegration = serde_yaml::from_str(
+            r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: demo-fi
+spec:
+  menus:
+    - displayName: Inspect Tasks
+      key: inspecttasks
+      placement: cluster
+      type: page
+  pages:
+    - key: inspecttasks
+      type: crdTable
+      crdTable:
+        names:
+          plural: inspecttasks
+        group: kubeeye.kubesphere.io
+        version: v1alpha2
+        scope: Cluster
+        columns:
+          - key: phase
+            title: PHASE
+            render:
+              type: status
+              path: status.phase
+              statusMap:
+                Succeeded: success
+                Failed: error
+          - key: health
+            title: HEALTH
+            render:
+              type: status
+              path: status.health
+              defaultIntent: warning
+"#,
+        )
+        .unwrap();
+
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        let columns =
+            &manifest["pages"][0]["componentsTree"]["dataSources"][0]["config"]["COLUMNS_CONFIG"];
+
+        assert_eq!(
+            columns,
+            &json!([
+                {
+                    "key": "phase",
+                    "title": "PHASE",
+                    "render": {
+                        "type": "status",
+                        "path": "status.phase",
+                        "payload": {
+                            "statusMap": { "Failed": "error", "Succeeded": "success" },
+                            "defaultIntent": "default"
+                        }
+                    }
+                },
+                {
+                    "key": "health",
+                    "title": "HEALTH",
+                    "render": {
+                        "type": "status",
+                        "path": "status.health",
+                        "payload": { "statusMap": {}, "defaultIntent": "warning" }
+                    }
+                }
+            ])
+        );
+    }
+
+    fn single_column_fi(render: &str) -> FrontendIntegration {
+        serde_yaml::from_str(&format!(
+            r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: demo-fi
+spec:
+  menus:
+    - displayName: Inspect Tasks
+      key: inspecttasks
+      placement: cluster
+      type: page
+  pages:
+    - key: inspecttasks
+      type: crdTable
+      crdTable:
+        names:
+          plural: inspecttasks
+        group: kubeeye.kubesphere.io
+        version: v1alpha2
+        scope: Cluster
+        columns:
+          - key: meta
+            title: META
+            render: {render}
+"#
+        ))
+        .unwrap()
+    }
+
+    fn column_render(render: &str) -> Result<Value, ManifestRenderError> {
+        render_v1_manifest(&single_column_fi(render), &RenderOptions::default()).map(|manifest| {
+            manifest["pages"][0]["componentsTree"]["dataSources"][0]["config"]["COLUMNS_CONFIG"][0]
+                ["render"]
+                .clone()
+        })
+    }
+
+    #[test]
+    fn renders_labels_and_annotations_columns_with_key_filters() {
+        let fi = single_column_fi(
+            "{type: annotations, path: metadata.annotations, excludeKeys: [kubectl.kubernetes.io/last-applied-configuration]}",
+        );
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        let column = &manifest["pages"][0]["componentsTree"]["dataSources"][0]["config"]["COLUMNS_CONFIG"]
+            [0];
+        assert_eq!(
+            column["render"],
+            json!({
+                "type": "annotations",
+                "path": "metadata.annotations",
+                "payload": {
+                    "excludeKeys": ["kubectl.kubernetes.io/last-applied-configuration"]
+                }
+            })
+        );
+
+        let fi =
+            single_column_fi("{type: labels, path: metadata.labels, includeKeys: [app, tier]}");
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        let column = &manifest["pages"][0]["componentsTree"]["dataSources"][0]["config"]["COLUMNS_CONFIG"]
+            [0];
+        assert_eq!(column["render"]["type"], "labels");
+        assert_eq!(
+            column["render"]["payload"],
+            json!({ "includeKeys": ["app", "tier"] })
+        );
+    }
+
+    #[test]
+    fn template_columns_emit_the_template_and_its_field_paths() {
+        assert_eq!(
+            column_render("{type: template, path: status.readyReplicas, template: '{status.readyReplicas}/{spec.replicas}'}")
+                .unwrap(),
+            json!({
+                "type": "template",
+                "path": "status.readyReplicas",
+                "payload": {
+                    "template": "{status.readyReplicas}/{spec.replicas}",
+                    "params": ["status.readyReplicas", "spec.replicas"]
+                }
+            })
+        );
+
+        for (render_spec, message) in [
+            (
+                "{type: template, path: spec.replicas}",
+                "requires render.template",
+            ),
+            (
+                "{type: template, path: spec.replicas, template: ''}",
+                "must not be empty",
+            ),
+            (
+                "{type: template, path: spec.replicas, template: 'ready'}",
+                "at least one",
+            ),
+            (
+                "{type: template, path: spec.replicas, template: '{spec.replicas'}",
+                "unclosed '{'",
+            ),
+            (
+                "{type: template, path: spec.replicas, template: 'spec}/{a}'}",
+                "unmatched '}'",
+            ),
+            (
+                "{type: template, path: spec.replicas, template: '{spec..replicas}'}",
+                "not a valid field path",
+            ),
+        ] {
+            let err = column_render(render_spec).unwrap_err();
+            assert!(
+                matches!(err, ManifestRenderError::InvalidColumn { .. }),
+                "{render_spec}"
+            );
+            assert!(err.to_string().contains(message), "{err}");
+        }
+    }
+
+    #[test]
+    fn custom_columns_pass_the_component_and_props_through() {
+        assert_eq!(
+            column_render("{type: custom, path: status.history, payload: {component: MySparkline, props: {color: green, points: 20}}}")
+                .unwrap(),
+            json!({
+                "type": "custom",
+                "path": "status.history",
+                "payload": {
+                    "component": "MySparkline",
+                    "props": {"color": "green", "points": 20}
+                }
+            })
+        );
+        assert_eq!(
+            column_render(
+                "{type: custom, path: status.history, payload: {component: MySparkline}}"
+            )
+            .unwrap()["payload"],
+            json!({"component": "MySparkline", "props": {}})
+        );
+
+        for (render_spec, message) in [
+            (
+                "{type: custom, path: status.history}",
+                "requires a non-empty payload.component",
+            ),
+            (
+                "{type: custom, path: status.history, payload: {component: ' '}}",
+                "requires a non-empty payload.component",
+            ),
+            (
+                "{type: custom, path: status.history, payload: {component: [a]}}",
+                "payload.component must be a string",
+            ),
+            (
+                "{type: custom, path: status.history, payload: {component: MySparkline, props: [1]}}",
+                "payload.props must be an object",
+            ),
+        ] {
+            let err = column_render(render_spec).unwrap_err();
+            assert!(
+                matches!(err, ManifestRenderError::InvalidColumn { .. }),
+                "{render_spec}"
+            );
+            assert!(err.to_string().contains(message), "{err}");
+        }
+    }
+
+    #[test]
+    fn time_columns_default_their_format_and_support_relative_display() {
+        let render = |render: &str| column_render(render).map(|render| render["payload"].clone());
+
+        assert_eq!(
+            render("{type: time, path: metadata.creationTimestamp}").unwrap(),
+            json!({"format": "YYYY-MM-DD HH:mm:ss"})
+        );
+        assert_eq!(
+            render("{type: time, path: metadata.creationTimestamp, format: relative-short}")
+                .unwrap(),
+            json!({"format": "relative-short"})
+        );
+        assert_eq!(
+            render("{type: time, path: metadata.creationTimestamp, pattern: 'HH:mm'}").unwrap(),
+            json!({"pattern": "HH:mm"})
+        );
+        assert_eq!(
+            render("{type: time, path: metadata.creationTimestamp, relative: true}").unwrap(),
+            json!({
+                "format": "YYYY-MM-DD HH:mm:ss",
+                "relative": true,
+                "tooltipFormat": "YYYY-MM-DD HH:mm:ss"
+            })
+        );
+        assert_eq!(
+            render(
+                "{type: time, path: metadata.creationTimestamp, relative: true, tooltipFormat: 'MMM D, HH:mm'}"
+            )
+            .unwrap()["tooltipFormat"],
+            "MMM D, HH:mm"
+        );
+        // Text columns never pick up the time default.
+        assert_eq!(
+            render("{type: text, path: metadata.name}").unwrap(),
+            json!({})
+        );
+
+        let err = render(
+            "{type: time, path: metadata.creationTimestamp, relative: true, pattern: 'HH:mm'}",
+        )
+        .unwrap_err();
+        assert!(matches!(err, ManifestRenderError::InvalidColumn { .. }));
+        assert!(err.to_string().contains("pattern cannot be combined"));
+    }
+
+    #[test]
+    fn number_columns_carry_unit_options_and_reject_unknown_ones() {
+        assert_eq!(
+            column_render(
+                "{type: number, path: status.size, unit: bytes, precision: 1, downscale: true}"
+            )
+            .unwrap(),
+            json!({
+                "type": "number",
+                "path": "status.size",
+                "payload": {"unit": "bytes", "precision": 1, "downscale": true}
+            })
+        );
+        assert_eq!(
+            column_render("{type: number, path: spec.replicas}").unwrap()["payload"],
+            json!({})
+        );
+        // Number options on other column types are rejected, like the other type-specific fields.
+        assert!(matches!(
+            column_render("{type: text, path: spec.replicas, unit: furlongs}").unwrap_err(),
+            ManifestRenderError::InvalidColumn { .. }
+        ));
+
+        for (options, message) in [
+            ("unit: furlongs", "unit 'furlongs'"),
+            ("unit: percent, precision: 7", "between 0 and 6, got 7"),
+            (
+                "unit: cores, downscale: true",
+                "downscale requires unit: bytes",
+            ),
+            ("downscale: true", "downscale requires unit: bytes"),
+        ] {
+            let err = column_render(&format!("{{type: number, path: status.size, {options}}}"))
+                .unwrap_err();
+            assert!(
+                matches!(err, ManifestRenderError::InvalidColumn { .. }),
+                "{options}"
+            );
+            assert!(err.to_string().contains(message), "{err}");
+        }
+    }
+
+    #[test]
+    fn copyable_flag_reaches_text_and_link_payloads_only_when_true() {
+        let payload = |render: &str| column_render(render).unwrap()["payload"].clone();
+
+        assert_eq!(
+            payload("{type: text, path: metadata.uid, copyable: true}"),
+            json!({ "copyable": true })
+        );
+        assert_eq!(
+            payload("{type: link, path: spec.url, link: spec.url, copyable: true}")["copyable"],
+            true
+        );
+        for render in [
+            "{type: text, path: metadata.uid}",
+            "{type: text, path: metadata.uid, copyable: false}",
+        ] {
+            assert!(payload(render).get("copyable").is_none(), "{render}");
+        }
+    }
+
+    #[test]
+    fn rejects_render_fields_meant_for_another_render_type() {
+        for (render, message) in [
+            (
+                "{type: time, path: metadata.creationTimestamp, copyable: true}",
+                "copyable is only supported by text/link columns, not time",
+            ),
+            (
+                "{type: text, path: status.phase, statusMap: {Ready: success}}",
+                "statusMap is only supported by status columns, not text",
+            ),
+            (
+                "{type: text, path: metadata.labels, includeKeys: [app]}",
+                "includeKeys is only supported by labels/annotations columns, not text",
+            ),
+            (
+                "{type: text, path: status.size, unit: bytes}",
+                "unit is only supported by number columns, not text",
+            ),
+            (
+                "{type: text, path: metadata.creationTimestamp, relative: true}",
+                "relative is only supported by time columns, not text",
+            ),
+            (
+                "{type: text, path: spec.replicas, template: '{spec.replicas}'}",
+                "template is only supported by template columns, not text",
+            ),
+        ] {
+            let err = column_render(render).unwrap_err();
+            assert!(
+                matches!(&err, ManifestRenderError::InvalidColumn { column, message: m, .. } if column == "meta" && m == message),
+                "{render}: {err}"
+            );
+        }
+    }
+
+    #[test]
+    fn renders_refresh_interval_only_when_configured() {
+        let mut fi = single_column_fi("{type: labels, path: metadata.labels}");
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        let page_state = &manifest["pages"][0]["componentsTree"]["dataSources"][1];
+        assert!(page_state["config"].get("REFRESH_INTERVAL").is_none());
+
+        fi.spec.pages[0]
+            .crd_table
+            .as_mut()
+            .unwrap()
+            .refresh_interval_seconds = Some(30);
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        let page_state = &manifest["pages"][0]["componentsTree"]["dataSources"][1];
+        assert_eq!(page_state["config"]["REFRESH_INTERVAL"], 30);
+
+        for interval in [0, 1, 86_400] {
+            fi.spec.pages[0]
+                .crd_table
+                .as_mut()
+                .unwrap()
+                .refresh_interval_seconds = Some(interval);
+            assert!(matches!(
+                render_v1_manifest(&fi, &RenderOptions::default()),
+                Err(ManifestRenderError::InvalidPageShape { message, .. })
+                    if message.contains("refreshIntervalSeconds")
+            ));
+        }
+    }
+
+    #[test]
+    fn renders_default_sort_and_page_size_into_page_state() {
+        let mut fi = single_column_fi("{type: text, path: metadata.name}");
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        let config = &manifest["pages"][0]["componentsTree"]["dataSources"][1]["config"];
+        assert!(config.get("DEFAULT_SORT").is_none());
+        assert!(config.get("PAGE_SIZE").is_none());
+
+        let crd_table = fi.spec.pages[0].crd_table.as_mut().unwrap();
+        crd_table.table = Some(TableSpec {
+            default_sort_key: Some("meta".to_string()),
+            default_sort_order: Some(SortOrder::Desc),
+            page_size: Some(20),
+        });
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        let config = &manifest["pages"][0]["componentsTree"]["dataSources"][1]["config"];
+        assert_eq!(
+            config["DEFAULT_SORT"],
+            json!({"key": "meta", "order": "desc"})
+        );
+        assert_eq!(config["PAGE_SIZE"], 20);
+    }
+
+    #[test]
+    fn rejects_default_sort_on_unknown_or_unsortable_columns() {
+        let mut fi = single_column_fi("{type: text, path: metadata.name}");
+        let crd_table = fi.spec.pages[0].crd_table.as_mut().unwrap();
+        crd_table.table = Some(TableSpec {
+            default_sort_key: Some("createdAt".to_string()),
+            ..Default::default()
+        });
+        let err = render_v1_manifest(&fi, &RenderOptions::default()).unwrap_err();
+        assert!(matches!(
+            &err,
+            ManifestRenderError::InvalidDefaultSort { column, .. } if column == "createdAt"
+        ));
+        assert!(
+            err.to_string()
+                .contains("'createdAt' is not a declared column (declared: meta)")
+        );
+
+        let crd_table = fi.spec.pages[0].crd_table.as_mut().unwrap();
+        crd_table.columns[0].enable_sorting = Some(false);
+        crd_table.table.as_mut().unwrap().default_sort_key = Some("meta".to_string());
+        assert!(matches!(
+            render_v1_manifest(&fi, &RenderOptions::default()),
+            Err(ManifestRenderError::InvalidDefaultSort { message, .. })
+                if message.contains("enableSorting")
+        ));
+
+        let crd_table = fi.spec.pages[0].crd_table.as_mut().unwrap();
+        crd_table.table = Some(TableSpec {
+            page_size: Some(0),
+            ..Default::default()
+        });
+        assert!(matches!(
+            render_v1_manifest(&fi, &RenderOptions::default()),
+            Err(ManifestRenderError::InvalidPageShape { message, .. }) if message.contains("pageSize")
+        ));
+    }
+
+    #[test]
+    fn propagates_selectors_into_crd_config() {
+        let mut fi = single_column_fi("{type: text, path: metadata.name}");
+        let crd_table = fi.spec.pages[0].crd_table.as_mut().unwrap();
+        crd_table.label_selector = Some(" team=payments,tier in (web,api) ".to_string());
+        crd_table.field_selector = Some("status.phase!=Failed".to_string());
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        let crd_config =
+            &manifest["pages"][0]["componentsTree"]["dataSources"][1]["config"]["CRD_CONFIG"];
+        assert_eq!(
+            crd_config["labelSelector"],
+            "team=payments,tier in (web,api)"
+        );
+        assert_eq!(crd_config["fieldSelector"], "status.phase!=Failed");
+
+        // The selector is part of the rendered manifest, so changing it changes the manifest hash.
+        let mut narrowed = fi.clone();
+        narrowed.spec.pages[0]
+            .crd_table
+            .as_mut()
+            .unwrap()
+            .label_selector = Some("team=billing".to_string());
+        assert_ne!(
+            render_v1_manifest(&narrowed, &RenderOptions::default()).unwrap(),
+            manifest
+        );
+
+        let crd_table = fi.spec.pages[0].crd_table.as_mut().unwrap();
+        crd_table.label_selector = Some("team in (payments".to_string());
+        let err = render_v1_manifest(&fi, &RenderOptions::default()).unwrap_err();
+        assert!(matches!(
+            &err,
+            ManifestRenderError::InvalidSelector { field, .. } if field == "labelSelector"
+        ));
+    }
+
+    #[test]
+    fn rejects_map_columns_pointing_at_non_map_fields() {
+        assert!(
+            render_v1_manifest(
+                &single_column_fi("{type: labels, path: spec.selector.matchLabels}"),
+                &RenderOptions::default()
+            )
+            .is_ok()
+        );
+
+        let err = render_v1_manifest(
+            &single_column_fi("{type: labels, path: metadata.name}"),
+            &RenderOptions::default(),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            &err,
+            ManifestRenderError::InvalidMapColumnPath { column, .. } if column == "meta"
+        ));
+        assert!(err.to_string().contains("column 'meta'"));
+    }
+
+    #[test]
+    fn rejects_duplicate_column_keys() {
+        let mut fi = single_column_fi("{type: text, path: metadata.name}");
+        let columns = &mut fi.spec.pages[0].crd_table.as_mut().unwrap().columns;
+        let mut repeat = columns[0].clone();
+        repeat.title = "AGAIN".to_string();
+        repeat.render.path = "metadata.uid".to_string();
+        columns.push(repeat);
+
+        let err = render_v1_manifest(&fi, &RenderOptions::default()).unwrap_err();
+        assert!(matches!(
+            &err,
+            ManifestRenderError::DuplicateColumnKey { column, .. } if column == "meta"
+        ));
+        assert!(
+            crate::v2::render_v2_manifest(&fi, &RenderOptions::default()).is_err(),
+            "v2 shares the check"
+        );
+    }
+
+    #[test]
+    fn column_layout_options_render_only_when_set() {
+        let fi = single_column_fi("{type: text, path: metadata.name}");
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        // Untouched specs must keep the exact column object so manifest hashes stay stable.
+        assert_eq!(
+            manifest["pages"][0]["componentsTree"]["dataSources"][0]["config"]["COLUMNS_CONFIG"][0],
+            json!({
+                "key": "meta",
+                "title": "META",
+                "render": {"type": "text", "path": "metadata.name", "payload": {}}
+            })
+        );
+        let round_tripped: FrontendIntegration =
+            serde_json::from_value(serde_json::to_value(&fi).unwrap()).unwrap();
+        assert_eq!(
+            render_v1_manifest(&round_tripped, &RenderOptions::default()).unwrap(),
+            manifest
+        );
+
+        let mut fi = fi;
+        let column = &mut fi.spec.pages[0].crd_table.as_mut().unwrap().columns[0];
+        column.width = Some("120px".to_string());
+        column.align = Some(ColumnAlign::Right);
+        column.default_hidden = Some(true);
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        let column = &manifest["pages"][0]["componentsTree"]["dataSources"][0]["config"]["COLUMNS_CONFIG"]
+            [0];
+        assert_eq!(column["width"], "120px");
+        assert_eq!(column["align"], "right");
+        assert_eq!(column["defaultHidden"], true);
+    }
+
+    #[test]
+    fn renders_link_templates_with_params_and_keeps_plain_links() {
+        let fi = single_column_fi(
+            "{type: link, path: metadata.name, link: {template: '/clusters/host/{metadata.namespace}/tasks/{metadata.name}', target: _blank}}",
         );
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        let column = &manifest["pages"][0]["componentsTree"]["dataSources"][0]["config"]["COLUMNS_CONFIG"]
+            [0];
         assert_eq!(
-            pages[0]["componentsTree"]["dataSources"][1]["type"],
-            "crd-page-state"
+            column["render"]["payload"]["link"],
+            json!({
+                "template": "/clusters/host/{metadata.namespace}/tasks/{metadata.name}",
+                "params": ["metadata.namespace", "metadata.name"],
+                "external": false,
+                "target": "_blank"
+            })
         );
+
+        let fi = single_column_fi("{type: link, path: metadata.name, link: /clusters/host}");
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
         assert_eq!(
-            pages[2]["componentsTree"]["dataSources"][1]["type"],
-            "workspace-crd-page-state"
+            manifest["pages"][0]["componentsTree"]["dataSources"][0]["config"]["COLUMNS_CONFIG"][0]
+                ["render"]["payload"]["link"],
+            json!("/clusters/host")
         );
     }
 
     #[test]
-    fn omits_kind_when_crd_table_kind_is_missing() {
-        let fi: FrontendIntegration = serde_yaml::from_str(
-            r#"
-apiVersion: frontend-forge.kubesphere.io/v1alpha1
-kind: FrontendIntegration
-metadata:
-  name: demo-fi
-spec:
-  menus:
-    - displayName: Inspect Tasks
-      key: inspecttasks
-      placement: cluster
-      type: page
-  pages:
-    - key: inspecttasks
-      type: crdTable
-      crdTable:
-        names:
-          plural: inspecttasks
-        group: kubeeye.kubesphere.io
-        version: v1alpha2
-        scope: Cluster
-        columns:
-          - key: name
-            title: NAME
-            render:
-              type: text
-              path: metadata.name
-"#,
-        )
-        .unwrap();
-
-        let manifest = render_v1_manifest(&fi).unwrap();
-        let page = &manifest["pages"].as_array().unwrap()[0];
-        let props = &page["componentsTree"]["root"]["props"];
-        let page_state = &page["componentsTree"]["dataSources"][1];
+    fn external_links_open_in_a_new_tab_without_an_opener() {
+        let link = |render: &str| column_render(render).unwrap()["payload"]["link"].clone();
 
         assert_eq!(
-            props["CREATE_INITIAL_VALUE"],
+            link("{type: link, path: spec.url, link: {template: '{spec.url}', external: true}}"),
             json!({
-                "apiVersion": "kubeeye.kubesphere.io/v1alpha2",
-                "metadata": {
-                    "name": "",
-                    "labels": {},
-                    "annotations": {}
-                },
-                "spec": {}
+                "template": "{spec.url}",
+                "params": ["spec.url"],
+                "external": true,
+                "target": "_blank",
+                "rel": "noopener"
             })
         );
+        let framed = link(
+            "{type: link, path: spec.url, link: {template: '{spec.url}', external: true, target: docs}}",
+        );
+        assert_eq!(framed["target"], "docs");
+        assert_eq!(framed["rel"], "noopener");
+
+        let in_app =
+            link("{type: link, path: metadata.name, link: {template: '/tasks/{metadata.name}'}}");
+        assert_eq!(in_app["external"], false);
+        assert!(in_app.get("target").is_none());
+        assert!(in_app.get("rel").is_none());
+    }
+
+    #[test]
+    fn rejects_link_templates_with_invalid_placeholders() {
+        for template in [
+            "/tasks/{}",
+            "/tasks/{metadata..name}",
+            "/tasks/{metadata.name",
+            "/tasks/metadata.name}",
+            "/tasks/{spec.ports[x]}",
+            "/tasks/{1st.field}",
+        ] {
+            let err = render_v1_manifest(
+                &single_column_fi(&format!(
+                    "{{type: link, path: metadata.name, link: {{template: '{template}'}}}}"
+                )),
+                &RenderOptions::default(),
+            )
+            .unwrap_err();
+            assert!(
+                matches!(&err, ManifestRenderError::InvalidLinkTemplate { column, .. } if column == "meta"),
+                "{template}: {err}"
+            );
+        }
         assert_eq!(
-            page_state["config"]["CRD_CONFIG"],
-            json!({
-                "apiVersion": "v1alpha2",
-                "plural": "inspecttasks",
-                "group": "kubeeye.kubesphere.io",
-                "kapi": true
-            })
+            link_template_params("https://{status.host}/{spec.ports[0].name}").unwrap(),
+            vec!["status.host", "spec.ports[0].name"]
         );
-        assert_eq!(props["AUTH_KEY"], "");
     }
 
     #[test]
-    fn includes_kind_metadata_and_spec_in_crd_create_initial_value() {
+    fn renders_required_permissions_only_for_gated_pages() {
         let fi: FrontendIntegration = serde_yaml::from_str(
             r#"
 apiVersion: frontend-forge.kubesphere.io/v1alpha1
@@ -1022,52 +4429,51 @@ metadata:
   name: demo-fi
 spec:
   menus:
-    - displayName: Cluster Tasks
-      key: cluster-tasks
+    - displayName: Inspect Tasks
+      key: inspecttasks
       placement: cluster
       type: page
+    - displayName: Docs
+      key: docs
+      placement: global
+      type: page
   pages:
-    - key: cluster-tasks
-      type: crdTable
-      crdTable:
-        names:
-          plural: serviceaccounts
-          kind: ServiceAccount
-        version: v1alpha1
-        group: kubesphere.io
-        scope: Namespaced
-        columns:
-          - key: name
-            title: NAME
-            render:
-              type: text
-              path: metadata.name
+    - key: inspecttasks
+      type: iframe
+      iframe:
+        src: http://example.test/tasks
+      permissions:
+        - group: kubeeye.kubesphere.io
+          resource: inspecttasks
+          verbs: [list, watch]
+        - resource: configmaps
+          verbs: [get]
+    - key: docs
+      type: iframe
+      iframe:
+        src: http://example.test/docs
 "#,
         )
         .unwrap();
 
-        let manifest = render_v1_manifest(&fi).unwrap();
-        let page = &manifest["pages"].as_array().unwrap()[0];
-        let props = &page["componentsTree"]["root"]["props"];
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        let expected = json!([
+            {
+                "group": "kubeeye.kubesphere.io",
+                "resource": "inspecttasks",
+                "verbs": ["list", "watch"]
+            },
+            { "group": "", "resource": "configmaps", "verbs": ["get"] }
+        ]);
 
-        assert_eq!(
-            props["CREATE_INITIAL_VALUE"],
-            json!({
-                "apiVersion": "kubesphere.io/v1alpha1",
-                "kind": "ServiceAccount",
-                "metadata": {
-                    "name": "",
-                    "labels": {},
-                    "annotations": {},
-                    "namespace": ""
-                },
-                "spec": {}
-            })
-        );
+        assert_eq!(manifest["menus"][1]["requiredPermissions"], expected);
+        assert_eq!(manifest["pages"][1]["requiredPermissions"], expected);
+        assert!(manifest["menus"][0].get("requiredPermissions").is_none());
+        assert!(manifest["pages"][0].get("requiredPermissions").is_none());
     }
 
     #[test]
-    fn includes_auth_key_in_crd_table_props_when_present() {
+    fn rejects_permissions_without_verbs() {
         let fi: FrontendIntegration = serde_yaml::from_str(
             r#"
 apiVersion: frontend-forge.kubesphere.io/v1alpha1
@@ -1076,38 +4482,28 @@ metadata:
   name: demo-fi
 spec:
   menus:
-    - displayName: Inspect Tasks
-      key: inspecttasks
-      placement: cluster
+    - displayName: Docs
+      key: docs
+      placement: global
       type: page
   pages:
-    - key: inspecttasks
-      type: crdTable
-      crdTable:
-        names:
-          plural: inspecttasks
-          kind: InspectTask
-        group: kubeeye.kubesphere.io
-        version: v1alpha2
-        authKey: kubeeye-auth
-        scope: Cluster
-        columns:
-          - key: name
-            title: NAME
-            render:
-              type: text
-              path: metadata.name
+    - key: docs
+      type: iframe
+      iframe:
+        src: http://example.test/docs
+      permissions:
+        - resource: configmaps
+          verbs: []
 "#,
         )
         .unwrap();
 
-        let manifest = render_v1_manifest(&fi).unwrap();
-        let page = &manifest["pages"].as_array().unwrap()[0];
-        let props = &page["componentsTree"]["root"]["props"];
-        let page_state = &page["componentsTree"]["dataSources"][1];
+        let err = render_v1_manifest(&fi, &RenderOptions::default()).unwrap_err();
 
-        assert_eq!(props["AUTH_KEY"], "kubeeye-auth");
-        assert!(page_state["config"]["CRD_CONFIG"].get("authKey").is_none());
+        assert!(matches!(
+            err,
+            ManifestRenderError::InvalidPageShape { ref key, .. } if key == "docs"
+        ));
     }
 
     #[test]
@@ -1144,7 +4540,7 @@ spec:
         )
         .unwrap();
 
-        let manifest = render_v1_manifest(&fi).unwrap();
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
         let routes = manifest["routes"].as_array().unwrap();
         let pages = manifest["pages"].as_array().unwrap();
 
@@ -1155,6 +4551,90 @@ spec:
         assert_eq!(pages[1]["id"], "demo-fi-workspace-ops_guide");
     }
 
+    #[test]
+    fn page_id_strategies_keep_routes_and_pages_consistent() {
+        let fi: FrontendIntegration = serde_yaml::from_str(
+            r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: demo-fi
+spec:
+  menus:
+    - displayName: Ops Guide
+      key: ops-guide
+      placement: workspace
+      type: page
+    - displayName: Ops
+      key: ops
+      placement: cluster
+      type: organization
+      children:
+        - displayName: Tasks
+          key: tasks
+  pages:
+    - key: ops-guide
+      type: iframe
+      iframe:
+        src: http://example.test/top-level
+    - key: tasks
+      type: crdTable
+      crdTable:
+        names:
+          plural: inspecttasks
+        group: kubeeye.kubesphere.io
+        version: v1alpha2
+        scope: Cluster
+        columns:
+          - key: name
+            title: NAME
+            render: {type: text, path: metadata.name}
+"#,
+        )
+        .unwrap();
+
+        let mut seen = HashSet::new();
+        for (strategy, expected) in [
+            (
+                PageIdStrategy::FiPlacement,
+                "demo-fi-cluster-ops_tasks".to_string(),
+            ),
+            (
+                PageIdStrategy::NamespaceFiPlacement,
+                "team-a-demo-fi-cluster-ops_tasks".to_string(),
+            ),
+            (
+                PageIdStrategy::HashBased,
+                format!("demo-fi-{:016x}", fnv1a_64(b"cluster/ops/tasks")),
+            ),
+        ] {
+            let options = RenderOptions {
+                page_id_strategy: Some(strategy),
+                page_id_namespace: "team-a".to_string(),
+                ..Default::default()
+            };
+            let manifest = render_v1_manifest(&fi, &options).unwrap();
+            let routes = manifest["routes"].as_array().unwrap();
+            let pages = manifest["pages"].as_array().unwrap();
+            for (route, page) in routes.iter().zip(pages) {
+                let id = page["id"].as_str().unwrap();
+                assert_eq!(route["pageId"], id, "{strategy:?}");
+                assert_eq!(page["entryComponent"], id);
+                assert_eq!(page["componentsTree"]["meta"]["id"], id);
+                assert_eq!(page["componentsTree"]["root"]["id"], format!("{id}-root"));
+                assert!(seen.insert(id.to_string()), "{strategy:?} reused {id}");
+            }
+            assert_eq!(routes[1]["pageId"], expected);
+            let tree = &pages[1]["componentsTree"];
+            assert_eq!(tree["root"]["props"]["TABLE_KEY"], expected);
+            assert_eq!(tree["dataSources"][1]["config"]["PAGE_ID"], expected);
+        }
+        assert_eq!(
+            render_v1_manifest(&fi, &RenderOptions::default()).unwrap()["routes"][0]["pageId"],
+            "demo-fi-workspace-ops-guide"
+        );
+    }
+
     #[test]
     fn rejects_page_menu_with_children() {
         let fi: FrontendIntegration = serde_yaml::from_str(
@@ -1182,7 +4662,7 @@ spec:
         .unwrap();
 
         assert!(matches!(
-            render_v1_manifest(&fi),
+            render_v1_manifest(&fi, &RenderOptions::default()),
             Err(ManifestRenderError::InvalidMenuShape { .. })
         ));
     }
@@ -1207,7 +4687,7 @@ spec:
         .unwrap();
 
         assert!(matches!(
-            render_v1_manifest(&fi),
+            render_v1_manifest(&fi, &RenderOptions::default()),
             Err(ManifestRenderError::InvalidMenuShape { .. })
         ));
     }
@@ -1232,7 +4712,7 @@ spec:
         .unwrap();
 
         assert!(matches!(
-            render_v1_manifest(&fi),
+            render_v1_manifest(&fi, &RenderOptions::default()),
             Err(ManifestRenderError::MissingPageForMenuKey { .. })
         ));
     }
@@ -1265,11 +4745,184 @@ spec:
         .unwrap();
 
         assert!(matches!(
-            render_v1_manifest(&fi),
+            render_v1_manifest(&fi, &RenderOptions::default()),
             Err(ManifestRenderError::OrphanPageConfig { .. })
         ));
     }
 
+    fn aliased_fi() -> FrontendIntegration {
+        serde_yaml::from_str(
+            r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: demo
+spec:
+  menus:
+    - displayName: Tasks
+      key: tasks
+      placement: cluster
+      type: page
+      aliases: [jobs, legacy/jobs]
+    - displayName: Ops
+      key: ops
+      placement: workspace
+      type: organization
+      children:
+        - displayName: Runs
+          key: runs
+          type: page
+          aliases: [ops/history, runs]
+  pages:
+    - key: tasks
+      type: iframe
+      iframe:
+        src: http://example.test
+    - key: runs
+      type: iframe
+      iframe:
+        src: http://example.test
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn route_aliases_add_routes_to_the_same_page_only() {
+        let fi = aliased_fi();
+        let manifest = render_v1_manifest(&fi, &RenderOptions::default()).unwrap();
+        let mut plain = fi.clone();
+        plain.spec.menus[0].aliases.clear();
+        plain.spec.menus[1].children[0].aliases.clear();
+        let plain = render_v1_manifest(&plain, &RenderOptions::default()).unwrap();
+
+        let routes = manifest["routes"].as_array().unwrap();
+        assert_eq!(routes.len(), 2 * (1 + 2));
+        assert_eq!(manifest["menus"], plain["menus"]);
+        assert_eq!(manifest["pages"], plain["pages"]);
+        assert_eq!(routes[0], plain["routes"][0]);
+        let tasks_id = &manifest["pages"][1]["id"];
+        assert_eq!(
+            routes[3..]
+                .iter()
+                .map(|route| (route["path"].as_str().unwrap(), &route["pageId"]))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    "/clusters/:cluster/frontendintegrations/demo/tasks",
+                    tasks_id
+                ),
+                (
+                    "/clusters/:cluster/frontendintegrations/demo/jobs",
+                    tasks_id
+                ),
+                (
+                    "/clusters/:cluster/frontendintegrations/demo/legacy/jobs",
+                    tasks_id
+                ),
+            ]
+        );
+        assert_eq!(
+            routes[2]["path"],
+            "/workspaces/:workspace/frontendintegrations/demo/runs"
+        );
+        assert_eq!(routes[2]["pageId"], manifest["pages"][0]["id"]);
+    }
+
+    #[test]
+    fn rejects_colliding_or_malformed_route_aliases() {
+        for (aliases, expected_path) in [(vec!["tasks"], "tasks"), (vec!["jobs", "jobs"], "jobs")] {
+            let mut fi = aliased_fi();
+            fi.spec.menus[0].aliases = aliases.iter().map(|alias| alias.to_string()).collect();
+            match render_v1_manifest(&fi, &RenderOptions::default()) {
+                Err(ManifestRenderError::DuplicateRoutePath { key, path, .. }) => {
+                    assert_eq!(key, "tasks");
+                    assert_eq!(path, expected_path);
+                }
+                other => panic!("expected DuplicateRoutePath for {aliases:?}, got {other:?}"),
+            }
+        }
+
+        // Another menu's primary path in the same placement is taken too.
+        let mut fi = aliased_fi();
+        fi.spec.menus[1].placement = MenuPlacement::Cluster;
+        fi.spec.menus[1].children[0].aliases = vec!["tasks".to_string()];
+        assert!(matches!(
+            render_v1_manifest(&fi, &RenderOptions::default()),
+            Err(ManifestRenderError::DuplicateRoutePath { .. })
+        ));
+
+        // So is a primary path resolved after an earlier menu's alias claimed it.
+        let mut fi = aliased_fi();
+        fi.spec.menus[0].aliases = vec!["ops/runs".to_string()];
+        fi.spec.menus[1].placement = MenuPlacement::Cluster;
+        fi.spec.menus[1].children[0].aliases.clear();
+        match render_v1_manifest(&fi, &RenderOptions::default()) {
+            Err(ManifestRenderError::DuplicateRoutePath { key, path, .. }) => {
+                assert_eq!(key, "runs");
+                assert_eq!(path, "ops/runs");
+            }
+            other => panic!("expected DuplicateRoutePath for ops/runs, got {other:?}"),
+        }
+
+        let mut fi = aliased_fi();
+        fi.spec.menus[0].aliases = vec!["a//b".to_string()];
+        assert!(matches!(
+            render_v1_manifest(&fi, &RenderOptions::default()),
+            Err(ManifestRenderError::InvalidRoutingPath {
+                source: RoutingPathError::EmptySegment { index: 1 },
+                ..
+            })
+        ));
+
+        let mut fi = aliased_fi();
+        fi.spec.menus[1].aliases = vec!["ops-old".to_string()];
+        assert!(matches!(
+            render_v1_manifest(&fi, &RenderOptions::default()),
+            Err(ManifestRenderError::InvalidMenuShape { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_nested_routes_past_the_routing_path_limit() {
+        let parent = "o".repeat(80);
+        let child = "t".repeat(60);
+        let fi: FrontendIntegration = serde_yaml::from_str(&format!(
+            r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: demo
+spec:
+  menus:
+    - displayName: Ops
+      key: {parent}
+      placement: cluster
+      type: organization
+      children:
+        - displayName: Tasks
+          key: {child}
+          type: page
+  pages:
+    - key: {child}
+      type: iframe
+      iframe:
+        src: http://example.test
+"#
+        ))
+        .unwrap();
+
+        let err = render_v1_manifest(&fi, &RenderOptions::default()).unwrap_err();
+        assert!(matches!(
+            &err,
+            ManifestRenderError::InvalidRoutingPath {
+                source: RoutingPathError::PathTooLong { length: 141, .. },
+                ..
+            }
+        ));
+        assert!(err.to_string().contains("exceeding the limit of 128"));
+    }
+
     #[test]
     fn rejects_invalid_page_shapes_and_keys() {
         let invalid_menu_key: FrontendIntegration = serde_yaml::from_str(
@@ -1293,7 +4946,7 @@ spec:
         )
         .unwrap();
         assert!(matches!(
-            render_v1_manifest(&invalid_menu_key),
+            render_v1_manifest(&invalid_menu_key, &RenderOptions::default()),
             Err(ManifestRenderError::InvalidMenuKey { .. })
         ));
 
@@ -1318,7 +4971,7 @@ spec:
         )
         .unwrap();
         assert!(matches!(
-            render_v1_manifest(&invalid_page_key),
+            render_v1_manifest(&invalid_page_key, &RenderOptions::default()),
             Err(ManifestRenderError::InvalidPageShape { .. })
         ));
 
@@ -1341,7 +4994,7 @@ spec:
         )
         .unwrap();
         assert!(matches!(
-            render_v1_manifest(&missing_iframe),
+            render_v1_manifest(&missing_iframe, &RenderOptions::default()),
             Err(ManifestRenderError::InvalidPageShape { .. })
         ));
 
@@ -1372,7 +5025,7 @@ spec:
         )
         .unwrap();
         assert!(matches!(
-            render_v1_manifest(&missing_columns),
+            render_v1_manifest(&missing_columns, &RenderOptions::default()),
             Err(ManifestRenderError::MissingCrdColumns { .. })
         ));
     }
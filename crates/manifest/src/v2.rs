@@ -0,0 +1,191 @@
+use frontend_forge_api::{FrontendIntegration, FrontendIntegrationSpec, PageType};
+use kube::ResourceExt;
+use serde_json::{Map, Value, json};
+
+use crate::v1::{self, ResolvedPageBinding, ResolvedTopMenu};
+use crate::{ManifestRenderError, RenderOptions};
+
+// Menu resolution, validation and page bodies are shared with v1; v2 only changes the layout
+// of the manifest around them.
+pub(super) fn render_v2_manifest(
+    fi: &FrontendIntegration,
+    options: &RenderOptions,
+) -> Result<Value, ManifestRenderError> {
+    let fi_name = fi.name_any();
+    let default_icon = v1::default_menu_icon(options);
+    let (display_name, description) = v1::display_name_and_description(fi);
+    let resolved_menus = v1::resolve_spec(&fi.spec, &fi_name, options)?;
+    v1::validate_locale_references(&fi_name, &fi.spec)?;
+
+    let mut routes = Vec::new();
+    let mut navigation = Vec::new();
+    let mut pages = Vec::new();
+
+    for menu in resolved_menus {
+        match menu {
+            ResolvedTopMenu::Page(page) => {
+                navigation.push(navigation_leaf(&page, default_icon));
+                routes.extend(v1::render_routes(&fi_name, &page));
+                pages.push(render_page(&fi_name, &page)?);
+            }
+            ResolvedTopMenu::Organization { menu, children } => {
+                let mut group = v1::render_organization_menu(&menu, default_icon);
+                let mut items = Vec::new();
+                for child in children {
+                    let mut item = navigation_leaf(&child, default_icon);
+                    // Nesting already says where the item lives.
+                    if let Some(item) = item.as_object_mut() {
+                        item.remove("parent");
+                    }
+                    items.push(item);
+                    routes.extend(v1::render_routes(&fi_name, &child));
+                    pages.push(render_page(&fi_name, &child)?);
+                }
+                group["children"] = Value::Array(items);
+                navigation.push(group);
+            }
+        }
+    }
+
+    let mut manifest = Map::new();
+    manifest.insert("version".to_string(), json!("2.0"));
+    manifest.insert("name".to_string(), json!(fi_name));
+    manifest.insert("displayName".to_string(), json!(display_name));
+    if let Some(description) = description {
+        manifest.insert("description".to_string(), json!(description));
+    }
+    if let Some(meta) = v1::render_meta(&fi.spec) {
+        manifest.insert("meta".to_string(), meta);
+    }
+    manifest.insert("routes".to_string(), Value::Array(routes));
+    manifest.insert("navigation".to_string(), Value::Array(navigation));
+    manifest.insert("locales".to_string(), render_locales(&fi.spec));
+    manifest.insert("pages".to_string(), Value::Array(pages));
+    let mut build = json!({
+        "target": "kubesphere-extension",
+        "moduleName": fi_name,
+        "format": "esm",
+        "systemjs": false,
+    });
+    v1::apply_build_output(&fi_name, &fi.spec, &mut build)?;
+    manifest.insert("build".to_string(), build);
+
+    Ok(Value::Object(manifest))
+}
+
+fn navigation_leaf(page: &ResolvedPageBinding, default_icon: &str) -> Value {
+    let mut leaf = v1::render_leaf_menu(page, default_icon);
+    leaf["pageId"] = json!(page.page_id);
+    leaf
+}
+
+fn render_page(fi_name: &str, page: &ResolvedPageBinding) -> Result<Value, ManifestRenderError> {
+    let mut rendered = v1::render_page(fi_name, page)?;
+    rendered["layout"] = json!(page_layout(&page.page.type_));
+    Ok(rendered)
+}
+
+// Iframes bring their own chrome, so they fill the content area edge to edge.
+fn page_layout(page_type: &PageType) -> &'static str {
+    match page_type {
+        PageType::Iframe => "fullBleed",
+        PageType::CrdTable | PageType::Custom => "standard",
+    }
+}
+
+fn render_locales(spec: &FrontendIntegrationSpec) -> Value {
+    json!(spec.locales)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nested_fi() -> FrontendIntegration {
+        serde_yaml::from_str(
+            r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: demo
+spec:
+  displayName: "{{title}}"
+  builder:
+    engineVersion: v2
+  locales:
+    zh:
+      title: 演示
+    en:
+      title: Demo
+  menus:
+    - displayName: Overview
+      key: overview
+      placement: cluster
+      type: page
+    - displayName: Ops
+      key: ops
+      placement: workspace
+      type: organization
+      children:
+        - displayName: Tasks
+          key: tasks
+          type: page
+  pages:
+    - key: overview
+      type: iframe
+      iframe:
+        src: http://example.test
+    - key: tasks
+      type: custom
+      custom:
+        type: TaskBoard
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn nests_navigation_and_keys_locales_by_language() {
+        let manifest = render_v2_manifest(&nested_fi(), &RenderOptions::default()).unwrap();
+
+        assert_eq!(manifest["version"], "2.0");
+        assert!(manifest.get("menus").is_none());
+        let navigation = manifest["navigation"].as_array().unwrap();
+        assert_eq!(navigation.len(), 2);
+        let group = &navigation[0];
+        assert_eq!(group["parent"], "workspace");
+        assert!(group.get("pageId").is_none());
+        let children = group["children"].as_array().unwrap();
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0]["title"], "Tasks");
+        assert_eq!(children[0]["pageId"], manifest["pages"][0]["id"]);
+        assert!(children[0].get("parent").is_none());
+
+        assert_eq!(navigation[1]["parent"], "cluster");
+        assert_eq!(navigation[1]["pageId"], manifest["pages"][1]["id"]);
+        assert!(navigation[1].get("children").is_none());
+
+        assert_eq!(
+            manifest["locales"],
+            json!({"en": {"title": "Demo"}, "zh": {"title": "演示"}})
+        );
+        assert_eq!(manifest["routes"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn emits_page_layouts_and_an_esm_build() {
+        let manifest = render_v2_manifest(&nested_fi(), &RenderOptions::default()).unwrap();
+
+        assert_eq!(manifest["pages"][0]["layout"], "standard");
+        assert_eq!(manifest["pages"][1]["layout"], "fullBleed");
+        assert_eq!(
+            manifest["build"],
+            json!({
+                "target": "kubesphere-extension",
+                "moduleName": "demo",
+                "format": "esm",
+                "systemjs": false,
+            })
+        );
+    }
+}
@@ -0,0 +1,316 @@
+use frontend_forge_api::{BuilderSpec, FrontendIntegration, frontend_integration_crd};
+use frontend_forge_common::{canonical_json_string, manifest_content_and_hash};
+use frontend_forge_manifest::render_extension_manifest;
+use serde_json::{Value, json};
+use std::error::Error;
+use std::io::Read;
+use std::{env, fs, io, process};
+
+const USAGE: &str =
+    "usage: ff-validate [--output json|yaml] [--engine-version <version>] [--schema] <fi.yaml|->";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum OutputFormat {
+    Json,
+    Yaml,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct CliArgs {
+    input: String,
+    output: OutputFormat,
+    engine_version: Option<String>,
+    check_schema: bool,
+}
+
+fn main() {
+    let args = match parse_args(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{message}\n{USAGE}");
+            process::exit(2);
+        }
+    };
+
+    match run(&args) {
+        Ok(problems) if problems.is_empty() => {}
+        Ok(problems) => {
+            for problem in problems {
+                eprintln!("error: {problem}");
+            }
+            process::exit(1);
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            process::exit(1);
+        }
+    }
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<CliArgs, String> {
+    let mut input = None;
+    let mut output = OutputFormat::Json;
+    let mut engine_version = None;
+    let mut check_schema = false;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--output" | "-o" => {
+                output = match args.next().as_deref() {
+                    Some("json") => OutputFormat::Json,
+                    Some("yaml") => OutputFormat::Yaml,
+                    other => return Err(format!("unsupported --output {other:?}")),
+                }
+            }
+            "--engine-version" => {
+                engine_version = Some(args.next().ok_or("--engine-version requires a value")?)
+            }
+            "--schema" => check_schema = true,
+            "-h" | "--help" => {
+                return Err("ff-validate renders a FrontendIntegration offline".into());
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown flag {flag}")),
+            path => {
+                if input.replace(path.to_string()).is_some() {
+                    return Err("only one input file is supported".into());
+                }
+            }
+        }
+    }
+
+    Ok(CliArgs {
+        input: input.ok_or("missing input file")?,
+        output,
+        engine_version,
+        check_schema,
+    })
+}
+
+// Returns validation problems; Err is reserved for I/O failures.
+fn run(args: &CliArgs) -> Result<Vec<String>, Box<dyn Error>> {
+    let raw = if args.input == "-" {
+        let mut raw = String::new();
+        io::stdin().read_to_string(&mut raw)?;
+        raw
+    } else {
+        fs::read_to_string(&args.input)?
+    };
+
+    let mut problems = Vec::new();
+    if args.check_schema {
+        let document: Value = serde_yaml::from_str(&raw)?;
+        problems.extend(schema_problems(&document));
+    }
+
+    let mut fi: FrontendIntegration = match serde_yaml::from_str(&raw) {
+        Ok(fi) => fi,
+        Err(err) => {
+            problems.push(format!("failed to parse FrontendIntegration: {err}"));
+            return Ok(problems);
+        }
+    };
+    if let Some(engine_version) = args.engine_version.as_ref() {
+        fi.spec
+            .builder
+            .get_or_insert_with(BuilderSpec::default)
+            .engine_version = Some(engine_version.clone());
+    }
+    let manifest = match render_extension_manifest(&fi) {
+        Ok(manifest) => manifest,
+        Err(err) => {
+            problems.push(err.to_string());
+            return Ok(problems);
+        }
+    };
+    if !problems.is_empty() {
+        return Ok(problems);
+    }
+
+    let (_, manifest_hash) = manifest_content_and_hash(&manifest)?;
+    let document = json!({
+        "manifest": manifest,
+        "manifestHash": manifest_hash,
+    });
+    match args.output {
+        OutputFormat::Json => println!("{}", canonical_json_string(&document)?),
+        OutputFormat::Yaml => print!("{}", serde_yaml::to_string(&document)?),
+    }
+    Ok(problems)
+}
+
+fn schema_problems(document: &Value) -> Vec<String> {
+    let crd = frontend_integration_crd();
+    let schema = crd
+        .spec
+        .versions
+        .first()
+        .and_then(|version| version.schema.as_ref())
+        .and_then(|schema| schema.open_api_v3_schema.as_ref())
+        .and_then(|schema| serde_json::to_value(schema).ok())
+        .and_then(|schema| schema.pointer("/properties/spec").cloned());
+
+    let mut problems = Vec::new();
+    match (schema, document.get("spec")) {
+        (Some(schema), Some(spec)) => check_schema(&schema, spec, "spec", &mut problems),
+        (None, _) => problems.push("generated CRD has no spec schema".to_string()),
+        (_, None) => problems.push("spec: required".to_string()),
+    }
+    problems
+}
+
+// Covers the subset of OpenAPI v3 the derived CRD uses; format and pattern are left to the API server.
+fn check_schema(schema: &Value, value: &Value, path: &str, problems: &mut Vec<String>) {
+    if value.is_null() {
+        if !schema["nullable"].as_bool().unwrap_or(false) {
+            problems.push(format!("{path}: must not be null"));
+        }
+        return;
+    }
+
+    if let Some(allowed) = schema["enum"].as_array()
+        && !allowed.contains(value)
+    {
+        problems.push(format!(
+            "{path}: {value} is not one of {}",
+            Value::from(allowed.clone())
+        ));
+    }
+
+    let type_matches = match schema["type"].as_str() {
+        Some("object") => value.is_object(),
+        Some("array") => value.is_array(),
+        Some("string") => value.is_string(),
+        Some("boolean") => value.is_boolean(),
+        Some("integer") => value.is_i64() || value.is_u64(),
+        Some("number") => value.is_number(),
+        _ => true,
+    };
+    if !type_matches {
+        problems.push(format!(
+            "{path}: expected {}",
+            schema["type"].as_str().unwrap_or_default()
+        ));
+        return;
+    }
+
+    if let Some(items) = value.as_array() {
+        for (index, item) in items.iter().enumerate() {
+            check_schema(
+                &schema["items"],
+                item,
+                &format!("{path}[{index}]"),
+                problems,
+            );
+        }
+        return;
+    }
+
+    let Some(object) = value.as_object() else {
+        return;
+    };
+    for required in schema["required"].as_array().into_iter().flatten() {
+        if let Some(field) = required.as_str()
+            && !object.contains_key(field)
+        {
+            problems.push(format!("{path}.{field}: required"));
+        }
+    }
+    let properties = schema["properties"].as_object();
+    for (field, field_value) in object {
+        let field_path = format!("{path}.{field}");
+        match (
+            properties.and_then(|p| p.get(field)),
+            &schema["additionalProperties"],
+        ) {
+            (Some(field_schema), _) => {
+                check_schema(field_schema, field_value, &field_path, problems)
+            }
+            (None, additional) if additional.is_object() => {
+                check_schema(additional, field_value, &field_path, problems)
+            }
+            (None, Value::Bool(true)) => {}
+            (None, _) if schema["x-kubernetes-preserve-unknown-fields"] == true => {}
+            (None, _) => problems.push(format!("{field_path}: unknown field")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FI_YAML: &str = r#"
+apiVersion: frontend-forge.kubesphere.io/v1alpha1
+kind: FrontendIntegration
+metadata:
+  name: demo
+spec:
+  menus:
+    - displayName: Demo
+      key: demo
+      placement: global
+      type: page
+  pages:
+    - key: demo
+      type: iframe
+      iframe:
+        src: http://example.test
+"#;
+
+    fn args(values: &[&str]) -> Result<CliArgs, String> {
+        parse_args(values.iter().map(|value| value.to_string()))
+    }
+
+    #[test]
+    fn parses_output_engine_and_schema_flags() {
+        assert_eq!(
+            args(&[
+                "--output",
+                "yaml",
+                "--engine-version",
+                "v1",
+                "--schema",
+                "fi.yaml"
+            ]),
+            Ok(CliArgs {
+                input: "fi.yaml".to_string(),
+                output: OutputFormat::Yaml,
+                engine_version: Some("v1".to_string()),
+                check_schema: true,
+            })
+        );
+        assert!(args(&["--output", "toml", "fi.yaml"]).is_err());
+        assert!(args(&[]).is_err());
+    }
+
+    #[test]
+    fn schema_accepts_valid_spec() {
+        let document: Value = serde_yaml::from_str(FI_YAML).unwrap();
+
+        assert_eq!(schema_problems(&document), Vec::<String>::new());
+    }
+
+    #[test]
+    fn schema_reports_every_problem_with_its_path() {
+        let mut document: Value = serde_yaml::from_str(FI_YAML).unwrap();
+        document["spec"]["menus"][0]["placement"] = json!("sidebar");
+        document["spec"]["menus"][0]["colour"] = json!("red");
+        document["spec"]["enabled"] = json!("yes");
+        document["spec"]["pages"][0]
+            .as_object_mut()
+            .unwrap()
+            .remove("key");
+
+        let problems = schema_problems(&document);
+
+        assert!(problems.contains(&"spec.enabled: expected boolean".to_string()));
+        assert!(problems.contains(&"spec.menus[0].colour: unknown field".to_string()));
+        assert!(
+            problems
+                .iter()
+                .any(|p| p.starts_with("spec.menus[0].placement: \"sidebar\""))
+        );
+        assert!(problems.contains(&"spec.pages[0].key: required".to_string()));
+    }
+}
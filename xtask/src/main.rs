@@ -1,8 +1,15 @@
-use frontend_forge_api::frontend_integration_crd;
+use frontend_forge_api::{
+    frontend_forge_config_crd, frontend_integration_crd, jsbundle_crd, validate_crd_round_trip,
+};
 use std::{env, error::Error, fs, path::PathBuf, process};
 
 const FRONTEND_INTEGRATION_CRD_PATH: &str =
     "config/crd/bases/frontend-forge.kubesphere.io_frontendintegrations.yaml";
+const FRONTEND_FORGE_CONFIG_CRD_PATH: &str =
+    "config/crd/bases/frontend-forge.kubesphere.io_frontendforgeconfigs.yaml";
+// Only for dev clusters without KubeSphere; real clusters ship their own JSBundle CRD.
+const JSBUNDLE_CRD_PATH: &str = "config/crd/dev/extensions.kubesphere.io_jsbundles.yaml";
+const USAGE: &str = "usage: cargo xtask gen-crd [--include-jsbundle] [--validate]";
 
 fn main() {
     if let Err(err) = run() {
@@ -12,28 +19,57 @@ fn main() {
 }
 
 fn run() -> Result<(), Box<dyn Error>> {
-    match env::args().nth(1).as_deref() {
-        Some("gen-crd") => gen_crd(),
-        _ => {
-            eprintln!("usage: cargo xtask gen-crd");
-            process::exit(2);
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("gen-crd") => {
+            let mut include_jsbundle = false;
+            let mut validate = false;
+            for flag in &args[1..] {
+                match flag.as_str() {
+                    "--include-jsbundle" => include_jsbundle = true,
+                    "--validate" => validate = true,
+                    _ => usage(),
+                }
+            }
+            gen_crd(include_jsbundle, validate)
         }
+        _ => usage(),
     }
 }
 
-fn gen_crd() -> Result<(), Box<dyn Error>> {
-    let crd = frontend_integration_crd();
-    let rendered = serde_yaml::to_string(&crd)?;
-    let output_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+fn usage() -> ! {
+    eprintln!("{USAGE}");
+    process::exit(2);
+}
+
+// --validate only checks the generated CRDs and never touches the files on disk.
+fn gen_crd(include_jsbundle: bool, validate: bool) -> Result<(), Box<dyn Error>> {
+    let mut crds = vec![
+        (FRONTEND_INTEGRATION_CRD_PATH, frontend_integration_crd()),
+        (FRONTEND_FORGE_CONFIG_CRD_PATH, frontend_forge_config_crd()),
+    ];
+    if include_jsbundle {
+        crds.push((JSBUNDLE_CRD_PATH, jsbundle_crd()));
+    }
+
+    let workspace_root = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
         .parent()
         .expect("xtask workspace root")
-        .join(FRONTEND_INTEGRATION_CRD_PATH);
+        .to_path_buf();
+    for (path, crd) in crds {
+        if validate {
+            validate_crd_round_trip(&crd)?;
+            println!("validated {path}");
+            continue;
+        }
 
-    if let Some(parent) = output_path.parent() {
-        fs::create_dir_all(parent)?;
+        let rendered = serde_yaml::to_string(&crd)?;
+        let output_path = workspace_root.join(path);
+        if let Some(parent) = output_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&output_path, rendered)?;
+        println!("updated {}", output_path.display());
     }
-
-    fs::write(&output_path, rendered)?;
-    println!("updated {}", output_path.display());
     Ok(())
 }